@@ -0,0 +1,517 @@
+//! [`Display`](fmt::Display) implementations for the executable and service document types.
+//!
+//! The nodes are printed in the compact format of the `graphql-toolkit-writer` crate, for example
+//! `query($id:ID!){user(id:$id){name}}`, suitable for logs and error messages. The operations and
+//! fragments of a document are printed in source order.
+//!
+//! The writer cannot print service documents, so their definitions follow the same conventions,
+//! one definition per line.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use graphql_toolkit_value::{ConstValue, Name, Value};
+
+use crate::{
+    pos::Positioned,
+    types::{
+        ConstDirective, Directive, DirectiveDefinition, DirectiveLocation, DocumentOperations,
+        EnumValueDefinition, ExecutableDocument, Field, FieldDefinition, FragmentDefinition,
+        FragmentSpread, InlineFragment, InputValueDefinition, OperationDefinition, OperationType,
+        SchemaDefinition, Selection, SelectionSet, ServiceDocument, TypeCondition, TypeDefinition,
+        TypeKind, TypeSystemDefinition, VariableDefinition,
+    },
+};
+
+impl Display for ExecutableDocument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.operations.fmt(f)?;
+        for (name, fragment) in self.fragments_in_order() {
+            write_fragment_definition(f, name, &fragment.node)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for DocumentOperations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Single(operation) => write_operation_definition(f, None, &operation.node, true),
+            Self::Multiple(_) => {
+                for (name, operation) in self.in_order() {
+                    write_operation_definition(f, name, &operation.node, false)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for OperationDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_operation_definition(f, None, self, true)
+    }
+}
+
+impl Display for VariableDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "${}:{}", self.name, self.var_type)?;
+        if let Some(default_value) = &self.default_value {
+            f.write_char('=')?;
+            write_const_value(f, &default_value.node)?;
+        }
+        write_directives(f, &self.directives)
+    }
+}
+
+impl Display for SelectionSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('{')?;
+        write_separated(f, &self.items)?;
+        f.write_char('}')
+    }
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(field) => field.fmt(f),
+            Self::FragmentSpread(spread) => spread.fmt(f),
+            Self::InlineFragment(fragment) => fragment.fmt(f),
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(alias) = &self.alias {
+            write!(f, "{}:", alias)?;
+        }
+        f.write_str(&self.name.node)?;
+        write_arguments(f, &self.arguments, write_value)?;
+        write_directives(f, &self.directives)?;
+        write_selection_set(f, &self.selection_set.node)
+    }
+}
+
+impl Display for FragmentSpread {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "...{}", self.fragment_name)?;
+        write_directives(f, &self.directives)
+    }
+}
+
+impl Display for InlineFragment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("...")?;
+        if let Some(type_condition) = &self.type_condition {
+            type_condition.fmt(f)?;
+        }
+        write_directives(f, &self.directives)?;
+        write_selection_set(f, &self.selection_set.node)
+    }
+}
+
+impl Display for TypeCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "on {}", self.on)
+    }
+}
+
+impl Display for Directive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}", self.name)?;
+        write_arguments(f, &self.arguments, write_value)
+    }
+}
+
+impl Display for ConstDirective {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}", self.name)?;
+        write_arguments(f, &self.arguments, write_const_value)
+    }
+}
+
+impl Display for ServiceDocument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut iter = self.definitions.iter();
+        if let Some(definition) = iter.next() {
+            definition.fmt(f)?;
+        }
+        for definition in iter {
+            write!(f, "\n{}", definition)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for TypeSystemDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Schema(schema) => schema.fmt(f),
+            Self::Type(ty) => ty.fmt(f),
+            Self::Directive(directive) => directive.fmt(f),
+        }
+    }
+}
+
+impl Display for SchemaDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.extend {
+            f.write_str("extend ")?;
+        }
+        f.write_str("schema")?;
+        write_directives(f, &self.directives)?;
+
+        let roots = [
+            (OperationType::Query, &self.query),
+            (OperationType::Mutation, &self.mutation),
+            (OperationType::Subscription, &self.subscription),
+        ];
+        if roots.iter().all(|(_, name)| name.is_none()) {
+            return Ok(());
+        }
+
+        f.write_char('{')?;
+        let mut first = true;
+        for (ty, name) in roots {
+            if let Some(name) = name {
+                if !first {
+                    f.write_char(',')?;
+                }
+                write!(f, "{}:{}", ty, name)?;
+                first = false;
+            }
+        }
+        f.write_char('}')
+    }
+}
+
+impl Display for TypeDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
+        if self.extend {
+            f.write_str("extend ")?;
+        }
+
+        let keyword = match &self.kind {
+            TypeKind::Scalar => "scalar",
+            TypeKind::Object(_) => "type",
+            TypeKind::Interface(_) => "interface",
+            TypeKind::Union(_) => "union",
+            TypeKind::Enum(_) => "enum",
+            TypeKind::InputObject(_) => "input",
+        };
+        write!(f, "{} {}", keyword, self.name)?;
+
+        match &self.kind {
+            TypeKind::Scalar => write_directives(f, &self.directives),
+            TypeKind::Object(object) => {
+                write_implements(f, &object.implements)?;
+                write_directives(f, &self.directives)?;
+                write_block(f, &object.fields)
+            }
+            TypeKind::Interface(interface) => {
+                write_implements(f, &interface.implements)?;
+                write_directives(f, &self.directives)?;
+                write_block(f, &interface.fields)
+            }
+            TypeKind::Union(union) => {
+                write_directives(f, &self.directives)?;
+                let mut iter = union.members.iter();
+                if let Some(member) = iter.next() {
+                    write!(f, "={}", member)?;
+                }
+                for member in iter {
+                    write!(f, "|{}", member)?;
+                }
+                Ok(())
+            }
+            TypeKind::Enum(enum_type) => {
+                write_directives(f, &self.directives)?;
+                write_block(f, &enum_type.values)
+            }
+            TypeKind::InputObject(input_object) => {
+                write_directives(f, &self.directives)?;
+                write_block(f, &input_object.fields)
+            }
+        }
+    }
+}
+
+impl Display for FieldDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
+        f.write_str(&self.name.node)?;
+        write_argument_definitions(f, &self.arguments)?;
+        write!(f, ":{}", self.ty)?;
+        write_directives(f, &self.directives)
+    }
+}
+
+impl Display for InputValueDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
+        write!(f, "{}:{}", self.name, self.ty)?;
+        if let Some(default_value) = &self.default_value {
+            f.write_char('=')?;
+            write_const_value(f, &default_value.node)?;
+        }
+        write_directives(f, &self.directives)
+    }
+}
+
+impl Display for EnumValueDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
+        f.write_str(&self.value.node)?;
+        write_directives(f, &self.directives)
+    }
+}
+
+impl Display for DirectiveDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
+        write!(f, "directive @{}", self.name)?;
+        write_argument_definitions(f, &self.arguments)?;
+        if self.is_repeatable {
+            f.write_str(" repeatable")?;
+        }
+        f.write_str(" on")?;
+
+        let mut iter = self.locations.iter();
+        if let Some(location) = iter.next() {
+            write!(f, " {}", location)?;
+        }
+        for location in iter {
+            write!(f, "|{}", location)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for DirectiveLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn write_operation_definition(
+    f: &mut Formatter<'_>,
+    name: Option<&Name>,
+    operation: &OperationDefinition,
+    single: bool,
+) -> fmt::Result {
+    // Use the query shorthand for a lone anonymous query without variables and directives.
+    let shorthand = single
+        && operation.ty == OperationType::Query
+        && name.is_none()
+        && operation.variable_definitions.is_empty()
+        && operation.directives.is_empty();
+    if !shorthand {
+        write!(f, "{}", operation.ty)?;
+        if let Some(name) = name {
+            write!(f, " {}", name)?;
+        }
+
+        if !operation.variable_definitions.is_empty() {
+            f.write_char('(')?;
+            write_separated(f, &operation.variable_definitions)?;
+            f.write_char(')')?;
+        }
+
+        write_directives(f, &operation.directives)?;
+    }
+
+    write_selection_set(f, &operation.selection_set.node)
+}
+
+fn write_fragment_definition(
+    f: &mut Formatter<'_>,
+    name: &Name,
+    fragment: &FragmentDefinition,
+) -> fmt::Result {
+    write!(f, "fragment {} {}", name, fragment.type_condition)?;
+    write_directives(f, &fragment.directives)?;
+    write_selection_set(f, &fragment.selection_set.node)
+}
+
+fn write_selection_set(f: &mut Formatter<'_>, selection_set: &SelectionSet) -> fmt::Result {
+    // Empty selection sets are not printed
+    if selection_set.items.is_empty() {
+        return Ok(());
+    }
+    selection_set.fmt(f)
+}
+
+fn write_arguments<T>(
+    f: &mut Formatter<'_>,
+    arguments: &[(Positioned<Name>, Positioned<T>)],
+    write_value: fn(&mut Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    if arguments.is_empty() {
+        return Ok(());
+    }
+
+    f.write_char('(')?;
+    for (i, (name, value)) in arguments.iter().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write!(f, "{}:", name)?;
+        write_value(f, &value.node)?;
+    }
+    f.write_char(')')
+}
+
+fn write_argument_definitions(
+    f: &mut Formatter<'_>,
+    arguments: &[Positioned<InputValueDefinition>],
+) -> fmt::Result {
+    if arguments.is_empty() {
+        return Ok(());
+    }
+
+    f.write_char('(')?;
+    write_separated(f, arguments)?;
+    f.write_char(')')
+}
+
+fn write_directives<T: Display>(
+    f: &mut Formatter<'_>,
+    directives: &[Positioned<T>],
+) -> fmt::Result {
+    for directive in directives {
+        directive.fmt(f)?;
+    }
+
+    Ok(())
+}
+
+fn write_implements(f: &mut Formatter<'_>, implements: &[Positioned<Name>]) -> fmt::Result {
+    let mut iter = implements.iter();
+    if let Some(interface) = iter.next() {
+        write!(f, " implements {}", interface)?;
+    }
+    for interface in iter {
+        write!(f, "&{}", interface)?;
+    }
+
+    Ok(())
+}
+
+fn write_block<T: Display>(f: &mut Formatter<'_>, items: &[Positioned<T>]) -> fmt::Result {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    f.write_char('{')?;
+    write_separated(f, items)?;
+    f.write_char('}')
+}
+
+fn write_separated<T: Display>(f: &mut Formatter<'_>, items: &[Positioned<T>]) -> fmt::Result {
+    let mut iter = items.iter();
+    if let Some(item) = iter.next() {
+        item.fmt(f)?;
+    }
+    for item in iter {
+        write!(f, ",{}", item)?;
+    }
+
+    Ok(())
+}
+
+fn write_value(f: &mut Formatter<'_>, value: &Value) -> fmt::Result {
+    match value {
+        Value::Variable(name) => write!(f, "${}", name),
+        Value::Null => f.write_str("null"),
+        Value::Number(number) => number.fmt(f),
+        Value::String(string) => write_string(f, string),
+        Value::Boolean(boolean) => boolean.fmt(f),
+        Value::Binary(bytes) => write_bytes(f, bytes),
+        Value::Enum(name) => name.fmt(f),
+        Value::List(items) => write_list(f, items, write_value),
+        Value::Object(object) => write_object(f, object, write_value),
+    }
+}
+
+fn write_const_value(f: &mut Formatter<'_>, value: &ConstValue) -> fmt::Result {
+    match value {
+        ConstValue::Null => f.write_str("null"),
+        ConstValue::Number(number) => number.fmt(f),
+        ConstValue::String(string) => write_string(f, string),
+        ConstValue::Boolean(boolean) => boolean.fmt(f),
+        ConstValue::Binary(bytes) => write_bytes(f, bytes),
+        ConstValue::Enum(name) => name.fmt(f),
+        ConstValue::List(items) => write_list(f, items, write_const_value),
+        ConstValue::Object(object) => write_object(f, object, write_const_value),
+    }
+}
+
+fn write_list<T>(
+    f: &mut Formatter<'_>,
+    items: &[T],
+    write_item: fn(&mut Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    f.write_char('[')?;
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_item(f, item)?;
+    }
+    f.write_char(']')
+}
+
+fn write_object<'a, T: 'a>(
+    f: &mut Formatter<'_>,
+    fields: impl IntoIterator<Item = (&'a Name, &'a T)>,
+    write_field: fn(&mut Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    f.write_char('{')?;
+    for (i, (name, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write!(f, "{}:", name)?;
+        write_field(f, value)?;
+    }
+    f.write_char('}')
+}
+
+/// Binary values are printed as a list of bytes, like the writer does by default.
+fn write_bytes(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    write_list(f, bytes, |f, byte| byte.fmt(f))
+}
+
+/// Strings are escaped like the writer does.
+fn write_string(f: &mut Formatter<'_>, string: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in string.chars() {
+        match c {
+            '\r' => f.write_str("\\r"),
+            '\n' => f.write_str("\\n"),
+            '\t' => f.write_str("\\t"),
+            '"' => f.write_str("\\\""),
+            '\\' => f.write_str("\\\\"),
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32),
+            c => f.write_char(c),
+        }?;
+    }
+    f.write_char('"')
+}
+
+fn write_description(
+    f: &mut Formatter<'_>,
+    description: &Option<Positioned<String>>,
+) -> fmt::Result {
+    let Some(description) = description else {
+        return Ok(());
+    };
+
+    write_string(f, &description.node)?;
+    f.write_char(' ')
+}
@@ -4,9 +4,13 @@
 
 pub use graphql_toolkit_value::*;
 pub use pos::*;
+pub use source::{FileId, FilePos, SourceFile, SourceMap};
 pub use types::*;
 
+#[cfg(feature = "display")]
+mod display;
 mod pos;
+mod source;
 mod types;
 
 /// A value-to-AST conversion trait that consumes the input value.
@@ -0,0 +1,206 @@
+use std::fmt::{self, Write as _};
+
+use crate::pos::Pos;
+
+/// The identifier of a source file in a [`SourceMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileId(u32);
+
+impl FileId {
+    /// Get the index of the file in its source map, in insertion order.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A position in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FilePos {
+    /// The file of the position.
+    pub file: FileId,
+    /// The position in the file.
+    pub pos: Pos,
+}
+
+impl FilePos {
+    /// Create a new position in a source file.
+    #[must_use]
+    pub const fn new(file: FileId, pos: Pos) -> Self {
+        Self { file, pos }
+    }
+}
+
+/// A source file, with its path and text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    path: String,
+    text: String,
+}
+
+impl SourceFile {
+    /// Get the path of the file.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get the text of the file.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the text of the line with the given one-based number, without the line terminator.
+    #[must_use]
+    pub fn line(&self, line: usize) -> Option<&str> {
+        self.text.lines().nth(line.checked_sub(1)?)
+    }
+
+    /// Get a snippet of the file, showing the line of the position with a caret under its column.
+    ///
+    /// ```text
+    /// 2 | type User { id: Id! }
+    ///   |                 ^
+    /// ```
+    ///
+    /// Returns `None` if the position is out of the file.
+    #[must_use]
+    pub fn snippet(&self, pos: Pos) -> Option<String> {
+        let line = self.line(pos.line)?;
+        let number = pos.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        // Tabs are kept in the padding, so that the caret aligns with the line.
+        let padding = line
+            .chars()
+            .take(pos.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        Some(format!("{} | {}\n{} | {}^", number, line, gutter, padding))
+    }
+}
+
+/// A collection of source files, such as the files of a schema split across multiple files.
+///
+/// The positions of the AST nodes and errors only carry a line and a column. The source map
+/// assigns a [`FileId`] to each parsed file, so that a [`FilePos`] can be resolved back to its
+/// file path and a snippet of its text.
+///
+/// ```
+/// # use graphql_toolkit_ast::{FilePos, Pos, SourceMap};
+/// let mut sources = SourceMap::new();
+/// let file = sources.add_file("users.graphql", "type User { id: Id! }");
+///
+/// let pos = FilePos::new(file, Pos { line: 1, column: 17 });
+/// assert_eq!(sources.location(pos).unwrap(), "users.graphql:1:17");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    /// Create a new empty source map.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a source file to the map, and get its identifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map already contains `u32::MAX` files.
+    pub fn add_file(&mut self, path: impl Into<String>, text: impl Into<String>) -> FileId {
+        let id = u32::try_from(self.files.len()).expect("too many source files");
+        self.files.push(SourceFile {
+            path: path.into(),
+            text: text.into(),
+        });
+        FileId(id)
+    }
+
+    /// Get the source file with the given identifier.
+    ///
+    /// Returns `None` if the identifier belongs to another source map.
+    #[must_use]
+    pub fn get(&self, file: FileId) -> Option<&SourceFile> {
+        self.files.get(file.index())
+    }
+
+    /// Get the identifier of the first source file with the given path.
+    #[must_use]
+    pub fn find(&self, path: &str) -> Option<FileId> {
+        self.files()
+            .find(|(_, file)| file.path == path)
+            .map(|(id, _)| id)
+    }
+
+    /// Get an iterator over the source files, in insertion order.
+    pub fn files(&self) -> impl Iterator<Item = (FileId, &SourceFile)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| (FileId(index as u32), file))
+    }
+
+    /// Get the location of a position, formatted as `path:line:column`.
+    #[must_use]
+    pub fn location(&self, pos: FilePos) -> Option<String> {
+        let file = self.get(pos.file)?;
+        Some(format!("{}:{}", file.path, pos.pos))
+    }
+
+    /// Get a snippet of the text at a position. See [`SourceFile::snippet`].
+    #[must_use]
+    pub fn snippet(&self, pos: FilePos) -> Option<String> {
+        self.get(pos.file)?.snippet(pos.pos)
+    }
+
+    /// Render a diagnostic message about a file, such as a parser, schema or validation error,
+    /// with the location and snippet of each of its positions.
+    ///
+    /// ```text
+    /// unknown type Id
+    ///  --> users.graphql:1:17
+    /// 1 | type User { id: Id! }
+    ///   |                 ^
+    /// ```
+    ///
+    /// The positions out of the file are rendered without a snippet.
+    #[must_use]
+    pub fn render(
+        &self,
+        file: FileId,
+        message: impl fmt::Display,
+        positions: impl IntoIterator<Item = Pos>,
+    ) -> String {
+        self.render_at(
+            message,
+            positions.into_iter().map(|pos| FilePos::new(file, pos)),
+        )
+    }
+
+    /// Render a diagnostic message about positions in different files, such as a conflict
+    /// between the definitions of two files. See [`SourceMap::render`].
+    #[must_use]
+    pub fn render_at(
+        &self,
+        message: impl fmt::Display,
+        positions: impl IntoIterator<Item = FilePos>,
+    ) -> String {
+        let mut output = message.to_string();
+        for pos in positions {
+            let path = self.get(pos.file).map_or("<unknown>", SourceFile::path);
+            // Writing to a `String` never fails.
+            let _ = write!(output, "\n --> {}:{}", path, pos.pos);
+            if let Some(snippet) = self.snippet(pos) {
+                output.push('\n');
+                output.push_str(&snippet);
+            }
+        }
+        output
+    }
+}
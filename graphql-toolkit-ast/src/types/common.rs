@@ -57,6 +57,68 @@ impl Type {
             nullable,
         })
     }
+
+    /// Get the named type, unwrapping the list and non-null wrappers. For example, the named
+    /// type of `[String!]!` is `String`.
+    #[must_use]
+    pub fn named_type(&self) -> &Name {
+        self.base.named_type()
+    }
+
+    /// Get the number of list wrappers of the type. For example, the list depth of `String` is
+    /// 0, and the list depth of `[[String]!]` is 2.
+    #[must_use]
+    pub fn list_depth(&self) -> usize {
+        match &self.base {
+            BaseType::Named(_) => 0,
+            BaseType::List(ty) => ty.list_depth() + 1,
+        }
+    }
+
+    /// Whether the type is a list type, nullable or not.
+    #[must_use]
+    pub fn is_list(&self) -> bool {
+        matches!(self.base, BaseType::List(_))
+    }
+
+    /// Get the type of the items of a list type, or `None` if the type is not a list type.
+    #[must_use]
+    pub fn item_type(&self) -> Option<&Type> {
+        match &self.base {
+            BaseType::Named(_) => None,
+            BaseType::List(ty) => Some(ty),
+        }
+    }
+
+    /// Get the nullable version of the type. For example, the nullable type of `[String!]!` is
+    /// `[String!]`.
+    #[must_use]
+    pub fn to_nullable(&self) -> Type {
+        Self {
+            base: self.base.clone(),
+            nullable: true,
+        }
+    }
+
+    /// Whether a value of this type can be used where a value of the `other` type is expected.
+    ///
+    /// This is the case when the types have the same named type and list wrappers, and this
+    /// type is non-null wherever the `other` type is. The named types are compared by name, so
+    /// interface and union subtyping is not taken into account.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#AreTypesCompatible()).
+    #[must_use]
+    pub fn is_subtype_of(&self, other: &Type) -> bool {
+        if self.nullable && !other.nullable {
+            return false;
+        }
+
+        match (&self.base, &other.base) {
+            (BaseType::Named(name), BaseType::Named(other)) => name == other,
+            (BaseType::List(ty), BaseType::List(other)) => ty.is_subtype_of(other),
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for Type {
@@ -79,6 +141,17 @@ pub enum BaseType {
     List(Box<Type>),
 }
 
+impl BaseType {
+    /// Get the named type, unwrapping the list and non-null wrappers.
+    #[must_use]
+    pub fn named_type(&self) -> &Name {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) => ty.named_type(),
+        }
+    }
+}
+
 impl fmt::Display for BaseType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -93,7 +166,7 @@ impl fmt::Display for BaseType {
 /// instead of [`Value`](enum.Value.html).
 ///
 /// [Reference](https://spec.graphql.org/October2021/#Directive).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ConstDirective {
     /// The name of the directive.
     pub name: Positioned<Name>,
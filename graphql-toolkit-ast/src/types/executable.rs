@@ -4,8 +4,11 @@ use std::collections::{hash_map, HashMap};
 
 use graphql_toolkit_value::{ConstValue, Name, Value};
 
-use super::common::{Directive, OperationType, Type};
-use crate::pos::Positioned;
+use super::{
+    common::{Directive, OperationType, Type},
+    service::DirectiveLocation,
+};
+use crate::pos::{Pos, Positioned};
 
 /// An executable GraphQL file or request string.
 ///
@@ -16,6 +19,66 @@ pub struct ExecutableDocument {
     pub operations: DocumentOperations,
     /// The fragments of the document.
     pub fragments: HashMap<Name, Positioned<FragmentDefinition>>,
+    /// The name clashes of the document, which the maps above cannot hold.
+    pub name_clashes: Vec<NameClash>,
+}
+
+impl ExecutableDocument {
+    /// Get the fragments of the document in source order.
+    ///
+    /// The fragments at the same position, such as the ones built without a source, are sorted
+    /// by name.
+    #[must_use]
+    pub fn fragments_in_order(&self) -> Vec<(&Name, &Positioned<FragmentDefinition>)> {
+        let mut fragments = self.fragments.iter().collect::<Vec<_>>();
+        fragments.sort_by(|(a_name, a), (b_name, b)| (a.pos, a_name).cmp(&(b.pos, b_name)));
+        fragments
+    }
+}
+
+/// A name clash in an executable document.
+///
+/// The parser keeps the last field with a given name of an input object value, and records the
+/// dropped fields as name clashes, to be reported by the validation. It rejects the documents
+/// with clashing operations or fragments, but the tools building documents otherwise, such as by
+/// combining the definitions of several documents, can record them here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NameClash {
+    /// An operation has the same name as a previous one.
+    Operation {
+        /// The name of the operations.
+        name: Name,
+        /// The position of the first operation.
+        first: Pos,
+        /// The position of the second operation.
+        second: Pos,
+    },
+    /// An anonymous operation is defined along with other operations.
+    AnonymousOperation {
+        /// The position of the anonymous operation.
+        anonymous: Pos,
+        /// The position of the other operation.
+        operation: Pos,
+    },
+    /// A fragment has the same name as a previous one.
+    Fragment {
+        /// The name of the fragments.
+        name: Name,
+        /// The position of the first fragment.
+        first: Pos,
+        /// The position of the second fragment.
+        second: Pos,
+    },
+    /// An input object value contains the same field twice.
+    InputField {
+        /// The name of the field.
+        name: Name,
+        /// The position of the first occurrence.
+        first: Pos,
+        /// The position of the second occurrence.
+        second: Pos,
+    },
 }
 
 /// The operations of a GraphQL document.
@@ -38,6 +101,17 @@ impl DocumentOperations {
             Self::Multiple(ops) => OperationsIterInner::Multiple(ops.iter()),
         })
     }
+
+    /// Get the operations of the document in source order.
+    ///
+    /// The operations at the same position, such as the ones built without a source, are sorted
+    /// by name.
+    #[must_use]
+    pub fn in_order(&self) -> Vec<(Option<&Name>, &Positioned<OperationDefinition>)> {
+        let mut operations = self.iter().collect::<Vec<_>>();
+        operations.sort_by(|(a_name, a), (b_name, b)| (a.pos, a_name).cmp(&(b.pos, b_name)));
+        operations
+    }
 }
 
 // TODO: This is not implemented as I would like to later implement IntoIterator
@@ -105,6 +179,14 @@ pub struct OperationDefinition {
     pub selection_set: Positioned<SelectionSet>,
 }
 
+impl OperationDefinition {
+    /// Get the location of the directives of the operation, depending on its type.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        self.ty.into()
+    }
+}
+
 /// A variable definition inside a list of variable definitions, for example
 /// `$name:String!`.
 ///
@@ -122,6 +204,12 @@ pub struct VariableDefinition {
 }
 
 impl VariableDefinition {
+    /// Get the location of the directives of the variable definition.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        DirectiveLocation::VariableDefinition
+    }
+
     /// Get the default value of the variable; this is `default_value` if it is
     /// present, `Value::Null` if it is nullable and `None` otherwise.
     #[must_use]
@@ -161,6 +249,16 @@ pub enum Selection {
 }
 
 impl Selection {
+    /// Get the location of the directives of the selection.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        match self {
+            Self::Field(_) => DirectiveLocation::Field,
+            Self::FragmentSpread(_) => DirectiveLocation::FragmentSpread,
+            Self::InlineFragment(_) => DirectiveLocation::InlineFragment,
+        }
+    }
+
     /// Get a reference to the directives of the selection.
     #[must_use]
     pub fn directives(&self) -> &Vec<Positioned<Directive>> {
@@ -201,6 +299,12 @@ pub struct Field {
 }
 
 impl Field {
+    /// Get the location of the directives of the field.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        DirectiveLocation::Field
+    }
+
     /// Get the response key of the field. This is the alias if present and the
     /// name otherwise.
     #[must_use]
@@ -229,6 +333,14 @@ pub struct FragmentSpread {
     pub directives: Vec<Positioned<Directive>>,
 }
 
+impl FragmentSpread {
+    /// Get the location of the directives of the fragment spread.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        DirectiveLocation::FragmentSpread
+    }
+}
+
 /// An inline fragment selector, such as `... on User { name }`.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#InlineFragment).
@@ -242,6 +354,14 @@ pub struct InlineFragment {
     pub selection_set: Positioned<SelectionSet>,
 }
 
+impl InlineFragment {
+    /// Get the location of the directives of the inline fragment.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        DirectiveLocation::InlineFragment
+    }
+}
+
 /// The definition of a fragment, such as `fragment userFields on User { name
 /// age }`.
 ///
@@ -256,6 +376,14 @@ pub struct FragmentDefinition {
     pub selection_set: Positioned<SelectionSet>,
 }
 
+impl FragmentDefinition {
+    /// Get the location of the directives of the fragment definition.
+    #[must_use]
+    pub fn directive_location(&self) -> DirectiveLocation {
+        DirectiveLocation::FragmentDefinition
+    }
+}
+
 /// A type a fragment can apply to (`on` followed by the type).
 ///
 /// [Reference](https://spec.graphql.org/October2021/#TypeCondition).
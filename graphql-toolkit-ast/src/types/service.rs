@@ -1,14 +1,19 @@
 //! Service-related GraphQL types.
 
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
 use graphql_toolkit_value::{ConstValue, Name};
 
-use super::common::{ConstDirective, Type};
+use super::common::{ConstDirective, OperationType, Type};
 use crate::pos::Positioned;
 
 /// A GraphQL file or request string defining a GraphQL service.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#Document).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ServiceDocument {
     /// The definitions of this document.
     pub definitions: Vec<TypeSystemDefinition>,
@@ -18,7 +23,7 @@ pub struct ServiceDocument {
 ///
 /// [Reference](https://spec.graphql.org/October2021/#TypeSystemDefinition). This enum also covers
 /// [extensions](https://spec.graphql.org/October2021/#TypeSystemExtension).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TypeSystemDefinition {
     /// The definition of the schema of the service.
     Schema(Positioned<SchemaDefinition>),
@@ -32,7 +37,7 @@ pub enum TypeSystemDefinition {
 ///
 /// [Reference](https://spec.graphql.org/October2021/#SchemaDefinition). This also covers
 /// [extensions](https://spec.graphql.org/October2021/#SchemaExtension).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SchemaDefinition {
     /// Whether the schema is an extension of another schema.
     pub extend: bool,
@@ -50,7 +55,7 @@ pub struct SchemaDefinition {
 ///
 /// [Reference](https://spec.graphql.org/October2021/#TypeDefinition). This also covers
 /// [extensions](https://spec.graphql.org/October2021/#TypeExtension).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TypeDefinition {
     /// Whether the type is an extension of another type.
     pub extend: bool,
@@ -66,7 +71,7 @@ pub struct TypeDefinition {
 }
 
 /// A kind of type; scalar, object, enum, etc.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TypeKind {
     /// A scalar type.
     Scalar,
@@ -85,7 +90,7 @@ pub enum TypeKind {
 /// The definition of an object type.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#ObjectType).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ObjectType {
     /// The interfaces implemented by the object.
     pub implements: Vec<Positioned<Name>>,
@@ -96,7 +101,7 @@ pub struct ObjectType {
 /// The definition of a field inside an object or interface.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#FieldDefinition).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FieldDefinition {
     /// The description of the field.
     pub description: Option<Positioned<String>>,
@@ -113,7 +118,7 @@ pub struct FieldDefinition {
 /// The definition of an interface type.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#InterfaceType).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct InterfaceType {
     /// The interfaces implemented by the interface.
     pub implements: Vec<Positioned<Name>>,
@@ -124,7 +129,7 @@ pub struct InterfaceType {
 /// The definition of a union type.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#UnionType).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UnionType {
     /// The member types of the union.
     pub members: Vec<Positioned<Name>>,
@@ -133,7 +138,7 @@ pub struct UnionType {
 /// The definition of an enum.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#EnumType).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EnumType {
     /// The possible values of the enum.
     pub values: Vec<Positioned<EnumValueDefinition>>,
@@ -142,7 +147,7 @@ pub struct EnumType {
 /// The definition of a value inside an enum.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#EnumValueDefinition).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EnumValueDefinition {
     /// The description of the argument.
     pub description: Option<Positioned<String>>,
@@ -155,7 +160,7 @@ pub struct EnumValueDefinition {
 /// The definition of an input object.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#InputObjectType).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct InputObjectType {
     /// The fields of the input object.
     pub fields: Vec<Positioned<InputValueDefinition>>,
@@ -164,7 +169,7 @@ pub struct InputObjectType {
 /// The definition of an input value inside the arguments of a field.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#InputValueDefinition).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct InputValueDefinition {
     /// The description of the argument.
     pub description: Option<Positioned<String>>,
@@ -181,7 +186,7 @@ pub struct InputValueDefinition {
 /// The definition of a directive in a service.
 ///
 /// [Reference](https://spec.graphql.org/October2021/#DirectiveDefinition).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DirectiveDefinition {
     /// The description of the directive.
     pub description: Option<Positioned<String>>,
@@ -244,3 +249,109 @@ pub enum DirectiveLocation {
     /// A [variable definition](struct.VariableDefinition.html).
     VariableDefinition,
 }
+
+impl DirectiveLocation {
+    /// Get the name of the location, as written in a directive definition, such as `FIELD`.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Query => "QUERY",
+            Self::Mutation => "MUTATION",
+            Self::Subscription => "SUBSCRIPTION",
+            Self::Field => "FIELD",
+            Self::FragmentDefinition => "FRAGMENT_DEFINITION",
+            Self::FragmentSpread => "FRAGMENT_SPREAD",
+            Self::InlineFragment => "INLINE_FRAGMENT",
+            Self::Schema => "SCHEMA",
+            Self::Scalar => "SCALAR",
+            Self::Object => "OBJECT",
+            Self::FieldDefinition => "FIELD_DEFINITION",
+            Self::ArgumentDefinition => "ARGUMENT_DEFINITION",
+            Self::Interface => "INTERFACE",
+            Self::Union => "UNION",
+            Self::Enum => "ENUM",
+            Self::EnumValue => "ENUM_VALUE",
+            Self::InputObject => "INPUT_OBJECT",
+            Self::InputFieldDefinition => "INPUT_FIELD_DEFINITION",
+            Self::VariableDefinition => "VARIABLE_DEFINITION",
+        }
+    }
+
+    /// Whether the location is in an executable document, rather than in a type system
+    /// document.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#ExecutableDirectiveLocation).
+    #[must_use]
+    pub fn is_executable(&self) -> bool {
+        matches!(
+            self,
+            Self::Query
+                | Self::Mutation
+                | Self::Subscription
+                | Self::Field
+                | Self::FragmentDefinition
+                | Self::FragmentSpread
+                | Self::InlineFragment
+                | Self::VariableDefinition
+        )
+    }
+}
+
+/// Directive location parsing error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveLocationParseError {
+    /// The unknown location name.
+    pub name: String,
+}
+
+impl Display for DirectiveLocationParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown directive location {}", self.name)
+    }
+}
+
+impl std::error::Error for DirectiveLocationParseError {}
+
+impl FromStr for DirectiveLocation {
+    type Err = DirectiveLocationParseError;
+
+    /// Parse the name of a location, as written in a directive definition, such as `FIELD`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(match name {
+            "QUERY" => Self::Query,
+            "MUTATION" => Self::Mutation,
+            "SUBSCRIPTION" => Self::Subscription,
+            "FIELD" => Self::Field,
+            "FRAGMENT_DEFINITION" => Self::FragmentDefinition,
+            "FRAGMENT_SPREAD" => Self::FragmentSpread,
+            "INLINE_FRAGMENT" => Self::InlineFragment,
+            "SCHEMA" => Self::Schema,
+            "SCALAR" => Self::Scalar,
+            "OBJECT" => Self::Object,
+            "FIELD_DEFINITION" => Self::FieldDefinition,
+            "ARGUMENT_DEFINITION" => Self::ArgumentDefinition,
+            "INTERFACE" => Self::Interface,
+            "UNION" => Self::Union,
+            "ENUM" => Self::Enum,
+            "ENUM_VALUE" => Self::EnumValue,
+            "INPUT_OBJECT" => Self::InputObject,
+            "INPUT_FIELD_DEFINITION" => Self::InputFieldDefinition,
+            "VARIABLE_DEFINITION" => Self::VariableDefinition,
+            _ => {
+                return Err(DirectiveLocationParseError {
+                    name: name.to_owned(),
+                })
+            }
+        })
+    }
+}
+
+impl From<OperationType> for DirectiveLocation {
+    fn from(ty: OperationType) -> Self {
+        match ty {
+            OperationType::Query => Self::Query,
+            OperationType::Mutation => Self::Mutation,
+            OperationType::Subscription => Self::Subscription,
+        }
+    }
+}
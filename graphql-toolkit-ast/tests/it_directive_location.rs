@@ -0,0 +1,72 @@
+use graphql_toolkit_ast::{DirectiveLocation, DocumentOperations, Selection};
+use graphql_toolkit_parser::parse_query;
+
+#[test]
+fn executable_nodes_map_to_their_directive_locations() {
+    //* Given
+    let document = parse_query(
+        r#"mutation ($id: ID!) { update(id: $id) { ...fields ... on User { name } } }
+        fragment fields on User { id }"#,
+    )
+    .expect("failed to parse document");
+
+    //* When
+    let DocumentOperations::Single(operation) = &document.operations else {
+        panic!("expected a single operation");
+    };
+    let Selection::Field(field) = &operation.node.selection_set.node.items[0].node else {
+        panic!("expected a field");
+    };
+    let selections = &field.node.selection_set.node.items;
+    let fragment = &document.fragments["fields"];
+
+    //* Then
+    assert_eq!(
+        operation.node.directive_location(),
+        DirectiveLocation::Mutation
+    );
+    assert_eq!(
+        operation.node.variable_definitions[0]
+            .node
+            .directive_location(),
+        DirectiveLocation::VariableDefinition
+    );
+    assert_eq!(field.node.directive_location(), DirectiveLocation::Field);
+    assert_eq!(
+        selections[0].node.directive_location(),
+        DirectiveLocation::FragmentSpread
+    );
+    assert_eq!(
+        selections[1].node.directive_location(),
+        DirectiveLocation::InlineFragment
+    );
+    assert_eq!(
+        fragment.node.directive_location(),
+        DirectiveLocation::FragmentDefinition
+    );
+    assert!(fragment.node.directive_location().is_executable());
+    assert!(!DirectiveLocation::FieldDefinition.is_executable());
+    assert_eq!(
+        DirectiveLocation::FragmentSpread.as_str(),
+        "FRAGMENT_SPREAD"
+    );
+}
+
+#[test]
+fn parse_directive_locations_from_their_names() {
+    //* Then
+    assert_eq!(
+        "INPUT_FIELD_DEFINITION".parse(),
+        Ok(DirectiveLocation::InputFieldDefinition)
+    );
+    assert_eq!(
+        DirectiveLocation::VariableDefinition.as_str().parse(),
+        Ok(DirectiveLocation::VariableDefinition)
+    );
+    assert_eq!(
+        "field"
+            .parse::<DirectiveLocation>()
+            .map_err(|err| err.to_string()),
+        Err("unknown directive location field".to_owned())
+    );
+}
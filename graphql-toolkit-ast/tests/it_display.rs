@@ -0,0 +1,155 @@
+use graphql_toolkit_ast::{DocumentOperations, Selection};
+use graphql_toolkit_parser::{parse_query, parse_schema};
+use graphql_toolkit_writer::display;
+
+#[test]
+fn display_query_shorthand() {
+    //* Given
+    let document = parse_query(r#"{user(id:1){name,friends(first:10){name}}}"#)
+        .expect("failed to parse document");
+
+    //* When
+    let output = document.to_string();
+
+    //* Then
+    assert_eq!(output, "{user(id:1){name,friends(first:10){name}}}");
+}
+
+#[test]
+fn display_matches_the_writer_compact_output() {
+    //* Given
+    let document = parse_query(
+        r#"
+        query User($id: ID! = 1, $size: Int, $note: String = "a\tb") @live {
+          user(id: $id) { ...UserFields ... on Admin @include(if: true) { role } }
+        }
+        mutation Rename($name: String) {
+          rename(input: {name: $name, tags: [A, B], ratio: 0.5, note: "\"x\"\ny\\z\u0001"})
+        }
+        fragment UserFields on User { name avatar(size: $size) }
+        fragment AdminFields on Admin { role }
+        "#,
+    )
+    .expect("failed to parse document");
+
+    //* When
+    let output = document.to_string();
+
+    //* Then
+    assert_eq!(output, display(&document).to_string());
+    assert!(
+        output.starts_with("query User"),
+        "operations are not in source order"
+    );
+    assert!(
+        output.ends_with("fragment AdminFields on Admin{role}"),
+        "fragments are not in source order"
+    );
+}
+
+#[test]
+fn display_named_query_with_variables_and_directives() {
+    //* Given
+    let document = parse_query(
+        r#"query MyQuery($id:ID!="1",$flag:Boolean)@live{user(id:$id)@include(if:$flag){alias:name}}"#,
+    )
+    .expect("failed to parse document");
+
+    //* When
+    let output = document.to_string();
+
+    //* Then
+    assert_eq!(
+        output,
+        r#"query MyQuery($id:ID!="1",$flag:Boolean)@live{user(id:$id)@include(if:$flag){alias:name}}"#
+    );
+    parse_query(&output).expect("invalid document");
+}
+
+#[test]
+fn display_query_with_fragments() {
+    //* Given
+    let document =
+        parse_query(r#"query{...on Query{...UserFields}}fragment UserFields on User@dir{id}"#)
+            .expect("failed to parse document");
+
+    //* When
+    let output = document.to_string();
+
+    //* Then
+    assert_eq!(
+        output,
+        "{...on Query{...UserFields}}fragment UserFields on User@dir{id}"
+    );
+    parse_query(&output).expect("invalid document");
+}
+
+#[test]
+fn display_selection() {
+    //* Given
+    let document =
+        parse_query(r#"{field(arg:[1,2],obj:{key:ENUM})}"#).expect("failed to parse document");
+    let operation = match document.operations {
+        DocumentOperations::Single(operation) => operation.node,
+        _ => panic!("Expected a single operation"),
+    };
+
+    //* When
+    let output = match &operation.selection_set.node.items[0].node {
+        Selection::Field(field) => field.to_string(),
+        _ => panic!("Expected a field selection"),
+    };
+
+    //* Then
+    assert_eq!(output, "field(arg:[1,2],obj:{key:ENUM})");
+}
+
+#[test]
+fn display_service_document() {
+    //* Given
+    let document = parse_schema(
+        r#"
+        schema { query: Query mutation: Mutation }
+
+        "A user"
+        type User implements Node & Entity @key(fields: "id") {
+          id: ID!
+          "The \"display\" name"
+          name(format: Format = SHORT): String @deprecated
+        }
+
+        extend type User { age: Int }
+
+        union SearchResult = User | Post
+
+        enum Format { SHORT LONG }
+
+        input UserFilter { name: String = "" ids: [ID!] }
+
+        scalar DateTime @specifiedBy(url: "https://example.com")
+
+        directive @key(fields: String!) repeatable on OBJECT | INTERFACE
+        "#,
+    )
+    .expect("failed to parse document");
+
+    //* When
+    let output = document.to_string();
+
+    //* Then
+    assert_eq!(
+        output,
+        [
+            "schema{query:Query,mutation:Mutation}",
+            r#""A user" type User implements Node&Entity@key(fields:"id"){id:ID!,"The \"display\" name" name(format:Format=SHORT):String@deprecated}"#,
+            "extend type User{age:Int}",
+            "union SearchResult=User|Post",
+            "enum Format{SHORT,LONG}",
+            r#"input UserFilter{name:String="",ids:[ID!]}"#,
+            r#"scalar DateTime@specifiedBy(url:"https://example.com")"#,
+            "directive @key(fields:String!) repeatable on OBJECT|INTERFACE",
+        ]
+        .join("\n")
+    );
+    parse_schema(&output).expect("invalid document");
+}
@@ -0,0 +1,64 @@
+use graphql_toolkit_ast::{FilePos, Pos, SourceMap};
+use indoc::indoc;
+
+#[test]
+fn resolve_positions_to_files_and_snippets() {
+    //* Given
+    let mut sources = SourceMap::new();
+    let users = sources.add_file("users.graphql", "type User {\n  id: ID!\n}\n");
+    let posts = sources.add_file("posts.graphql", "type Post {\n\ttitle: String\n}\n");
+
+    //* When
+    let pos = FilePos::new(posts, Pos { line: 2, column: 9 });
+
+    //* Then
+    assert_eq!(sources.find("users.graphql"), Some(users));
+    assert_eq!(sources.find("schema.graphql"), None);
+    assert_eq!(sources.get(users).unwrap().line(2), Some("  id: ID!"));
+    assert_eq!(sources.location(pos).unwrap(), "posts.graphql:2:9");
+    assert_eq!(
+        sources.snippet(pos).unwrap(),
+        "2 | \ttitle: String\n  | \t       ^"
+    );
+    assert_eq!(
+        sources.snippet(FilePos::new(users, Pos { line: 9, column: 1 })),
+        None
+    );
+    assert_eq!(
+        sources
+            .files()
+            .map(|(_, file)| file.path())
+            .collect::<Vec<_>>(),
+        ["users.graphql", "posts.graphql"]
+    );
+}
+
+#[test]
+fn render_parser_errors_with_their_file() {
+    //* Given
+    let mut sources = SourceMap::new();
+    sources.add_file("query.graphql", "type Query { user: User }");
+    let input = indoc! {r#"
+        type User { id: ID! }
+        schema { query: Query query: Root }
+    "#};
+
+    //* When
+    let (users, result) =
+        graphql_toolkit_parser::parse_schema_file(&mut sources, "users.graphql", input);
+    let err = result.expect_err("parsing succeeded");
+    let diagnostic = sources.render(users, &err, err.positions());
+
+    //* Then
+    assert_eq!(
+        diagnostic,
+        indoc! {"
+            multiple query roots in schema definition
+             --> users.graphql:2:23
+            2 | schema { query: Query query: Root }
+              |                       ^
+             --> users.graphql:2:1
+            2 | schema { query: Query query: Root }
+              | ^"}
+    );
+}
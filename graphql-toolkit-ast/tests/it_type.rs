@@ -0,0 +1,39 @@
+use graphql_toolkit_ast::Type;
+
+/// Test helper function to create a type from a type string.
+///
+/// # Panics
+/// The function asserts that the input is a valid type string.
+fn ty(input: &str) -> Type {
+    Type::new(input).expect("invalid type")
+}
+
+#[test]
+fn named_type_and_list_depth_unwrap_the_wrappers() {
+    //* Given
+    let named = ty("String!");
+    let nested = ty("[[String]!]");
+
+    //* Then
+    assert_eq!(named.named_type(), "String");
+    assert_eq!(named.list_depth(), 0);
+    assert!(!named.is_list());
+    assert_eq!(nested.named_type(), "String");
+    assert_eq!(nested.list_depth(), 2);
+    assert!(nested.is_list());
+    assert_eq!(nested.item_type(), Some(&ty("[String]!")));
+    assert_eq!(ty("[String!]!").to_nullable(), ty("[String!]"));
+}
+
+#[test]
+fn subtypes_are_at_least_as_strict() {
+    //* Then
+    assert!(ty("Int").is_subtype_of(&ty("Int")));
+    assert!(ty("Int!").is_subtype_of(&ty("Int")));
+    assert!(ty("[Int!]!").is_subtype_of(&ty("[Int]")));
+    assert!(!ty("Int").is_subtype_of(&ty("Int!")));
+    assert!(!ty("[Int]").is_subtype_of(&ty("[Int!]")));
+    assert!(!ty("Int").is_subtype_of(&ty("[Int]")));
+    assert!(!ty("[Int]").is_subtype_of(&ty("Int")));
+    assert!(!ty("Int").is_subtype_of(&ty("Float")));
+}
@@ -1 +1,10 @@
 //! A GraphQL document composer
+#![warn(missing_docs)]
+#![allow(clippy::uninlined_format_args)]
+#![forbid(unsafe_code)]
+
+pub use merge::{Conflict, ConflictPolicy, MergedDocument, Merger};
+pub use spans::SourceSpans;
+
+mod merge;
+mod spans;
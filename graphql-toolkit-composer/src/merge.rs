@@ -0,0 +1,500 @@
+//! Merging of the service documents of a schema split across multiple sources.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use graphql_toolkit_ast::{
+    ConstDirective, DirectiveDefinition, EnumValueDefinition, FieldDefinition, FileId, FilePos,
+    InputValueDefinition, Name, Pos, Positioned, SchemaDefinition, ServiceDocument, TypeDefinition,
+    TypeKind, TypeSystemDefinition,
+};
+
+use crate::spans::SourceSpans;
+
+/// How to resolve the definitions of a type, directive or schema found multiple times.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Report every duplicated definition as a conflict.
+    #[default]
+    Error,
+    /// Keep the first definition, and ignore the later ones.
+    FirstWins,
+    /// Merge the definitions of a type, combining their fields, values, members and interfaces.
+    ///
+    /// The fields, input fields and enum values defined multiple times must be identical, and so
+    /// must the duplicated directive definitions. The root operation types of the schema
+    /// definitions are combined too.
+    MergeIdentical,
+}
+
+/// Conflicting definitions found while merging the sources.
+///
+/// The positions of the definitions are resolved to their files through the source map of the
+/// merged sources. See [`SourceMap::render_at`](graphql_toolkit_ast::SourceMap::render_at).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Conflict {
+    /// The schema is defined in multiple sources.
+    SchemaDefinition {
+        /// The position of the first definition.
+        first: FilePos,
+        /// The position of the second definition.
+        second: FilePos,
+    },
+    /// A type is defined multiple times.
+    Type {
+        /// The name of the type.
+        name: Name,
+        /// The position of the first definition.
+        first: FilePos,
+        /// The position of the second definition.
+        second: FilePos,
+    },
+    /// A field, input field or enum value is defined differently in two definitions of a type.
+    Member {
+        /// The name of the type.
+        type_name: Name,
+        /// The name of the field, input field or enum value.
+        member: Name,
+        /// The position of the first definition.
+        first: FilePos,
+        /// The position of the second definition.
+        second: FilePos,
+    },
+    /// A directive is defined multiple times.
+    Directive {
+        /// The name of the directive.
+        name: Name,
+        /// The position of the first definition.
+        first: FilePos,
+        /// The position of the second definition.
+        second: FilePos,
+    },
+}
+
+impl Conflict {
+    /// Get the positions of the conflicting definitions.
+    ///
+    /// The positions are ordered from most important to least important.
+    #[must_use]
+    pub fn positions(&self) -> Vec<FilePos> {
+        match self {
+            Self::SchemaDefinition { first, second }
+            | Self::Type { first, second, .. }
+            | Self::Member { first, second, .. }
+            | Self::Directive { first, second, .. } => vec![*second, *first],
+        }
+    }
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SchemaDefinition { .. } => f.write_str("conflicting schema definitions"),
+            Self::Type { name, .. } => write!(f, "conflicting definitions of type {}", name),
+            Self::Member {
+                type_name, member, ..
+            } => write!(f, "conflicting definitions of {}.{}", type_name, member),
+            Self::Directive { name, .. } => {
+                write!(f, "conflicting definitions of directive @{}", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Conflict {}
+
+/// A service document merged from multiple sources, with the files of its positions.
+#[derive(Debug, Clone)]
+pub struct MergedDocument {
+    /// The merged document.
+    ///
+    /// The lines of its positions are relative to the concatenation of the sources. Resolve them
+    /// to their files with [`MergedDocument::spans`].
+    pub document: ServiceDocument,
+    /// The lines of each source in the merged document.
+    pub spans: SourceSpans,
+}
+
+/// A merger of the service documents of a schema split across multiple sources, such as the SDL
+/// modules owned by different teams.
+///
+/// The definitions are merged in the order of the sources. The extensions are kept as-is, to be
+/// applied once the schema is built.
+///
+/// ```
+/// # use graphql_toolkit_ast::SourceMap;
+/// # use graphql_toolkit_composer::{ConflictPolicy, Merger};
+/// # use graphql_toolkit_parser::parse_schema_file;
+/// let mut sources = SourceMap::new();
+/// let (users, document) = parse_schema_file(&mut sources, "users.graphql", "type Query { user: ID }");
+/// let merger = Merger::new().add_source(users, document.unwrap());
+/// let (posts, document) = parse_schema_file(&mut sources, "posts.graphql", "type Query { post: ID }");
+/// let merger = merger.add_source(posts, document.unwrap());
+///
+/// let merged = merger
+///     .conflict_policy(ConflictPolicy::MergeIdentical)
+///     .merge()
+///     .unwrap();
+/// assert_eq!(merged.document.definitions.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Merger {
+    policy: ConflictPolicy,
+    sources: Vec<(FileId, ServiceDocument)>,
+}
+
+impl Merger {
+    /// Create a new merger, reporting every duplicated definition as a conflict.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the policy to resolve the definitions found multiple times.
+    #[must_use]
+    pub fn conflict_policy(mut self, policy: ConflictPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Add a source document, parsed from the file identified by `file` in the reported
+    /// conflicts.
+    #[must_use]
+    pub fn add_source(mut self, file: FileId, document: ServiceDocument) -> Self {
+        self.sources.push((file, document));
+        self
+    }
+
+    /// Merge the source documents into a single document.
+    ///
+    /// The positions of the merged document are moved past the lines of the previous sources,
+    /// so that they resolve to the file of their source. See [`SourceSpans`].
+    ///
+    /// # Errors
+    ///
+    /// Fails with all the conflicts found, according to the conflict policy.
+    pub fn merge(self) -> Result<MergedDocument, Vec<Conflict>> {
+        let mut spans = SourceSpans::default();
+        let documents = self
+            .sources
+            .into_iter()
+            .map(|(file, mut document)| (spans.push(file, &mut document), document))
+            .collect::<Vec<_>>();
+        let mut state = MergeState {
+            policy: self.policy,
+            spans: &spans,
+            definitions: Vec::new(),
+            schema: None,
+            types: HashMap::new(),
+            directives: HashMap::new(),
+            member_sources: HashMap::new(),
+            conflicts: Vec::new(),
+        };
+
+        for (source, document) in documents {
+            for def in document.definitions {
+                state.add_definition(source, def);
+            }
+        }
+
+        if !state.conflicts.is_empty() {
+            return Err(state.conflicts);
+        }
+
+        let document = ServiceDocument {
+            definitions: state.definitions.into_iter().map(|(_, def)| def).collect(),
+        };
+        Ok(MergedDocument { document, spans })
+    }
+}
+
+/// The state of a merge.
+///
+/// The sources are identified by their index, and the definitions are tracked by their index in
+/// the merged definitions.
+struct MergeState<'a> {
+    policy: ConflictPolicy,
+    spans: &'a SourceSpans,
+    definitions: Vec<(usize, TypeSystemDefinition)>,
+    schema: Option<usize>,
+    types: HashMap<Name, usize>,
+    directives: HashMap<Name, usize>,
+    /// The sources of the members merged into a type from a different source than its first
+    /// definition.
+    member_sources: HashMap<(Name, Name), usize>,
+    conflicts: Vec<Conflict>,
+}
+
+impl MergeState<'_> {
+    fn add_definition(&mut self, source: usize, def: TypeSystemDefinition) {
+        match def {
+            TypeSystemDefinition::Schema(schema) if !schema.node.extend => match self.schema {
+                Some(index) => self.merge_schema(index, source, schema),
+                None => {
+                    self.schema = Some(self.definitions.len());
+                    self.push(source, TypeSystemDefinition::Schema(schema));
+                }
+            },
+            TypeSystemDefinition::Type(ty) if !ty.node.extend => {
+                match self.types.get(&ty.node.name.node) {
+                    Some(&index) => self.merge_type(index, source, ty),
+                    None => {
+                        self.types
+                            .insert(ty.node.name.node.clone(), self.definitions.len());
+                        self.push(source, TypeSystemDefinition::Type(ty));
+                    }
+                }
+            }
+            TypeSystemDefinition::Directive(directive) => {
+                match self.directives.get(&directive.node.name.node) {
+                    Some(&index) => self.merge_directive(index, source, directive),
+                    None => {
+                        self.directives
+                            .insert(directive.node.name.node.clone(), self.definitions.len());
+                        self.push(source, TypeSystemDefinition::Directive(directive));
+                    }
+                }
+            }
+            // Extensions
+            def => self.push(source, def),
+        }
+    }
+
+    fn push(&mut self, source: usize, def: TypeSystemDefinition) {
+        self.definitions.push((source, def));
+    }
+
+    fn merge_schema(&mut self, index: usize, source: usize, schema: Positioned<SchemaDefinition>) {
+        let (first_source, TypeSystemDefinition::Schema(first)) = &mut self.definitions[index]
+        else {
+            unreachable!("schema definitions are indexed");
+        };
+        let conflict = Conflict::SchemaDefinition {
+            first: self.spans.source_pos(*first_source, first.pos),
+            second: self.spans.source_pos(source, schema.pos),
+        };
+
+        match self.policy {
+            ConflictPolicy::Error => self.conflicts.push(conflict),
+            ConflictPolicy::FirstWins => {}
+            ConflictPolicy::MergeIdentical => {
+                let first = &mut first.node;
+                let schema = schema.node;
+                let mut is_conflict = false;
+                for (first, root) in [
+                    (&mut first.query, schema.query),
+                    (&mut first.mutation, schema.mutation),
+                    (&mut first.subscription, schema.subscription),
+                ] {
+                    match (first.as_ref(), root) {
+                        (Some(first), Some(root)) => is_conflict |= first.node != root.node,
+                        (None, root) => *first = root,
+                        (Some(_), None) => {}
+                    }
+                }
+                merge_directives(&mut first.directives, schema.directives);
+                if is_conflict {
+                    self.conflicts.push(conflict);
+                }
+            }
+        }
+    }
+
+    fn merge_type(&mut self, index: usize, source: usize, ty: Positioned<TypeDefinition>) {
+        let first_source = self.definitions[index].0;
+        let TypeSystemDefinition::Type(first) = &mut self.definitions[index].1 else {
+            unreachable!("type definitions are indexed");
+        };
+        let type_name = ty.node.name.node.clone();
+        let conflict = Conflict::Type {
+            name: type_name.clone(),
+            first: self.spans.source_pos(first_source, first.pos),
+            second: self.spans.source_pos(source, ty.pos),
+        };
+
+        match self.policy {
+            ConflictPolicy::Error => self.conflicts.push(conflict),
+            ConflictPolicy::FirstWins => {}
+            ConflictPolicy::MergeIdentical => {
+                let first = &mut first.node;
+                let ty = ty.node;
+                let is_same_description = match (&first.description, ty.description) {
+                    (Some(first), Some(description)) => first.node == description.node,
+                    (_, None) => true,
+                    (None, description) => {
+                        first.description = description;
+                        true
+                    }
+                };
+                merge_directives(&mut first.directives, ty.directives);
+
+                // The members are merged into the first definition, and the conflicting ones
+                // are collected to be reported once it is no longer borrowed.
+                let mut members = Vec::new();
+                let is_same_kind = match (&mut first.kind, ty.kind) {
+                    (TypeKind::Scalar, TypeKind::Scalar) => true,
+                    (TypeKind::Object(first), TypeKind::Object(object)) => {
+                        merge_names(&mut first.implements, object.implements);
+                        merge_members(&mut first.fields, object.fields, field_name, &mut members);
+                        true
+                    }
+                    (TypeKind::Interface(first), TypeKind::Interface(interface)) => {
+                        merge_names(&mut first.implements, interface.implements);
+                        merge_members(
+                            &mut first.fields,
+                            interface.fields,
+                            field_name,
+                            &mut members,
+                        );
+                        true
+                    }
+                    (TypeKind::Union(first), TypeKind::Union(union)) => {
+                        merge_names(&mut first.members, union.members);
+                        true
+                    }
+                    (TypeKind::Enum(first), TypeKind::Enum(enum_type)) => {
+                        merge_members(
+                            &mut first.values,
+                            enum_type.values,
+                            enum_value_name,
+                            &mut members,
+                        );
+                        true
+                    }
+                    (TypeKind::InputObject(first), TypeKind::InputObject(input)) => {
+                        merge_members(
+                            &mut first.fields,
+                            input.fields,
+                            input_value_name,
+                            &mut members,
+                        );
+                        true
+                    }
+                    _ => false,
+                };
+
+                if !is_same_kind || !is_same_description {
+                    self.conflicts.push(conflict);
+                    return;
+                }
+                for member in members {
+                    let key = (type_name.clone(), member.name.clone());
+                    match member.first {
+                        Some(first) => {
+                            let member_source =
+                                *self.member_sources.get(&key).unwrap_or(&first_source);
+                            self.conflicts.push(Conflict::Member {
+                                type_name: type_name.clone(),
+                                member: member.name,
+                                first: self.spans.source_pos(member_source, first),
+                                second: self.spans.source_pos(source, member.pos),
+                            });
+                        }
+                        None if source != first_source => {
+                            self.member_sources.insert(key, source);
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn merge_directive(
+        &mut self,
+        index: usize,
+        source: usize,
+        directive: Positioned<DirectiveDefinition>,
+    ) {
+        let (first_source, TypeSystemDefinition::Directive(first)) = &self.definitions[index]
+        else {
+            unreachable!("directive definitions are indexed");
+        };
+        let is_conflict = match self.policy {
+            ConflictPolicy::Error => true,
+            ConflictPolicy::FirstWins => false,
+            ConflictPolicy::MergeIdentical => first.node != directive.node,
+        };
+        if is_conflict {
+            self.conflicts.push(Conflict::Directive {
+                name: directive.node.name.node.clone(),
+                first: self.spans.source_pos(*first_source, first.pos),
+                second: self.spans.source_pos(source, directive.pos),
+            });
+        }
+    }
+}
+
+/// A member merged into a type definition.
+struct MergedMember {
+    name: Name,
+    pos: Pos,
+    /// The position of the first definition of the member, if it was already defined
+    /// differently.
+    first: Option<Pos>,
+}
+
+/// Merge the fields, input fields or enum values of a type definition into the first one.
+///
+/// The identical members are skipped, and the new ones are appended.
+fn merge_members<T: PartialEq>(
+    first: &mut Vec<Positioned<T>>,
+    members: Vec<Positioned<T>>,
+    name: fn(&T) -> &Name,
+    merged: &mut Vec<MergedMember>,
+) {
+    for member in members {
+        let existing = first
+            .iter()
+            .find(|existing| name(&existing.node) == name(&member.node));
+        match existing {
+            Some(existing) if existing.node == member.node => {}
+            Some(existing) => merged.push(MergedMember {
+                name: name(&member.node).clone(),
+                pos: member.pos,
+                first: Some(existing.pos),
+            }),
+            None => {
+                merged.push(MergedMember {
+                    name: name(&member.node).clone(),
+                    pos: member.pos,
+                    first: None,
+                });
+                first.push(member);
+            }
+        }
+    }
+}
+
+/// Merge the union members or implemented interfaces of a type definition into the first one.
+fn merge_names(first: &mut Vec<Positioned<Name>>, names: Vec<Positioned<Name>>) {
+    for name in names {
+        if !first.contains(&name) {
+            first.push(name);
+        }
+    }
+}
+
+/// Merge the directives applied to a definition into the first one, skipping the identical ones.
+fn merge_directives(
+    first: &mut Vec<Positioned<ConstDirective>>,
+    mut directives: Vec<Positioned<ConstDirective>>,
+) {
+    directives.retain(|directive| !first.contains(directive));
+    first.append(&mut directives);
+}
+
+fn field_name(field: &FieldDefinition) -> &Name {
+    &field.name.node
+}
+
+fn enum_value_name(value: &EnumValueDefinition) -> &Name {
+    &value.value.node
+}
+
+fn input_value_name(value: &InputValueDefinition) -> &Name {
+    &value.name.node
+}
@@ -0,0 +1,240 @@
+//! Attribution of the positions of a merged document to the files of its sources.
+
+use graphql_toolkit_ast::{
+    ConstDirective, DirectiveDefinition, FieldDefinition, FileId, FilePos, InputValueDefinition,
+    Pos, Positioned, SchemaDefinition, ServiceDocument, TypeDefinition, TypeKind,
+    TypeSystemDefinition,
+};
+
+/// A table of the lines of each source in a merged document.
+///
+/// The positions of the AST nodes only carry a line and a column, so the positions of the
+/// definitions merged from different files would overlap. The merger moves the lines of each
+/// source past the lines of the previous sources, as if the sources were concatenated, and records
+/// the lines of each source in this table.
+///
+/// The positions reported about the merged document, such as the schema building and validation
+/// errors, are resolved back to their files with [`SourceSpans::resolve`].
+///
+/// ```
+/// # use graphql_toolkit_ast::{SourceMap, TypeSystemDefinition};
+/// # use graphql_toolkit_composer::Merger;
+/// # use graphql_toolkit_parser::parse_schema_file;
+/// let mut sources = SourceMap::new();
+/// let (users, document) = parse_schema_file(&mut sources, "users.graphql", "type User { id: ID }");
+/// let merger = Merger::new().add_source(users, document.unwrap());
+/// let (posts, document) = parse_schema_file(&mut sources, "posts.graphql", "type Post { id: ID }");
+/// let merger = merger.add_source(posts, document.unwrap());
+///
+/// let merged = merger.merge().unwrap();
+/// let TypeSystemDefinition::Type(post) = &merged.document.definitions[1] else {
+///     unreachable!()
+/// };
+/// let pos = merged.spans.resolve(post.pos).unwrap();
+/// assert_eq!(sources.location(pos).unwrap(), "posts.graphql:1:1");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceSpans {
+    /// The lines of the sources, in source order.
+    spans: Vec<SourceSpan>,
+}
+
+/// The lines of a source in a merged document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SourceSpan {
+    file: FileId,
+    /// The number of lines before the first line of the source.
+    offset: usize,
+    /// The number of lines of the source.
+    lines: usize,
+}
+
+impl SourceSpans {
+    /// Resolve a position in the merged document to the position in the file of its source.
+    ///
+    /// Returns `None` if the position is out of the sources, such as the default position of the
+    /// nodes that were not parsed.
+    #[must_use]
+    pub fn resolve(&self, pos: Pos) -> Option<FilePos> {
+        let index = self
+            .spans
+            .partition_point(|span| span.offset + span.lines < pos.line);
+        let span = self.spans.get(index)?;
+        if pos.line <= span.offset {
+            return None;
+        }
+
+        Some(FilePos::new(
+            span.file,
+            Pos {
+                line: pos.line - span.offset,
+                column: pos.column,
+            },
+        ))
+    }
+
+    /// Move the lines of a source document past the lines of the previous sources, and record
+    /// them.
+    ///
+    /// Returns the index of the source.
+    pub(crate) fn push(&mut self, file: FileId, document: &mut ServiceDocument) -> usize {
+        let offset = self.spans.last().map_or(0, |span| span.offset + span.lines);
+        let mut lines = 0;
+        for def in &mut document.definitions {
+            visit_definition(def, &mut |pos| {
+                // The default positions are out of the source.
+                if pos.line > 0 {
+                    lines = lines.max(pos.line);
+                    pos.line += offset;
+                }
+            });
+        }
+
+        self.spans.push(SourceSpan {
+            file,
+            offset,
+            lines,
+        });
+        self.spans.len() - 1
+    }
+
+    /// Get the position in the file of the source with the given index.
+    pub(crate) fn source_pos(&self, source: usize, pos: Pos) -> FilePos {
+        let span = self.spans[source];
+        FilePos::new(
+            span.file,
+            Pos {
+                line: pos.line.saturating_sub(span.offset),
+                column: pos.column,
+            },
+        )
+    }
+}
+
+/// Visit the positions of a definition, and of its nested nodes.
+fn visit_definition(def: &mut TypeSystemDefinition, f: &mut impl FnMut(&mut Pos)) {
+    match def {
+        TypeSystemDefinition::Schema(schema) => visit_schema(schema, f),
+        TypeSystemDefinition::Type(ty) => visit_type(ty, f),
+        TypeSystemDefinition::Directive(directive) => visit_directive_definition(directive, f),
+    }
+}
+
+fn visit_schema(schema: &mut Positioned<SchemaDefinition>, f: &mut impl FnMut(&mut Pos)) {
+    f(&mut schema.pos);
+    let schema = &mut schema.node;
+    visit_directives(&mut schema.directives, f);
+    for root in [
+        &mut schema.query,
+        &mut schema.mutation,
+        &mut schema.subscription,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        f(&mut root.pos);
+    }
+}
+
+fn visit_type(ty: &mut Positioned<TypeDefinition>, f: &mut impl FnMut(&mut Pos)) {
+    f(&mut ty.pos);
+    let ty = &mut ty.node;
+    if let Some(description) = &mut ty.description {
+        f(&mut description.pos);
+    }
+    f(&mut ty.name.pos);
+    visit_directives(&mut ty.directives, f);
+
+    match &mut ty.kind {
+        TypeKind::Scalar => {}
+        TypeKind::Object(object) => {
+            object
+                .implements
+                .iter_mut()
+                .for_each(|name| f(&mut name.pos));
+            visit_fields(&mut object.fields, f);
+        }
+        TypeKind::Interface(interface) => {
+            interface
+                .implements
+                .iter_mut()
+                .for_each(|name| f(&mut name.pos));
+            visit_fields(&mut interface.fields, f);
+        }
+        TypeKind::Union(union) => union.members.iter_mut().for_each(|name| f(&mut name.pos)),
+        TypeKind::Enum(enum_type) => {
+            for value in &mut enum_type.values {
+                f(&mut value.pos);
+                let value = &mut value.node;
+                if let Some(description) = &mut value.description {
+                    f(&mut description.pos);
+                }
+                f(&mut value.value.pos);
+                visit_directives(&mut value.directives, f);
+            }
+        }
+        TypeKind::InputObject(input) => visit_input_values(&mut input.fields, f),
+    }
+}
+
+fn visit_fields(fields: &mut [Positioned<FieldDefinition>], f: &mut impl FnMut(&mut Pos)) {
+    for field in fields {
+        f(&mut field.pos);
+        let field = &mut field.node;
+        if let Some(description) = &mut field.description {
+            f(&mut description.pos);
+        }
+        f(&mut field.name.pos);
+        visit_input_values(&mut field.arguments, f);
+        f(&mut field.ty.pos);
+        visit_directives(&mut field.directives, f);
+    }
+}
+
+fn visit_input_values(
+    values: &mut [Positioned<InputValueDefinition>],
+    f: &mut impl FnMut(&mut Pos),
+) {
+    for value in values {
+        f(&mut value.pos);
+        let value = &mut value.node;
+        if let Some(description) = &mut value.description {
+            f(&mut description.pos);
+        }
+        f(&mut value.name.pos);
+        f(&mut value.ty.pos);
+        if let Some(default_value) = &mut value.default_value {
+            f(&mut default_value.pos);
+        }
+        visit_directives(&mut value.directives, f);
+    }
+}
+
+fn visit_directive_definition(
+    directive: &mut Positioned<DirectiveDefinition>,
+    f: &mut impl FnMut(&mut Pos),
+) {
+    f(&mut directive.pos);
+    let directive = &mut directive.node;
+    if let Some(description) = &mut directive.description {
+        f(&mut description.pos);
+    }
+    f(&mut directive.name.pos);
+    visit_input_values(&mut directive.arguments, f);
+    directive
+        .locations
+        .iter_mut()
+        .for_each(|location| f(&mut location.pos));
+}
+
+fn visit_directives(directives: &mut [Positioned<ConstDirective>], f: &mut impl FnMut(&mut Pos)) {
+    for directive in directives {
+        f(&mut directive.pos);
+        let directive = &mut directive.node;
+        f(&mut directive.name.pos);
+        for (name, value) in &mut directive.arguments {
+            f(&mut name.pos);
+            f(&mut value.pos);
+        }
+    }
+}
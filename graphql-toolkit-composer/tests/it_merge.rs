@@ -0,0 +1,301 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{
+    FilePos, Name, Pos, ServiceDocument, SourceMap, TypeKind, TypeSystemDefinition,
+};
+use graphql_toolkit_composer::{Conflict, ConflictPolicy, MergedDocument, Merger};
+use indoc::indoc;
+
+/// Test helper function to parse the given sources into a source map, and merge them with the
+/// given conflict policy.
+///
+/// # Panics
+/// The function asserts that the sources are valid SDL.
+fn merge(
+    policy: ConflictPolicy,
+    sources: &[(&str, &str)],
+) -> (SourceMap, Result<MergedDocument, Vec<Conflict>>) {
+    let mut source_map = SourceMap::new();
+    let merger = sources.iter().fold(
+        Merger::new().conflict_policy(policy),
+        |merger, (path, input)| {
+            let (file, document) =
+                graphql_toolkit_parser::parse_schema_file(&mut source_map, *path, *input);
+            merger.add_source(file, document.expect("schema parsing failed"))
+        },
+    );
+    (source_map, merger.merge())
+}
+
+/// Test helper function to describe a conflict with the locations of its definitions, in source
+/// order.
+fn describe(sources: &SourceMap, conflict: &Conflict) -> String {
+    let locations = conflict
+        .positions()
+        .into_iter()
+        .rev()
+        .map(|pos| sources.location(pos).expect("position out of the sources"))
+        .collect::<Vec<_>>();
+    format!("{} at {}", conflict, locations.join(" and "))
+}
+
+/// Test helper function to get the names of the fields of an object type in a document.
+///
+/// # Panics
+/// The function asserts that the document defines the object type.
+fn field_names<'a>(document: &'a ServiceDocument, type_name: &str) -> Vec<&'a str> {
+    document
+        .definitions
+        .iter()
+        .find_map(|def| match def {
+            TypeSystemDefinition::Type(ty) if ty.node.name.node == type_name && !ty.node.extend => {
+                match &ty.node.kind {
+                    TypeKind::Object(object) => Some(
+                        object
+                            .fields
+                            .iter()
+                            .map(|field| field.node.name.node.as_str())
+                            .collect(),
+                    ),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .expect("object type not found")
+}
+
+const USERS: &str = indoc! {r#"
+    type Query { user(id: ID!): User }
+    type User { id: ID! name: String }
+    directive @tag(name: String!) on FIELD_DEFINITION
+"#};
+
+const POSTS: &str = indoc! {r#"
+    type Query { post(id: ID!): Post }
+    type Post { id: ID! author: User }
+    type User { id: ID! posts: [Post] }
+    directive @tag(name: String!) on FIELD_DEFINITION
+    extend type Post { title: String }
+"#};
+
+#[test]
+fn disjoint_sources_are_concatenated() {
+    //* When
+    let (_, result) = merge(
+        ConflictPolicy::Error,
+        &[
+            ("users.graphql", "type User { id: ID! }"),
+            (
+                "query.graphql",
+                "type Query { user: User }\nextend type User { name: String }",
+            ),
+        ],
+    );
+
+    //* Then
+    assert_matches!(result, Ok(MergedDocument { document, .. }) => {
+        assert_eq!(document.definitions.len(), 3);
+        assert_eq!(field_names(&document, "User"), ["id"]);
+    });
+}
+
+#[test]
+fn duplicated_definitions_are_conflicts_by_default() {
+    //* When
+    let (sources, result) = merge(
+        ConflictPolicy::Error,
+        &[("users.graphql", USERS), ("posts.graphql", POSTS)],
+    );
+
+    //* Then
+    let conflicts = result.expect_err("merge succeeded");
+    let messages = conflicts
+        .iter()
+        .map(|conflict| describe(&sources, conflict))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "conflicting definitions of type Query at users.graphql:1:1 and posts.graphql:1:1",
+            "conflicting definitions of type User at users.graphql:2:1 and posts.graphql:3:1",
+            "conflicting definitions of directive @tag at users.graphql:3:1 and posts.graphql:4:1",
+        ]
+    );
+    assert_eq!(
+        conflicts[1],
+        Conflict::Type {
+            name: Name::new("User"),
+            first: FilePos::new(
+                sources.find("users.graphql").unwrap(),
+                Pos { line: 2, column: 1 }
+            ),
+            second: FilePos::new(
+                sources.find("posts.graphql").unwrap(),
+                Pos { line: 3, column: 1 }
+            ),
+        }
+    );
+}
+
+#[test]
+fn first_definitions_win() {
+    //* When
+    let (_, result) = merge(
+        ConflictPolicy::FirstWins,
+        &[("users.graphql", USERS), ("posts.graphql", POSTS)],
+    );
+
+    //* Then
+    assert_matches!(result, Ok(MergedDocument { document, .. }) => {
+        assert_eq!(field_names(&document, "Query"), ["user"]);
+        assert_eq!(field_names(&document, "User"), ["id", "name"]);
+        assert_eq!(field_names(&document, "Post"), ["id", "author"]);
+        assert_eq!(document.definitions.len(), 5);
+    });
+}
+
+#[test]
+fn identical_definitions_are_merged() {
+    //* When
+    let (_, result) = merge(
+        ConflictPolicy::MergeIdentical,
+        &[
+            ("users.graphql", USERS),
+            ("posts.graphql", POSTS),
+            (
+                "schema.graphql",
+                indoc! {r#"
+                    schema { query: Query }
+                    schema { query: Query mutation: Mutation }
+                    type Mutation { noop: Boolean }
+                    union Content = Post
+                    union Content = User | Post
+                "#},
+            ),
+        ],
+    );
+
+    //* Then
+    assert_matches!(result, Ok(MergedDocument { document, .. }) => {
+        assert_eq!(field_names(&document, "Query"), ["user", "post"]);
+        assert_eq!(field_names(&document, "User"), ["id", "name", "posts"]);
+        assert_matches!(&document.definitions[5], TypeSystemDefinition::Schema(schema) => {
+            assert_eq!(schema.node.mutation.as_ref().unwrap().node, "Mutation");
+        });
+        assert_matches!(&document.definitions[7], TypeSystemDefinition::Type(ty) => {
+            assert_matches!(&ty.node.kind, TypeKind::Union(union) => {
+                assert_eq!(union.members.len(), 2);
+            });
+        });
+        assert_eq!(document.definitions.len(), 8);
+    });
+}
+
+#[test]
+fn different_definitions_are_conflicts_when_merging() {
+    //* When
+    let (sources, result) = merge(
+        ConflictPolicy::MergeIdentical,
+        &[
+            ("users.graphql", USERS),
+            ("posts.graphql", POSTS),
+            (
+                "admin.graphql",
+                indoc! {r#"
+                    type User { posts: [Post!] role: String }
+                    interface Post { id: ID! }
+                    directive @tag(name: String) on FIELD_DEFINITION
+                    schema { query: Root }
+                    schema { query: Query }
+                "#},
+            ),
+        ],
+    );
+
+    //* Then
+    let messages = result
+        .expect_err("merge succeeded")
+        .iter()
+        .map(|conflict| describe(&sources, conflict))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "conflicting definitions of User.posts at posts.graphql:3:21 and admin.graphql:1:13",
+            "conflicting definitions of type Post at posts.graphql:2:1 and admin.graphql:2:1",
+            "conflicting definitions of directive @tag at users.graphql:3:1 and admin.graphql:3:1",
+            "conflicting schema definitions at admin.graphql:4:1 and admin.graphql:5:1",
+        ]
+    );
+}
+
+#[test]
+fn render_conflicts_with_the_files_of_their_definitions() {
+    //* When
+    let (sources, result) = merge(
+        ConflictPolicy::Error,
+        &[
+            ("users.graphql", "type User { id: ID! }"),
+            ("admin.graphql", "scalar Role\ntype User { role: Role }"),
+        ],
+    );
+
+    //* Then
+    let conflicts = result.expect_err("merge succeeded");
+    assert_eq!(
+        sources.render_at(&conflicts[0], conflicts[0].positions()),
+        indoc! {"
+            conflicting definitions of type User
+             --> admin.graphql:2:1
+            2 | type User { role: Role }
+              | ^
+             --> users.graphql:1:1
+            1 | type User { id: ID! }
+              | ^"}
+    );
+}
+
+#[test]
+fn resolve_merged_positions_to_the_files_of_their_sources() {
+    //* When
+    let (sources, result) = merge(
+        ConflictPolicy::MergeIdentical,
+        &[("users.graphql", USERS), ("posts.graphql", POSTS)],
+    );
+
+    //* Then
+    let merged = result.expect("merge failed");
+    let user = merged
+        .document
+        .definitions
+        .iter()
+        .find_map(|def| match def {
+            TypeSystemDefinition::Type(ty) if ty.node.name.node == "User" => Some(ty),
+            _ => None,
+        })
+        .expect("type not found");
+    let TypeKind::Object(object) = &user.node.kind else {
+        panic!("not an object type");
+    };
+    let locations = [user.pos, object.fields[1].pos, object.fields[2].pos]
+        .into_iter()
+        .map(|pos| {
+            let pos = merged
+                .spans
+                .resolve(pos)
+                .expect("position out of the sources");
+            sources
+                .location(pos)
+                .expect("position out of the source map")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        locations,
+        [
+            "users.graphql:2:1",
+            "users.graphql:2:21",
+            "posts.graphql:3:21"
+        ]
+    );
+    assert_eq!(merged.spans.resolve(Pos::default()), None);
+}
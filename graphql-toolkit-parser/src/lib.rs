@@ -9,7 +9,9 @@
 use std::fmt::{self, Display, Formatter};
 
 pub use graphql_toolkit_ast::*;
-pub use parse::{parse_query, parse_schema};
+pub use parse::{
+    parse_query, parse_query_file, parse_schema, parse_schema_file, value::parse_value,
+};
 use pest::{error::LineColLocation, RuleType};
 use serde::{Serialize, Serializer};
 
@@ -68,6 +70,15 @@ pub enum Error {
         /// The position of the second definition.
         second: Pos,
     },
+    /// An input object value of a schema contains the same field multiple times.
+    InputFieldDuplicated {
+        /// The name of the field.
+        field: Name,
+        /// The position of the first occurrence.
+        first: Pos,
+        /// The position of the second occurrence.
+        second: Pos,
+    },
     /// The document does not contain any operation.
     MissingOperation,
     /// Recursion limit exceeded.
@@ -99,6 +110,9 @@ impl Error {
             Self::FragmentDuplicated { first, second, .. } => {
                 ErrorPositions::new_2(*second, *first)
             }
+            Self::InputFieldDuplicated { first, second, .. } => {
+                ErrorPositions::new_2(*second, *first)
+            }
             Self::MissingOperation => ErrorPositions::new_0(),
             Self::RecursionLimitExceeded => ErrorPositions::new_0(),
         }
@@ -120,6 +134,9 @@ impl Display for Error {
             Self::FragmentDuplicated { fragment, .. } => {
                 write!(f, "fragment {} is defined twice", fragment)
             }
+            Self::InputFieldDuplicated { field, .. } => {
+                write!(f, "input field {} is defined twice", field)
+            }
             Self::MissingOperation => f.write_str("document does not contain an operation"),
             Self::RecursionLimitExceeded => f.write_str("recursion limit exceeded."),
         }
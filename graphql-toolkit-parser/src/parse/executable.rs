@@ -1,9 +1,9 @@
 use std::collections::{hash_map, HashMap};
 
 use graphql_toolkit_ast::{
-    DocumentOperations, ExecutableDocument, Field, FragmentDefinition, FragmentSpread,
+    DocumentOperations, ExecutableDocument, Field, FileId, FragmentDefinition, FragmentSpread,
     InlineFragment, Name, OperationDefinition, OperationType, Positioned, Selection, SelectionSet,
-    TypeCondition, VariableDefinition,
+    SourceMap, TypeCondition, VariableDefinition,
 };
 use pest::{iterators::Pair, Parser};
 
@@ -30,11 +30,29 @@ macro_rules! recursion_depth {
     }};
 }
 
+/// Add a query file to the source map, and parse it.
+///
+/// The positions of the document and of the error are resolved to the file through the returned
+/// identifier. See [`SourceMap::render`].
+pub fn parse_query_file(
+    sources: &mut SourceMap,
+    path: impl Into<String>,
+    input: impl Into<String>,
+) -> (FileId, Result<ExecutableDocument>) {
+    let file = sources.add_file(path, input);
+    let text = sources.get(file).expect("file added to the map").text();
+    (file, parse_query(text))
+}
+
 /// Parse a GraphQL query document.
 ///
+/// The input object values with duplicated fields keep the last one, and the duplicates are
+/// recorded in [`ExecutableDocument::name_clashes`], for the validation to report them.
+///
 /// # Errors
 ///
-/// Fails if the query is not a valid GraphQL document.
+/// Fails if the query is not a valid GraphQL document, or if it defines an operation or a
+/// fragment multiple times, or an anonymous operation along with other operations.
 pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
     let mut pc = PositionCalculator::new(input.as_ref());
 
@@ -114,6 +132,7 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
     Ok(ExecutableDocument {
         operations: operations.ok_or(Error::MissingOperation)?,
         fragments,
+        name_clashes: pc.name_clashes,
     })
 }
 
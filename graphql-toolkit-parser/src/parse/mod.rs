@@ -2,13 +2,16 @@
 //!
 //! This module's structure mirrors `types`.
 
-pub use executable::parse_query;
+use std::collections::HashMap;
+
+pub use executable::{parse_query, parse_query_file};
 use generated::Rule;
 use graphql_toolkit_ast::{
-    ConstDirective, ConstValue, Directive, Name, Number, OperationType, Positioned, Type, Value,
+    indexmap::IndexMap, ConstDirective, ConstValue, Directive, Name, NameClash, Number,
+    OperationType, Positioned, Type, Value,
 };
 use pest::iterators::{Pair, Pairs};
-pub use service::parse_schema;
+pub use service::{parse_schema, parse_schema_file};
 
 use crate::{
     parse::utils::{block_string_value, exactly_one, parse_if_rule, string_value},
@@ -23,6 +26,7 @@ mod executable;
 mod generated;
 mod service;
 mod utils;
+pub(crate) mod value;
 
 struct GraphQLParser;
 
@@ -84,8 +88,9 @@ fn parse_const_value(
                     .map(|pair| Ok(parse_const_value(pair, pc)?.node))
                     .collect::<Result<_>>()?,
             ),
-            Rule::const_object => ConstValue::Object(
-                pair.into_inner()
+            Rule::const_object => {
+                let fields = pair
+                    .into_inner()
                     .map(|pair| {
                         debug_assert_eq!(pair.as_rule(), Rule::const_object_field);
 
@@ -96,10 +101,11 @@ fn parse_const_value(
 
                         debug_assert_eq!(pairs.next(), None);
 
-                        Ok((name.node, value.node))
+                        Ok((name, value.node))
                     })
-                    .collect::<Result<_>>()?,
-            ),
+                    .collect::<Result<_>>()?;
+                ConstValue::Object(collect_object_fields(fields, pc))
+            }
             _ => unreachable!(),
         },
         pos,
@@ -124,8 +130,9 @@ fn parse_value(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Position
                     .map(|pair| Ok(parse_value(pair, pc)?.node))
                     .collect::<Result<_>>()?,
             ),
-            Rule::object => Value::Object(
-                pair.into_inner()
+            Rule::object => {
+                let fields = pair
+                    .into_inner()
                     .map(|pair| {
                         debug_assert_eq!(pair.as_rule(), Rule::object_field);
                         let mut pairs = pair.into_inner();
@@ -135,16 +142,39 @@ fn parse_value(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Position
 
                         debug_assert_eq!(pairs.next(), None);
 
-                        Ok((name.node, value.node))
+                        Ok((name, value.node))
                     })
-                    .collect::<Result<_>>()?,
-            ),
+                    .collect::<Result<_>>()?;
+                Value::Object(collect_object_fields(fields, pc))
+            }
             _ => unreachable!(),
         },
         pos,
     ))
 }
 
+/// Collect the fields of an object value.
+///
+/// The last field with a given name wins, and the clash with the previous one is recorded.
+fn collect_object_fields<T>(
+    fields: Vec<(Positioned<Name>, T)>,
+    pc: &mut PositionCalculator,
+) -> IndexMap<Name, T> {
+    let mut positions = HashMap::new();
+    let mut object = IndexMap::new();
+    for (name, value) in fields {
+        if let Some(first) = positions.insert(name.node.clone(), name.pos) {
+            pc.name_clashes.push(NameClash::InputField {
+                name: name.node.clone(),
+                first,
+                second: name.pos,
+            });
+        }
+        object.insert(name.node, value);
+    }
+    object
+}
+
 fn parse_variable(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Name>> {
     debug_assert_eq!(pair.as_rule(), Rule::variable);
     parse_name(exactly_one(pair.into_inner()), pc)
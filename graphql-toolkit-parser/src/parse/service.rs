@@ -1,7 +1,8 @@
 use graphql_toolkit_ast::{
-    DirectiveDefinition, DirectiveLocation, EnumType, EnumValueDefinition, FieldDefinition,
-    InputObjectType, InputValueDefinition, InterfaceType, ObjectType, OperationType, Positioned,
-    SchemaDefinition, ServiceDocument, TypeDefinition, TypeKind, TypeSystemDefinition, UnionType,
+    DirectiveDefinition, EnumType, EnumValueDefinition, FieldDefinition, FileId, InputObjectType,
+    InputValueDefinition, InterfaceType, NameClash, ObjectType, OperationType, Positioned,
+    SchemaDefinition, ServiceDocument, SourceMap, TypeDefinition, TypeKind, TypeSystemDefinition,
+    UnionType,
 };
 use pest::{iterators::Pair, Parser};
 
@@ -17,11 +18,26 @@ use crate::{
     Error, Result,
 };
 
+/// Add a schema file to the source map, and parse it.
+///
+/// The positions of the document and of the error are resolved to the file through the returned
+/// identifier. See [`SourceMap::render`].
+pub fn parse_schema_file(
+    sources: &mut SourceMap,
+    path: impl Into<String>,
+    input: impl Into<String>,
+) -> (FileId, Result<ServiceDocument>) {
+    let file = sources.add_file(path, input);
+    let text = sources.get(file).expect("file added to the map").text();
+    (file, parse_schema(text))
+}
+
 /// Parse a GraphQL schema document.
 ///
 /// # Errors
 ///
-/// Fails if the schema is not a valid GraphQL document.
+/// Fails if the schema is not a valid GraphQL document, or if an input object value contains the
+/// same field multiple times.
 pub fn parse_schema<T: AsRef<str>>(input: T) -> Result<ServiceDocument> {
     let mut pc = PositionCalculator::new(input.as_ref());
     Ok(parse_service_document(
@@ -39,13 +55,28 @@ fn parse_service_document(
 ) -> Result<ServiceDocument> {
     debug_assert_eq!(pair.as_rule(), Rule::service_document);
 
-    Ok(ServiceDocument {
-        definitions: pair
-            .into_inner()
-            .filter(|pair| pair.as_rule() != Rule::EOI)
-            .map(|pair| parse_type_system_definition(pair, pc))
-            .collect::<Result<_>>()?,
-    })
+    let definitions = pair
+        .into_inner()
+        .filter(|pair| pair.as_rule() != Rule::EOI)
+        .map(|pair| parse_type_system_definition(pair, pc))
+        .collect::<Result<_>>()?;
+
+    // A service document has no validation step to report the duplicated input object fields of
+    // its values, so they are rejected here.
+    if let Some(NameClash::InputField {
+        name,
+        first,
+        second,
+    }) = pc.name_clashes.first()
+    {
+        return Err(Error::InputFieldDuplicated {
+            field: name.clone(),
+            first: *first,
+            second: *second,
+        });
+    }
+
+    Ok(ServiceDocument { definitions })
 }
 
 fn parse_type_system_definition(
@@ -337,31 +368,9 @@ fn parse_directive_definition(
             .map(|pair| {
                 let pos = pc.step(&pair);
                 debug_assert_eq!(pair.as_rule(), Rule::directive_location);
-                Positioned::new(
-                    match pair.as_str() {
-                        "QUERY" => DirectiveLocation::Query,
-                        "MUTATION" => DirectiveLocation::Mutation,
-                        "SUBSCRIPTION" => DirectiveLocation::Subscription,
-                        "FIELD" => DirectiveLocation::Field,
-                        "FRAGMENT_DEFINITION" => DirectiveLocation::FragmentDefinition,
-                        "FRAGMENT_SPREAD" => DirectiveLocation::FragmentSpread,
-                        "INLINE_FRAGMENT" => DirectiveLocation::InlineFragment,
-                        "VARIABLE_DEFINITION" => DirectiveLocation::VariableDefinition,
-                        "SCHEMA" => DirectiveLocation::Schema,
-                        "SCALAR" => DirectiveLocation::Scalar,
-                        "OBJECT" => DirectiveLocation::Object,
-                        "FIELD_DEFINITION" => DirectiveLocation::FieldDefinition,
-                        "ARGUMENT_DEFINITION" => DirectiveLocation::ArgumentDefinition,
-                        "INTERFACE" => DirectiveLocation::Interface,
-                        "UNION" => DirectiveLocation::Union,
-                        "ENUM" => DirectiveLocation::Enum,
-                        "ENUM_VALUE" => DirectiveLocation::EnumValue,
-                        "INPUT_OBJECT" => DirectiveLocation::InputObject,
-                        "INPUT_FIELD_DEFINITION" => DirectiveLocation::InputFieldDefinition,
-                        _ => unreachable!(),
-                    },
-                    pos,
-                )
+                // The grammar only accepts the known locations.
+                let location = pair.as_str().parse().expect("known directive location");
+                Positioned::new(location, pos)
             })
             .collect()
     };
@@ -446,4 +455,55 @@ mod tests {
             parse_schema(fs::read_to_string(entry.path()).unwrap()).unwrap();
         }
     }
+
+    #[test]
+    fn test_parse_duplicated_input_fields() {
+        for schema in [
+            "type Q { f(a: In = {x: 1, x: 2}): Int }",
+            "type Q @d(arg: {x: 1, x: 2}) { f: Int }",
+        ] {
+            let err = parse_schema(schema).unwrap_err();
+            assert!(
+                matches!(&err, Error::InputFieldDuplicated { field, .. } if field == "x"),
+                "unexpected error {:?}",
+                err
+            );
+        }
+    }
+
+    /// The `repeatable` rule used to match an empty input, so that every directive definition
+    /// was parsed as repeatable.
+    #[test]
+    fn test_parse_repeatable_directive() {
+        let doc = parse_schema(
+            r#"
+            "A description." directive @a(if: Boolean!) on FIELD
+            directive @b(if: Boolean!) repeatable on | FIELD | FRAGMENT_SPREAD
+            directive @repeatable on FIELD
+            directive @c repeatable on FIELD
+            "#,
+        )
+        .unwrap();
+
+        let directives = doc
+            .definitions
+            .iter()
+            .map(|def| match def {
+                TypeSystemDefinition::Directive(directive) => (
+                    directive.node.name.node.as_str(),
+                    directive.node.is_repeatable,
+                ),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directives,
+            [
+                ("a", false),
+                ("b", true),
+                ("repeatable", false),
+                ("c", true)
+            ]
+        );
+    }
 }
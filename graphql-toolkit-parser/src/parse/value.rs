@@ -0,0 +1,27 @@
+use graphql_toolkit_ast::ConstValue;
+use pest::Parser;
+
+use crate::{
+    parse::{generated::Rule, parse_const_value, utils::exactly_one, GraphQLParser},
+    pos::PositionCalculator,
+    Result,
+};
+
+/// Parse a GraphQL constant value, such as the default value of an argument.
+///
+/// # Errors
+///
+/// Fails if the input is not a valid GraphQL constant value.
+pub fn parse_value<T: AsRef<str>>(input: T) -> Result<ConstValue> {
+    let mut pc = PositionCalculator::new(input.as_ref());
+
+    let pair = exactly_one(GraphQLParser::parse(
+        Rule::const_value_document,
+        input.as_ref(),
+    )?);
+    let value = pair
+        .into_inner()
+        .find(|pair| pair.as_rule() == Rule::const_value)
+        .expect("a constant value");
+    Ok(parse_const_value(value, &mut pc)?.node)
+}
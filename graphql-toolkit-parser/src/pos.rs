@@ -1,6 +1,6 @@
 use std::str::Chars;
 
-use graphql_toolkit_ast::Pos;
+use graphql_toolkit_ast::{NameClash, Pos};
 use pest::{iterators::Pair, RuleType};
 
 pub(crate) struct PositionCalculator<'a> {
@@ -8,6 +8,9 @@ pub(crate) struct PositionCalculator<'a> {
     pos: usize,
     line: usize,
     column: usize,
+    /// The name clashes found so far, as the position calculator is threaded through the
+    /// whole parsing.
+    pub(crate) name_clashes: Vec<NameClash>,
 }
 
 impl<'a> PositionCalculator<'a> {
@@ -17,6 +20,7 @@ impl<'a> PositionCalculator<'a> {
             pos: 0,
             line: 1,
             column: 1,
+            name_clashes: Vec::new(),
         }
     }
 
@@ -1,8 +1,9 @@
 use assert_matches::assert_matches;
 use graphql_toolkit_ast::{
-    BaseType, ConstValue, DocumentOperations, Name, OperationType, Selection, SelectionSet, Value,
+    BaseType, ConstValue, DocumentOperations, Name, NameClash, OperationType, Selection,
+    SelectionSet, Value,
 };
-use graphql_toolkit_parser::parse_query as parse_exec_document;
+use graphql_toolkit_parser::{parse_query as parse_exec_document, Error};
 use testlib_parser_testdata as testdata;
 
 #[test]
@@ -593,6 +594,69 @@ fn field_with_arguments_object() {
     });
 }
 
+#[test]
+fn field_with_arguments_object_duplicated_field() {
+    //* Given
+    let document = r#"{ field(input: { key: 1, other: 2, key: 3 }) }"#;
+
+    //* When
+    let parsed = parse_exec_document(document);
+
+    //* Then
+    let doc = parsed.expect("Document parsing failed");
+    assert_matches!(&doc.name_clashes[..], [NameClash::InputField { name, first, second }] => {
+        assert_eq!(name, &Name::new("key"));
+        assert_eq!((first.line, first.column), (1, 18));
+        assert_eq!((second.line, second.column), (1, 36));
+    });
+
+    // The last field with a given name wins.
+    assert_matches!(&doc.operations, DocumentOperations::Single(op) => {
+        assert_matches!(&op.node.selection_set.node.items[0].node, Selection::Field(field) => {
+            assert_matches!(&field.node.arguments[0].1.node, Value::Object(object) => {
+                assert_eq!(object.len(), 2);
+                assert_eq!(object.get("key"), Some(&Value::Number(3.into())));
+            });
+        });
+    });
+}
+
+#[test]
+fn duplicated_operations_and_fragments() {
+    //* Then
+    assert_matches!(
+        parse_exec_document("query A { a }\nquery A { b }\n"),
+        Err(Error::OperationDuplicated { operation, first, second }) => {
+            assert_eq!(operation, "A");
+            assert_eq!((first.line, second.line), (1, 2));
+        }
+    );
+    assert_matches!(
+        parse_exec_document("{ a }\nfragment F on T { a }\nfragment F on T { b }\n"),
+        Err(Error::FragmentDuplicated { fragment, first, second }) => {
+            assert_eq!(fragment, "F");
+            assert_eq!((first.line, second.line), (2, 3));
+        }
+    );
+}
+
+#[test]
+fn anonymous_operation_with_other_operations() {
+    //* Then
+    for (document, lines) in [
+        ("{ a }\nquery B { b }\n", (1, 2)),
+        ("query A { a }\n{ b }\n", (2, 1)),
+        ("{ a }\n{ b }\n", (2, 1)),
+    ] {
+        assert_matches!(
+            parse_exec_document(document),
+            Err(Error::MultipleOperations { anonymous, operation }) => {
+                assert_eq!((anonymous.line, operation.line), lines);
+            }
+        );
+    }
+}
+
 #[test]
 fn field_with_arguments_object_single_pair() {
     //* Given
@@ -0,0 +1,36 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{ConstValue, Name};
+use graphql_toolkit_parser::{parse_value, Error};
+
+#[test]
+fn parse_const_values() {
+    //* When
+    let object = parse_value(r#"{ name: "ada", roles: [ADMIN, USER], limit: 10, next: null }"#);
+
+    //* Then
+    assert_matches!(object, Ok(ConstValue::Object(fields)) => {
+        assert_eq!(fields["name"], ConstValue::String("ada".to_owned()));
+        assert_eq!(
+            fields["roles"],
+            ConstValue::List(vec![
+                ConstValue::Enum(Name::new("ADMIN")),
+                ConstValue::Enum(Name::new("USER")),
+            ])
+        );
+        assert_eq!(fields["limit"], ConstValue::from(10));
+        assert_eq!(fields["next"], ConstValue::Null);
+    });
+    assert_eq!(parse_value(" 1.5 "), Ok(ConstValue::from(1.5)));
+    assert_eq!(
+        parse_value(r#""""block""""#),
+        Ok(ConstValue::String("block".to_owned()))
+    );
+}
+
+#[test]
+fn reject_variables_and_trailing_input() {
+    //* Then
+    assert_matches!(parse_value("$id"), Err(Error::Syntax { .. }));
+    assert_matches!(parse_value("1 2"), Err(Error::Syntax { .. }));
+    assert_matches!(parse_value(""), Err(Error::Syntax { .. }));
+}
@@ -0,0 +1,368 @@
+//! Input value coercion.
+//!
+//! [Reference](https://spec.graphql.org/October2021/#sec-Input-Values).
+
+use std::fmt::{self, Display, Formatter};
+
+use graphql_toolkit_ast::{
+    indexmap::IndexMap, ConstValue, InputObjectType, Name, Number, OperationDefinition, Type,
+    TypeDefinition, TypeKind, Variables,
+};
+
+use crate::schema::Schema;
+
+/// A segment of the path to a value nested in an input value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A variable of an operation.
+    Variable(Name),
+    /// A field of an input object.
+    Field(Name),
+    /// An item of a list.
+    Index(usize),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Variable(name) => write!(f, "${}", name),
+            Self::Field(name) => f.write_str(name),
+            Self::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+/// Input value coercion error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoercionError {
+    /// The message of the error.
+    pub message: String,
+    /// The path to the offending value, empty if it is the coerced value itself.
+    pub path: Vec<PathSegment>,
+}
+
+impl CoercionError {
+    /// Create a new coercion error.
+    pub fn new(message: impl Into<String>, path: Vec<PathSegment>) -> Self {
+        Self {
+            message: message.into(),
+            path,
+        }
+    }
+}
+
+impl Display for CoercionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if self.path.is_empty() {
+            return Ok(());
+        }
+
+        f.write_str(" at ")?;
+        for (i, segment) in self.path.iter().enumerate() {
+            if i > 0 && matches!(segment, PathSegment::Field(_)) {
+                f.write_str(".")?;
+            }
+            Display::fmt(segment, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CoercionError {}
+
+impl Schema {
+    /// Coerce the literal value to the input type.
+    ///
+    /// The enum values must be given as enums, as in the GraphQL documents. The coerced value
+    /// has the fields of the input objects in definition order, with the missing fields set to
+    /// their default values.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#sec-Input-Values).
+    ///
+    /// # Errors
+    ///
+    /// Fails with all the errors found if the value, or any value nested in it, cannot be
+    /// coerced to its type.
+    pub fn coerce_input_value(
+        &self,
+        value: &ConstValue,
+        ty: &Type,
+    ) -> Result<ConstValue, Vec<CoercionError>> {
+        let mut errors = Vec::new();
+        let mut path = Vec::new();
+        let value = self.coerce_value(value, ty, false, &mut path, &mut errors);
+        if errors.is_empty() {
+            Ok(value)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Coerce the request variables to the types of the variables defined by the operation.
+    ///
+    /// The variables that are not provided are set to their default values, if any. The variables
+    /// not defined by the operation are dropped. The paths of the errors in the values start with
+    /// the variable. The enum values can be given as enums or strings, as they are in JSON.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#CoerceVariableValues()).
+    ///
+    /// # Errors
+    ///
+    /// Fails with all the errors found if any variable cannot be coerced to its type.
+    pub fn coerce_variable_values(
+        &self,
+        operation: &OperationDefinition,
+        variables: &Variables,
+    ) -> Result<Variables, Vec<CoercionError>> {
+        let mut coerced = Variables::default();
+        let mut errors = Vec::new();
+
+        for definition in &operation.variable_definitions {
+            let definition = &definition.node;
+            let name = &definition.name.node;
+            let ty = &definition.var_type.node;
+            let path = || vec![PathSegment::Variable(name.clone())];
+
+            let type_name = ty.named_type();
+            if self.get_type(type_name).is_none() {
+                errors.push(CoercionError::new(
+                    format!("variable ${} has unknown type {}", name, type_name),
+                    path(),
+                ));
+                continue;
+            }
+            if !self.is_input_type(type_name) {
+                errors.push(CoercionError::new(
+                    format!("variable ${} cannot be of non-input type {}", name, ty),
+                    path(),
+                ));
+                continue;
+            }
+
+            match (variables.get(name), &definition.default_value) {
+                (Some(value), _) => {
+                    let value = self.coerce_value(value, ty, true, &mut path(), &mut errors);
+                    coerced.insert(name.clone(), value);
+                }
+                (None, Some(default_value)) => {
+                    coerced.insert(name.clone(), default_value.node.clone());
+                }
+                (None, None) if !ty.nullable => {
+                    errors.push(CoercionError::new(
+                        format!(
+                            "variable ${} of required type {} was not provided",
+                            name, ty
+                        ),
+                        path(),
+                    ));
+                }
+                (None, None) => {}
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(coerced)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Coerce the value to the type. The values of variables can give the enum values as
+    /// strings.
+    fn coerce_value(
+        &self,
+        value: &ConstValue,
+        ty: &Type,
+        is_variable: bool,
+        path: &mut Vec<PathSegment>,
+        errors: &mut Vec<CoercionError>,
+    ) -> ConstValue {
+        if let ConstValue::Null = value {
+            if !ty.nullable {
+                errors.push(CoercionError::new(
+                    format!("expected value of type {}, found null", ty),
+                    path.clone(),
+                ));
+            }
+            return ConstValue::Null;
+        }
+
+        // A single value is coerced to a list of one item.
+        if let Some(item_type) = ty.item_type() {
+            let ConstValue::List(items) = value else {
+                return ConstValue::List(vec![self.coerce_value(
+                    value,
+                    item_type,
+                    is_variable,
+                    path,
+                    errors,
+                )]);
+            };
+
+            let mut coerced = Vec::with_capacity(items.len());
+            for (index, item) in items.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                coerced.push(self.coerce_value(item, item_type, is_variable, path, errors));
+                path.pop();
+            }
+            return ConstValue::List(coerced);
+        }
+
+        let type_name = ty.named_type();
+        let Some(definition) = self.get_type(type_name) else {
+            errors.push(CoercionError::new(
+                format!("unknown type {}", type_name),
+                path.clone(),
+            ));
+            return ConstValue::Null;
+        };
+
+        let coerced = match &definition.node.kind {
+            TypeKind::Scalar => coerce_scalar(type_name, value),
+            TypeKind::Enum(enum_type) => {
+                let name = match value {
+                    ConstValue::Enum(name) => Some(name.as_str()),
+                    ConstValue::String(name) if is_variable => Some(name.as_str()),
+                    _ => None,
+                };
+                name.filter(|name| enum_type.values.iter().any(|v| v.node.value.node == *name))
+                    .map(|name| ConstValue::Enum(Name::new(name)))
+            }
+            TypeKind::InputObject(input) => {
+                let ConstValue::Object(fields) = value else {
+                    errors.push(unexpected(ty, value, path));
+                    return ConstValue::Null;
+                };
+                return self.coerce_input_object(
+                    &definition.node,
+                    input,
+                    fields,
+                    is_variable,
+                    path,
+                    errors,
+                );
+            }
+            TypeKind::Object(_) | TypeKind::Interface(_) | TypeKind::Union(_) => {
+                errors.push(CoercionError::new(
+                    format!("type {} is not an input type", type_name),
+                    path.clone(),
+                ));
+                return ConstValue::Null;
+            }
+        };
+
+        coerced.unwrap_or_else(|| {
+            errors.push(unexpected(ty, value, path));
+            ConstValue::Null
+        })
+    }
+
+    fn coerce_input_object(
+        &self,
+        definition: &TypeDefinition,
+        input: &InputObjectType,
+        fields: &IndexMap<Name, ConstValue>,
+        is_variable: bool,
+        path: &mut Vec<PathSegment>,
+        errors: &mut Vec<CoercionError>,
+    ) -> ConstValue {
+        let type_name = &definition.name.node;
+        let is_one_of = definition
+            .directives
+            .iter()
+            .any(|d| d.node.name.node == "oneOf");
+
+        for name in fields.keys() {
+            if input.fields.iter().all(|f| &f.node.name.node != name) {
+                errors.push(CoercionError::new(
+                    format!("field {} is not defined by type {}", name, type_name),
+                    path.clone(),
+                ));
+            }
+        }
+
+        if is_one_of {
+            match fields.first() {
+                Some((name, ConstValue::Null)) if fields.len() == 1 => {
+                    errors.push(CoercionError::new(
+                        format!("field {}.{} must be non-null", type_name, name),
+                        path.clone(),
+                    ));
+                }
+                Some(_) if fields.len() == 1 => {}
+                _ => errors.push(CoercionError::new(
+                    format!(
+                        "oneOf input object {} must specify exactly one field",
+                        type_name
+                    ),
+                    path.clone(),
+                )),
+            }
+        }
+
+        let mut coerced = IndexMap::new();
+        for field in &input.fields {
+            let field = &field.node;
+            let name = &field.name.node;
+
+            match (fields.get(name), &field.default_value) {
+                (Some(value), _) => {
+                    path.push(PathSegment::Field(name.clone()));
+                    let value = self.coerce_value(value, &field.ty.node, is_variable, path, errors);
+                    path.pop();
+                    coerced.insert(name.clone(), value);
+                }
+                (None, Some(default_value)) => {
+                    coerced.insert(name.clone(), default_value.node.clone());
+                }
+                (None, None) if !field.ty.node.nullable => {
+                    errors.push(CoercionError::new(
+                        format!(
+                            "field {}.{} of required type {} was not provided",
+                            type_name, name, field.ty.node
+                        ),
+                        path.clone(),
+                    ));
+                }
+                (None, None) => {}
+            }
+        }
+
+        ConstValue::Object(coerced)
+    }
+}
+
+/// Coerce the value to the scalar type, returning `None` if it cannot be coerced.
+///
+/// Custom scalars accept any value.
+fn coerce_scalar(type_name: &str, value: &ConstValue) -> Option<ConstValue> {
+    match (type_name, value) {
+        ("Int", ConstValue::Number(n)) => n
+            .as_i64()
+            .filter(|n| i32::try_from(*n).is_ok())
+            .map(|_| value.clone()),
+        // Integers are widened to floats.
+        ("Float", ConstValue::Number(n)) => n
+            .as_f64()
+            .and_then(Number::from_f64)
+            .map(ConstValue::Number),
+        ("String", ConstValue::String(_)) | ("Boolean", ConstValue::Boolean(_)) => {
+            Some(value.clone())
+        }
+        // Integers are accepted as IDs, and serialized as strings.
+        ("ID", ConstValue::String(_)) => Some(value.clone()),
+        ("ID", ConstValue::Number(n)) if n.is_i64() || n.is_u64() => {
+            Some(ConstValue::String(n.to_string()))
+        }
+        ("Int" | "Float" | "String" | "Boolean" | "ID", _) => None,
+        _ => Some(value.clone()),
+    }
+}
+
+fn unexpected(ty: &Type, value: &ConstValue, path: &[PathSegment]) -> CoercionError {
+    CoercionError::new(
+        format!("expected value of type {}, found {}", ty, value),
+        path.to_vec(),
+    )
+}
@@ -0,0 +1,584 @@
+//! Schema contracts: filtered variants of a schema, such as the ones published to partners.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+};
+
+use graphql_toolkit_ast::{
+    ConstDirective, ConstValue, FieldDefinition, InputValueDefinition, Name, OperationType, Pos,
+    Positioned, ServiceDocument, Type, TypeKind, TypeSystemDefinition,
+};
+
+use crate::coordinate::SchemaCoordinate;
+
+/// A filter matching the applications of a directive, such as `@inaccessible` or
+/// `@tag(name: "internal")`.
+///
+/// A directive application matches if it has the filter name, and the values of all the filter
+/// arguments. Other arguments of the application are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectiveFilter {
+    name: Name,
+    arguments: Vec<(Name, ConstValue)>,
+}
+
+impl DirectiveFilter {
+    /// Create a filter matching all the applications of the directive with the given name.
+    #[must_use]
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self {
+            name: Name::new(name),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Only match the applications with the given argument value.
+    #[must_use]
+    pub fn argument(mut self, name: impl AsRef<str>, value: impl Into<ConstValue>) -> Self {
+        self.arguments.push((Name::new(name), value.into()));
+        self
+    }
+
+    /// Whether the directive application matches the filter.
+    #[must_use]
+    pub fn matches(&self, directive: &ConstDirective) -> bool {
+        directive.name.node == self.name
+            && self.arguments.iter().all(|(name, value)| {
+                directive
+                    .get_argument(name)
+                    .map_or(false, |arg| &arg.node == value)
+            })
+    }
+}
+
+/// A schema contract, removing the elements tagged with some directives from a service
+/// document.
+///
+/// Applying a contract removes the types, fields, arguments, input fields and enum values with a
+/// directive matching any of the contract filters. The union members and implemented interfaces
+/// referring to removed types are removed as well. Then, the types that are no longer reachable
+/// from the root types are pruned.
+///
+/// ```
+/// # use graphql_toolkit_schema::{Contract, DirectiveFilter};
+/// let contract = Contract::new()
+///     .exclude(DirectiveFilter::new("inaccessible"))
+///     .exclude(DirectiveFilter::new("tag").argument("name", "internal"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Contract {
+    filters: Vec<DirectiveFilter>,
+    keep_unreachable_types: bool,
+}
+
+impl Contract {
+    /// Create a new contract, which does not remove any element.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Remove the elements with a directive matching the filter.
+    #[must_use]
+    pub fn exclude(mut self, filter: DirectiveFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Keep the types that are no longer reachable from the root types.
+    #[must_use]
+    pub fn keep_unreachable_types(mut self) -> Self {
+        self.keep_unreachable_types = true;
+        self
+    }
+
+    /// Apply the contract to a service document.
+    ///
+    /// The extensions in the document are filtered like the definitions. The issues found in the
+    /// resulting document are reported along with it, instead of failing.
+    #[must_use]
+    pub fn apply(&self, document: ServiceDocument) -> ContractOutput {
+        let mut issues = Vec::new();
+
+        let removed = document
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                TypeSystemDefinition::Type(ty) if self.is_excluded(&ty.node.directives) => {
+                    Some(ty.node.name.node.clone())
+                }
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut definitions = Vec::new();
+        for def in document.definitions {
+            match def {
+                TypeSystemDefinition::Type(ty) if removed.contains(&ty.node.name.node) => {}
+                TypeSystemDefinition::Type(mut ty) => {
+                    match &mut ty.node.kind {
+                        TypeKind::Object(object) => {
+                            object
+                                .implements
+                                .retain(|name| !removed.contains(&name.node));
+                            self.filter_fields(&mut object.fields);
+                        }
+                        TypeKind::Interface(interface) => {
+                            interface
+                                .implements
+                                .retain(|name| !removed.contains(&name.node));
+                            self.filter_fields(&mut interface.fields);
+                        }
+                        TypeKind::Union(union) => {
+                            union.members.retain(|name| !removed.contains(&name.node));
+                        }
+                        TypeKind::Enum(enum_type) => {
+                            enum_type
+                                .values
+                                .retain(|value| !self.is_excluded(&value.node.directives));
+                        }
+                        TypeKind::InputObject(input) => {
+                            input
+                                .fields
+                                .retain(|field| !self.is_excluded(&field.node.directives));
+                        }
+                        TypeKind::Scalar => {}
+                    }
+                    definitions.push(TypeSystemDefinition::Type(ty));
+                }
+                TypeSystemDefinition::Directive(mut directive) => {
+                    directive
+                        .node
+                        .arguments
+                        .retain(|arg| !self.is_excluded(&arg.node.directives));
+                    definitions.push(TypeSystemDefinition::Directive(directive));
+                }
+                TypeSystemDefinition::Schema(schema) => {
+                    definitions.push(TypeSystemDefinition::Schema(schema));
+                }
+            }
+        }
+
+        if !self.keep_unreachable_types {
+            let reachable = reachable_types(&definitions);
+            definitions.retain(|def| match def {
+                TypeSystemDefinition::Type(ty) => reachable.contains(&ty.node.name.node),
+                _ => true,
+            });
+        }
+
+        report_removed_types(&definitions, &removed, &mut issues);
+        report_unimplemented_fields(&definitions, &mut issues);
+        report_dangling_types(&definitions, &mut issues);
+
+        ContractOutput {
+            document: ServiceDocument { definitions },
+            issues,
+        }
+    }
+
+    /// Whether any of the directives matches a contract filter.
+    fn is_excluded(&self, directives: &[Positioned<ConstDirective>]) -> bool {
+        directives.iter().any(|directive| {
+            self.filters
+                .iter()
+                .any(|filter| filter.matches(&directive.node))
+        })
+    }
+
+    /// Remove the excluded fields, and the excluded arguments of the remaining fields.
+    fn filter_fields(&self, fields: &mut Vec<Positioned<FieldDefinition>>) {
+        fields.retain(|field| !self.is_excluded(&field.node.directives));
+        for field in fields {
+            field
+                .node
+                .arguments
+                .retain(|arg| !self.is_excluded(&arg.node.directives));
+        }
+    }
+}
+
+/// Report the references to removed types left in the root operation types, fields, arguments
+/// and input fields.
+fn report_removed_types(
+    definitions: &[TypeSystemDefinition],
+    removed: &HashSet<Name>,
+    issues: &mut Vec<ContractIssue>,
+) {
+    let removed_reference = |ty: &Positioned<Type>, referrer: SchemaCoordinate| {
+        let name = ty.node.named_type();
+        removed
+            .contains(name)
+            .then(|| ContractIssue::RemovedTypeReferenced {
+                referrer,
+                name: name.clone(),
+                pos: ty.pos,
+            })
+    };
+
+    for def in definitions {
+        match def {
+            TypeSystemDefinition::Schema(schema) => {
+                for (root, name) in [
+                    (OperationType::Query, &schema.node.query),
+                    (OperationType::Mutation, &schema.node.mutation),
+                    (OperationType::Subscription, &schema.node.subscription),
+                ] {
+                    if let Some(name) = name.as_ref().filter(|name| removed.contains(&name.node)) {
+                        issues.push(ContractIssue::RootTypeRemoved {
+                            root,
+                            name: name.node.clone(),
+                            pos: name.pos,
+                        });
+                    }
+                }
+            }
+            TypeSystemDefinition::Type(ty) => {
+                let type_name = &ty.node.name.node;
+                let fields = match &ty.node.kind {
+                    TypeKind::Object(object) => &object.fields[..],
+                    TypeKind::Interface(interface) => &interface.fields[..],
+                    TypeKind::InputObject(input) => {
+                        for field in &input.fields {
+                            issues.extend(removed_reference(
+                                &field.node.ty,
+                                SchemaCoordinate::member(type_name, &field.node.name.node),
+                            ));
+                        }
+                        continue;
+                    }
+                    _ => continue,
+                };
+                for field in fields {
+                    let field_name = &field.node.name.node;
+                    issues.extend(removed_reference(
+                        &field.node.ty,
+                        SchemaCoordinate::member(type_name, field_name),
+                    ));
+                    for arg in &field.node.arguments {
+                        issues.extend(removed_reference(
+                            &arg.node.ty,
+                            SchemaCoordinate::argument(type_name, field_name, &arg.node.name.node),
+                        ));
+                    }
+                }
+            }
+            TypeSystemDefinition::Directive(directive) => {
+                for arg in &directive.node.arguments {
+                    issues.extend(removed_reference(
+                        &arg.node.ty,
+                        SchemaCoordinate::directive_argument(
+                            &directive.node.name.node,
+                            &arg.node.name.node,
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Get the names of the types reachable from the root types and the directive definitions.
+///
+/// The implementations of a reachable interface are reachable too, as they can be returned by
+/// its fields.
+fn reachable_types(definitions: &[TypeSystemDefinition]) -> HashSet<Name> {
+    let mut references: HashMap<&Name, Vec<&Name>> = HashMap::new();
+    let mut roots = Vec::new();
+    let mut has_schema = false;
+
+    for def in definitions {
+        match def {
+            TypeSystemDefinition::Schema(schema) => {
+                has_schema |= !schema.node.extend;
+                roots.extend(
+                    [
+                        &schema.node.query,
+                        &schema.node.mutation,
+                        &schema.node.subscription,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(|name| &name.node),
+                );
+            }
+            TypeSystemDefinition::Type(ty) => {
+                let name = &ty.node.name.node;
+                let referenced = match &ty.node.kind {
+                    TypeKind::Object(object) => {
+                        let mut referenced = Vec::new();
+                        for field in &object.fields {
+                            referenced.push(field.node.ty.node.named_type());
+                            referenced.extend(input_value_types(&field.node.arguments));
+                        }
+                        for interface in &object.implements {
+                            referenced.push(&interface.node);
+                            references.entry(&interface.node).or_default().push(name);
+                        }
+                        referenced
+                    }
+                    TypeKind::Interface(interface) => {
+                        let mut referenced = Vec::new();
+                        for field in &interface.fields {
+                            referenced.push(field.node.ty.node.named_type());
+                            referenced.extend(input_value_types(&field.node.arguments));
+                        }
+                        for implemented in &interface.implements {
+                            referenced.push(&implemented.node);
+                            references.entry(&implemented.node).or_default().push(name);
+                        }
+                        referenced
+                    }
+                    TypeKind::Union(union) => {
+                        union.members.iter().map(|member| &member.node).collect()
+                    }
+                    TypeKind::InputObject(input) => input_value_types(&input.fields).collect(),
+                    TypeKind::Scalar | TypeKind::Enum(_) => Vec::new(),
+                };
+                references.entry(name).or_default().extend(referenced);
+            }
+            TypeSystemDefinition::Directive(directive) => {
+                roots.extend(
+                    directive
+                        .node
+                        .arguments
+                        .iter()
+                        .map(|arg| arg.node.ty.node.named_type()),
+                );
+            }
+        }
+    }
+
+    // Without a schema definition, the root types are the types with the default root names.
+    if !has_schema {
+        for def in definitions {
+            if let TypeSystemDefinition::Type(ty) = def {
+                let name = &ty.node.name.node;
+                if ["Query", "Mutation", "Subscription"].contains(&name.as_str()) {
+                    roots.push(name);
+                }
+            }
+        }
+    }
+
+    let mut reachable = HashSet::new();
+    let mut stack = roots;
+    while let Some(name) = stack.pop() {
+        if reachable.insert(name.clone()) {
+            stack.extend(references.get(name).into_iter().flatten());
+        }
+    }
+    reachable
+}
+
+/// Get the named types of the arguments or input fields.
+fn input_value_types(values: &[Positioned<InputValueDefinition>]) -> impl Iterator<Item = &Name> {
+    values.iter().map(|value| value.node.ty.node.named_type())
+}
+
+/// Report the fields and arguments of the implemented interfaces that were removed from the
+/// object types and interfaces implementing them, but not from the interfaces.
+fn report_unimplemented_fields(
+    definitions: &[TypeSystemDefinition],
+    issues: &mut Vec<ContractIssue>,
+) {
+    // The fields of a type can be split between its definition and its extensions.
+    let mut fields: HashMap<&Name, Vec<&FieldDefinition>> = HashMap::new();
+    let mut implements = Vec::new();
+
+    for def in definitions {
+        let TypeSystemDefinition::Type(ty) = def else {
+            continue;
+        };
+        let (type_fields, interfaces) = match &ty.node.kind {
+            TypeKind::Object(object) => (&object.fields, &object.implements),
+            TypeKind::Interface(interface) => (&interface.fields, &interface.implements),
+            _ => continue,
+        };
+        let name = &ty.node.name.node;
+        fields
+            .entry(name)
+            .or_default()
+            .extend(type_fields.iter().map(|field| &field.node));
+        implements.extend(interfaces.iter().map(|interface| (name, interface)));
+    }
+
+    for (type_name, interface) in implements {
+        // The removed interfaces are no longer implemented.
+        let Some(interface_fields) = fields.get(&interface.node) else {
+            continue;
+        };
+        let type_fields = &fields[type_name];
+
+        for interface_field in interface_fields {
+            let field_name = &interface_field.name.node;
+            let Some(field) = type_fields
+                .iter()
+                .find(|field| field.name.node == *field_name)
+            else {
+                issues.push(ContractIssue::InterfaceFieldNotImplemented {
+                    type_name: type_name.clone(),
+                    field: SchemaCoordinate::member(&interface.node, field_name),
+                    pos: interface.pos,
+                });
+                continue;
+            };
+
+            for arg in &interface_field.arguments {
+                let arg_name = &arg.node.name.node;
+                if !field
+                    .arguments
+                    .iter()
+                    .any(|a| a.node.name.node == *arg_name)
+                {
+                    issues.push(ContractIssue::InterfaceFieldNotImplemented {
+                        type_name: type_name.clone(),
+                        field: SchemaCoordinate::argument(&interface.node, field_name, arg_name),
+                        pos: interface.pos,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Report the types left without fields, values or members, and the interfaces left without
+/// implementations.
+fn report_dangling_types(definitions: &[TypeSystemDefinition], issues: &mut Vec<ContractIssue>) {
+    // The members of a type can be split between its definition and its extensions.
+    let mut types: Vec<(&Name, Pos, bool)> = Vec::new();
+    let mut members: HashMap<&Name, usize> = HashMap::new();
+    let mut implemented = HashSet::new();
+
+    for def in definitions {
+        let TypeSystemDefinition::Type(ty) = def else {
+            continue;
+        };
+        let name = &ty.node.name.node;
+        let count = match &ty.node.kind {
+            TypeKind::Object(object) => {
+                implemented.extend(object.implements.iter().map(|name| &name.node));
+                object.fields.len()
+            }
+            TypeKind::Interface(interface) => interface.fields.len(),
+            TypeKind::Union(union) => union.members.len(),
+            TypeKind::Enum(enum_type) => enum_type.values.len(),
+            TypeKind::InputObject(input) => input.fields.len(),
+            TypeKind::Scalar => continue,
+        };
+        *members.entry(name).or_default() += count;
+        if !ty.node.extend {
+            types.push((name, ty.pos, matches!(ty.node.kind, TypeKind::Interface(_))));
+        }
+    }
+
+    for (name, pos, is_interface) in types {
+        if members[name] == 0 {
+            issues.push(ContractIssue::EmptyType {
+                name: name.clone(),
+                pos,
+            });
+        } else if is_interface && !implemented.contains(name) {
+            issues.push(ContractIssue::InterfaceNotImplemented {
+                name: name.clone(),
+                pos,
+            });
+        }
+    }
+}
+
+/// The result of applying a [`Contract`] to a service document.
+#[derive(Debug, Clone)]
+pub struct ContractOutput {
+    /// The filtered service document.
+    pub document: ServiceDocument,
+    /// The issues found in the filtered document, in document order.
+    pub issues: Vec<ContractIssue>,
+}
+
+/// An issue left in a service document by a [`Contract`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContractIssue {
+    /// A root operation type was removed.
+    RootTypeRemoved {
+        /// The root operation.
+        root: OperationType,
+        /// The name of the root operation type.
+        name: Name,
+        /// The position of the root operation type reference.
+        pos: Pos,
+    },
+    /// A field, argument or input field that was kept refers to a removed type.
+    RemovedTypeReferenced {
+        /// The coordinate of the field, argument or input field.
+        referrer: SchemaCoordinate,
+        /// The name of the removed type.
+        name: Name,
+        /// The position of the type reference.
+        pos: Pos,
+    },
+    /// An object type or interface no longer has a field, or a field argument, of an interface
+    /// it implements.
+    InterfaceFieldNotImplemented {
+        /// The name of the object type or interface.
+        type_name: Name,
+        /// The coordinate of the interface field or argument.
+        field: SchemaCoordinate,
+        /// The position of the implemented interface reference.
+        pos: Pos,
+    },
+    /// A type has no fields, values or members left.
+    EmptyType {
+        /// The name of the type.
+        name: Name,
+        /// The position of the type definition.
+        pos: Pos,
+    },
+    /// An interface is not implemented by any object type.
+    InterfaceNotImplemented {
+        /// The name of the interface.
+        name: Name,
+        /// The position of the interface definition.
+        pos: Pos,
+    },
+}
+
+impl ContractIssue {
+    /// Get the position of the issue.
+    #[must_use]
+    pub fn pos(&self) -> Pos {
+        match self {
+            Self::RootTypeRemoved { pos, .. }
+            | Self::RemovedTypeReferenced { pos, .. }
+            | Self::InterfaceFieldNotImplemented { pos, .. }
+            | Self::EmptyType { pos, .. }
+            | Self::InterfaceNotImplemented { pos, .. } => *pos,
+        }
+    }
+}
+
+impl Display for ContractIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RootTypeRemoved { root, name, .. } => {
+                write!(f, "{} root type {} was removed", root, name)
+            }
+            Self::RemovedTypeReferenced { referrer, name, .. } => {
+                write!(f, "{} refers to removed type {}", referrer, name)
+            }
+            Self::InterfaceFieldNotImplemented {
+                type_name, field, ..
+            } => write!(f, "{} does not implement {}", type_name, field),
+            Self::EmptyType { name, .. } => write!(f, "type {} is empty", name),
+            Self::InterfaceNotImplemented { name, .. } => {
+                write!(
+                    f,
+                    "interface {} is not implemented by any object type",
+                    name
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,442 @@
+//! Schema coordinates.
+//!
+//! [Reference](https://github.com/graphql/graphql-wg/blob/main/rfcs/SchemaCoordinates.md).
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    ptr,
+    str::FromStr,
+};
+
+use graphql_toolkit_ast::{
+    DirectiveDefinition, EnumValueDefinition, FieldDefinition, InputValueDefinition, InterfaceType,
+    Name, ObjectType, Positioned, ServiceDocument, TypeDefinition, TypeKind, TypeSystemDefinition,
+};
+
+use crate::schema::Schema;
+
+/// A schema coordinate, uniquely identifying an element of a schema, such as `Query.user(id:)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SchemaCoordinate {
+    /// A type, such as `User`.
+    Type {
+        /// The name of the type.
+        name: Name,
+    },
+    /// A field, input field or enum value, such as `User.email` or `Role.ADMIN`.
+    Member {
+        /// The name of the type.
+        type_name: Name,
+        /// The name of the field, input field or enum value.
+        member: Name,
+    },
+    /// A field argument, such as `Query.user(id:)`.
+    Argument {
+        /// The name of the type.
+        type_name: Name,
+        /// The name of the field.
+        field: Name,
+        /// The name of the argument.
+        argument: Name,
+    },
+    /// A directive, such as `@deprecated`.
+    Directive {
+        /// The name of the directive.
+        name: Name,
+    },
+    /// A directive argument, such as `@deprecated(reason:)`.
+    DirectiveArgument {
+        /// The name of the directive.
+        directive: Name,
+        /// The name of the argument.
+        argument: Name,
+    },
+}
+
+impl SchemaCoordinate {
+    /// Create the coordinate of a type.
+    #[must_use]
+    pub fn ty(name: impl AsRef<str>) -> Self {
+        Self::Type {
+            name: Name::new(name),
+        }
+    }
+
+    /// Create the coordinate of a field, input field or enum value.
+    #[must_use]
+    pub fn member(type_name: impl AsRef<str>, member: impl AsRef<str>) -> Self {
+        Self::Member {
+            type_name: Name::new(type_name),
+            member: Name::new(member),
+        }
+    }
+
+    /// Create the coordinate of a field argument.
+    #[must_use]
+    pub fn argument(
+        type_name: impl AsRef<str>,
+        field: impl AsRef<str>,
+        argument: impl AsRef<str>,
+    ) -> Self {
+        Self::Argument {
+            type_name: Name::new(type_name),
+            field: Name::new(field),
+            argument: Name::new(argument),
+        }
+    }
+
+    /// Create the coordinate of a directive.
+    #[must_use]
+    pub fn directive(name: impl AsRef<str>) -> Self {
+        Self::Directive {
+            name: Name::new(name),
+        }
+    }
+
+    /// Create the coordinate of a directive argument.
+    #[must_use]
+    pub fn directive_argument(directive: impl AsRef<str>, argument: impl AsRef<str>) -> Self {
+        Self::DirectiveArgument {
+            directive: Name::new(directive),
+            argument: Name::new(argument),
+        }
+    }
+
+    /// Get the name of the type the coordinate belongs to, if it is not a directive coordinate.
+    #[must_use]
+    pub fn type_name(&self) -> Option<&Name> {
+        match self {
+            Self::Type { name } => Some(name),
+            Self::Member { type_name, .. } | Self::Argument { type_name, .. } => Some(type_name),
+            Self::Directive { .. } | Self::DirectiveArgument { .. } => None,
+        }
+    }
+}
+
+impl Display for SchemaCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type { name } => f.write_str(name),
+            Self::Member { type_name, member } => write!(f, "{}.{}", type_name, member),
+            Self::Argument {
+                type_name,
+                field,
+                argument,
+            } => write!(f, "{}.{}({}:)", type_name, field, argument),
+            Self::Directive { name } => write!(f, "@{}", name),
+            Self::DirectiveArgument {
+                directive,
+                argument,
+            } => write!(f, "@{}({}:)", directive, argument),
+        }
+    }
+}
+
+/// Schema coordinate parsing error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoordinateParseError {
+    /// The invalid coordinate.
+    pub coordinate: String,
+}
+
+impl Display for CoordinateParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid schema coordinate {}", self.coordinate)
+    }
+}
+
+impl std::error::Error for CoordinateParseError {}
+
+impl FromStr for SchemaCoordinate {
+    type Err = CoordinateParseError;
+
+    fn from_str(coordinate: &str) -> Result<Self, Self::Err> {
+        let err = || CoordinateParseError {
+            coordinate: coordinate.to_owned(),
+        };
+
+        // Split the optional argument, written as `(name:)`, from the end of the coordinate.
+        let (path, argument) = match coordinate.strip_suffix(":)") {
+            Some(rest) => {
+                let (path, argument) = rest.split_once('(').ok_or_else(err)?;
+                (path, Some(parse_name(argument).ok_or_else(err)?))
+            }
+            None => (coordinate, None),
+        };
+
+        if let Some(directive) = path.strip_prefix('@') {
+            let directive = parse_name(directive).ok_or_else(err)?;
+            return Ok(match argument {
+                Some(argument) => Self::DirectiveArgument {
+                    directive,
+                    argument,
+                },
+                None => Self::Directive { name: directive },
+            });
+        }
+
+        match (path.split_once('.'), argument) {
+            (None, None) => Ok(Self::Type {
+                name: parse_name(path).ok_or_else(err)?,
+            }),
+            (Some((type_name, member)), argument) => {
+                let type_name = parse_name(type_name).ok_or_else(err)?;
+                let member = parse_name(member).ok_or_else(err)?;
+                Ok(match argument {
+                    Some(argument) => Self::Argument {
+                        type_name,
+                        field: member,
+                        argument,
+                    },
+                    None => Self::Member { type_name, member },
+                })
+            }
+            // Only fields and directives have arguments.
+            (None, Some(_)) => Err(err()),
+        }
+    }
+}
+
+/// Parse a GraphQL name, returning `None` if it is not a valid name.
+fn parse_name(name: &str) -> Option<Name> {
+    let mut chars = name.chars();
+    let is_valid = chars
+        .next()
+        .map_or(false, |c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric());
+    is_valid.then(|| Name::new(name))
+}
+
+/// An element of a schema, identified by a [`SchemaCoordinate`].
+#[derive(Debug, Clone, Copy)]
+pub enum SchemaElement<'a> {
+    /// A type.
+    Type(&'a Positioned<TypeDefinition>),
+    /// A field of an object or interface type.
+    Field(&'a Positioned<FieldDefinition>),
+    /// A field of an input object type.
+    InputField(&'a Positioned<InputValueDefinition>),
+    /// A value of an enum type.
+    EnumValue(&'a Positioned<EnumValueDefinition>),
+    /// An argument of a field or directive.
+    Argument(&'a Positioned<InputValueDefinition>),
+    /// A directive.
+    Directive(&'a Positioned<DirectiveDefinition>),
+}
+
+/// The identity of the node of a [`SchemaElement`]: its kind and its address.
+type ElementKey = (u8, usize);
+
+impl SchemaElement<'_> {
+    /// Get the identity of the node of the element, to tell it apart from equal nodes.
+    fn key(&self) -> ElementKey {
+        match self {
+            Self::Type(ty) => (0, ptr::addr_of!(**ty) as usize),
+            Self::Field(field) => (1, ptr::addr_of!(**field) as usize),
+            Self::InputField(value) => (2, ptr::addr_of!(**value) as usize),
+            Self::Argument(value) => (3, ptr::addr_of!(**value) as usize),
+            Self::EnumValue(value) => (4, ptr::addr_of!(**value) as usize),
+            Self::Directive(directive) => (5, ptr::addr_of!(**directive) as usize),
+        }
+    }
+}
+
+impl Schema {
+    /// Get the element of the schema identified by the coordinate.
+    ///
+    /// A member coordinate resolves to a field of an object or interface type, a field of an input
+    /// object type, or a value of an enum type.
+    #[must_use]
+    pub fn resolve_coordinate(&self, coordinate: &SchemaCoordinate) -> Option<SchemaElement<'_>> {
+        match coordinate {
+            SchemaCoordinate::Type { name } => self.get_type(name).map(SchemaElement::Type),
+            SchemaCoordinate::Member { type_name, member } => {
+                match &self.get_type(type_name)?.node.kind {
+                    TypeKind::Object(_) | TypeKind::Interface(_) => {
+                        self.get_field(type_name, member).map(SchemaElement::Field)
+                    }
+                    TypeKind::InputObject(_) => self
+                        .get_input_field(type_name, member)
+                        .map(SchemaElement::InputField),
+                    TypeKind::Enum(enum_type) => enum_type
+                        .values
+                        .iter()
+                        .find(|value| &value.node.value.node == member)
+                        .map(SchemaElement::EnumValue),
+                    TypeKind::Scalar | TypeKind::Union(_) => None,
+                }
+            }
+            SchemaCoordinate::Argument {
+                type_name,
+                field,
+                argument,
+            } => self
+                .get_field(type_name, field)?
+                .node
+                .arguments
+                .iter()
+                .find(|arg| &arg.node.name.node == argument)
+                .map(SchemaElement::Argument),
+            SchemaCoordinate::Directive { name } => {
+                self.get_directive(name).map(SchemaElement::Directive)
+            }
+            SchemaCoordinate::DirectiveArgument {
+                directive,
+                argument,
+            } => self
+                .get_directive(directive)?
+                .node
+                .arguments
+                .iter()
+                .find(|arg| &arg.node.name.node == argument)
+                .map(SchemaElement::Argument),
+        }
+    }
+
+    /// Get the coordinates of all the elements of the schema, in definition order.
+    #[must_use]
+    pub fn coordinates(&self) -> Vec<(SchemaCoordinate, SchemaElement<'_>)> {
+        let mut coordinates = Vec::new();
+        for ty in self.types() {
+            push_type_coordinates(&mut coordinates, ty);
+        }
+        for directive in self.directives() {
+            push_directive_coordinates(&mut coordinates, directive);
+        }
+        coordinates
+    }
+
+    /// Build an index of the coordinates of the elements of the schema.
+    #[must_use]
+    pub fn coordinate_index(&self) -> CoordinateIndex<'_> {
+        CoordinateIndex::new(self.coordinates())
+    }
+}
+
+/// An index of the coordinates of the elements of a schema or a service document.
+///
+/// The index is built once, to look up the coordinates of many elements, such as the nodes
+/// reported by a diff or a lint rule. The elements are identified by their node, rather than by
+/// equality, so the nodes of another schema or document are not found.
+///
+/// ```
+/// # use graphql_toolkit_schema::{CoordinateIndex, SchemaCoordinate, SchemaElement};
+/// # use graphql_toolkit_ast::{TypeKind, TypeSystemDefinition};
+/// let document = graphql_toolkit_parser::parse_schema("type User { email: String }").unwrap();
+/// let index = CoordinateIndex::from_document(&document);
+///
+/// let TypeSystemDefinition::Type(user) = &document.definitions[0] else { unreachable!() };
+/// let TypeKind::Object(object) = &user.node.kind else { unreachable!() };
+/// assert_eq!(
+///     index.coordinate_of(SchemaElement::Field(&object.fields[0])),
+///     Some(&SchemaCoordinate::member("User", "email"))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CoordinateIndex<'a> {
+    coordinates: HashMap<ElementKey, SchemaCoordinate>,
+    nodes: PhantomData<SchemaElement<'a>>,
+}
+
+impl<'a> CoordinateIndex<'a> {
+    /// Build an index of the coordinates of the elements of a service document, including the
+    /// elements of its type extensions.
+    #[must_use]
+    pub fn from_document(document: &'a ServiceDocument) -> Self {
+        let mut coordinates = Vec::new();
+        for def in &document.definitions {
+            match def {
+                TypeSystemDefinition::Type(ty) => push_type_coordinates(&mut coordinates, ty),
+                TypeSystemDefinition::Directive(directive) => {
+                    push_directive_coordinates(&mut coordinates, directive);
+                }
+                TypeSystemDefinition::Schema(_) => {}
+            }
+        }
+        Self::new(coordinates)
+    }
+
+    fn new(coordinates: Vec<(SchemaCoordinate, SchemaElement<'a>)>) -> Self {
+        Self {
+            coordinates: coordinates
+                .into_iter()
+                .map(|(coordinate, element)| (element.key(), coordinate))
+                .collect(),
+            nodes: PhantomData,
+        }
+    }
+
+    /// Get the coordinate of an element.
+    ///
+    /// Returns `None` if the element is not a node of the indexed schema or document, even if
+    /// it has an equal node.
+    #[must_use]
+    pub fn coordinate_of(&self, element: SchemaElement<'_>) -> Option<&SchemaCoordinate> {
+        self.coordinates.get(&element.key())
+    }
+}
+
+/// Push the coordinates of a type and of its fields, arguments, input fields or values.
+fn push_type_coordinates<'a>(
+    coordinates: &mut Vec<(SchemaCoordinate, SchemaElement<'a>)>,
+    ty: &'a Positioned<TypeDefinition>,
+) {
+    let type_name = &ty.node.name.node;
+    coordinates.push((SchemaCoordinate::ty(type_name), SchemaElement::Type(ty)));
+
+    match &ty.node.kind {
+        TypeKind::Object(ObjectType { fields, .. })
+        | TypeKind::Interface(InterfaceType { fields, .. }) => {
+            for field in fields {
+                let name = &field.node.name.node;
+                coordinates.push((
+                    SchemaCoordinate::member(type_name, name),
+                    SchemaElement::Field(field),
+                ));
+                for arg in &field.node.arguments {
+                    coordinates.push((
+                        SchemaCoordinate::argument(type_name, name, &arg.node.name.node),
+                        SchemaElement::Argument(arg),
+                    ));
+                }
+            }
+        }
+        TypeKind::InputObject(input) => {
+            for field in &input.fields {
+                coordinates.push((
+                    SchemaCoordinate::member(type_name, &field.node.name.node),
+                    SchemaElement::InputField(field),
+                ));
+            }
+        }
+        TypeKind::Enum(enum_type) => {
+            for value in &enum_type.values {
+                coordinates.push((
+                    SchemaCoordinate::member(type_name, &value.node.value.node),
+                    SchemaElement::EnumValue(value),
+                ));
+            }
+        }
+        TypeKind::Scalar | TypeKind::Union(_) => {}
+    }
+}
+
+/// Push the coordinates of a directive and of its arguments.
+fn push_directive_coordinates<'a>(
+    coordinates: &mut Vec<(SchemaCoordinate, SchemaElement<'a>)>,
+    directive: &'a Positioned<DirectiveDefinition>,
+) {
+    let name = &directive.node.name.node;
+    coordinates.push((
+        SchemaCoordinate::directive(name),
+        SchemaElement::Directive(directive),
+    ));
+    for arg in &directive.node.arguments {
+        coordinates.push((
+            SchemaCoordinate::directive_argument(name, &arg.node.name.node),
+            SchemaElement::Argument(arg),
+        ));
+    }
+}
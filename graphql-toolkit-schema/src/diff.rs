@@ -0,0 +1,853 @@
+//! Schema comparison and change classification.
+
+use std::fmt::{self, Display, Formatter};
+
+use graphql_toolkit_ast::{
+    BaseType, ConstDirective, ConstValue, DirectiveDefinition, FieldDefinition,
+    InputValueDefinition, Name, OperationType, Pos, Positioned, Type, TypeDefinition, TypeKind,
+};
+
+use crate::{coordinate::SchemaCoordinate, schema::Schema};
+
+/// How a schema change affects the existing clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Criticality {
+    /// The change breaks existing operations or clients.
+    Breaking,
+    /// The change is backward compatible, but can change the behavior of existing clients.
+    Dangerous,
+    /// The change is backward compatible.
+    Safe,
+}
+
+impl Criticality {
+    /// Get the name of the criticality, such as `BREAKING`.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Breaking => "BREAKING",
+            Self::Dangerous => "DANGEROUS",
+            Self::Safe => "SAFE",
+        }
+    }
+}
+
+impl Display for Criticality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The kind of a schema change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeCode {
+    /// A root operation type was added, removed or replaced.
+    RootTypeChanged,
+    /// A type was added.
+    TypeAdded,
+    /// A type was removed.
+    TypeRemoved,
+    /// A type changed to a different kind, such as from an object to an interface.
+    TypeKindChanged,
+    /// The description of a schema element changed.
+    DescriptionChanged,
+    /// A field, argument, input field or enum value was deprecated.
+    DeprecationAdded,
+    /// A field, argument, input field or enum value is no longer deprecated.
+    DeprecationRemoved,
+    /// A field was added to an object or interface type.
+    FieldAdded,
+    /// A field was removed from an object or interface type.
+    FieldRemoved,
+    /// The type of a field changed.
+    FieldTypeChanged,
+    /// An argument was added to a field or directive.
+    ArgumentAdded,
+    /// An argument was removed from a field or directive.
+    ArgumentRemoved,
+    /// The type of an argument changed.
+    ArgumentTypeChanged,
+    /// The default value of an argument changed.
+    ArgumentDefaultChanged,
+    /// A field was added to an input object type.
+    InputFieldAdded,
+    /// A field was removed from an input object type.
+    InputFieldRemoved,
+    /// The type of an input field changed.
+    InputFieldTypeChanged,
+    /// The default value of an input field changed.
+    InputFieldDefaultChanged,
+    /// A value was added to an enum type.
+    EnumValueAdded,
+    /// A value was removed from an enum type.
+    EnumValueRemoved,
+    /// A member type was added to a union.
+    UnionMemberAdded,
+    /// A member type was removed from a union.
+    UnionMemberRemoved,
+    /// An object or interface type implements a new interface.
+    InterfaceAdded,
+    /// An object or interface type no longer implements an interface.
+    InterfaceRemoved,
+    /// A directive was added.
+    DirectiveAdded,
+    /// A directive was removed.
+    DirectiveRemoved,
+    /// A location was added to a directive.
+    DirectiveLocationAdded,
+    /// A location was removed from a directive.
+    DirectiveLocationRemoved,
+    /// A directive became repeatable.
+    DirectiveRepeatableAdded,
+    /// A directive is no longer repeatable.
+    DirectiveRepeatableRemoved,
+}
+
+impl ChangeCode {
+    /// Get the stable code of the change, such as `FIELD_REMOVED`.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RootTypeChanged => "ROOT_TYPE_CHANGED",
+            Self::TypeAdded => "TYPE_ADDED",
+            Self::TypeRemoved => "TYPE_REMOVED",
+            Self::TypeKindChanged => "TYPE_KIND_CHANGED",
+            Self::DescriptionChanged => "DESCRIPTION_CHANGED",
+            Self::DeprecationAdded => "DEPRECATION_ADDED",
+            Self::DeprecationRemoved => "DEPRECATION_REMOVED",
+            Self::FieldAdded => "FIELD_ADDED",
+            Self::FieldRemoved => "FIELD_REMOVED",
+            Self::FieldTypeChanged => "FIELD_TYPE_CHANGED",
+            Self::ArgumentAdded => "ARGUMENT_ADDED",
+            Self::ArgumentRemoved => "ARGUMENT_REMOVED",
+            Self::ArgumentTypeChanged => "ARGUMENT_TYPE_CHANGED",
+            Self::ArgumentDefaultChanged => "ARGUMENT_DEFAULT_CHANGED",
+            Self::InputFieldAdded => "INPUT_FIELD_ADDED",
+            Self::InputFieldRemoved => "INPUT_FIELD_REMOVED",
+            Self::InputFieldTypeChanged => "INPUT_FIELD_TYPE_CHANGED",
+            Self::InputFieldDefaultChanged => "INPUT_FIELD_DEFAULT_CHANGED",
+            Self::EnumValueAdded => "ENUM_VALUE_ADDED",
+            Self::EnumValueRemoved => "ENUM_VALUE_REMOVED",
+            Self::UnionMemberAdded => "UNION_MEMBER_ADDED",
+            Self::UnionMemberRemoved => "UNION_MEMBER_REMOVED",
+            Self::InterfaceAdded => "INTERFACE_ADDED",
+            Self::InterfaceRemoved => "INTERFACE_REMOVED",
+            Self::DirectiveAdded => "DIRECTIVE_ADDED",
+            Self::DirectiveRemoved => "DIRECTIVE_REMOVED",
+            Self::DirectiveLocationAdded => "DIRECTIVE_LOCATION_ADDED",
+            Self::DirectiveLocationRemoved => "DIRECTIVE_LOCATION_REMOVED",
+            Self::DirectiveRepeatableAdded => "DIRECTIVE_REPEATABLE_ADDED",
+            Self::DirectiveRepeatableRemoved => "DIRECTIVE_REPEATABLE_REMOVED",
+        }
+    }
+}
+
+impl Display for ChangeCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A change between two schemas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// The kind of the change.
+    pub code: ChangeCode,
+    /// How the change affects the existing clients.
+    pub criticality: Criticality,
+    /// The schema coordinate of the changed element, such as `Query.user(id:)`.
+    pub coordinate: SchemaCoordinate,
+    /// The message describing the change.
+    pub message: String,
+    /// The position of the element in the old schema, if it is defined there.
+    pub old_pos: Option<Pos>,
+    /// The position of the element in the new schema, if it is defined there.
+    pub new_pos: Option<Pos>,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Compare two schemas, and classify the changes from the old schema to the new one.
+///
+/// The schemas are usually built from the old and new service documents, with
+/// [`Schema::from_document`]. The schema extensions are already folded into the definitions, so
+/// moving a field to an extension is not reported as a change.
+///
+/// The changes are reported in the order of the definitions in the old schema, followed by the
+/// additions in the order of the definitions in the new schema.
+#[must_use]
+pub fn diff_schemas(old: &Schema, new: &Schema) -> Vec<Change> {
+    let mut diff = Diff {
+        new,
+        changes: Vec::new(),
+    };
+
+    for operation in [
+        OperationType::Query,
+        OperationType::Mutation,
+        OperationType::Subscription,
+    ] {
+        let (old_root, new_root) = (old.root_type_name(operation), new.root_type_name(operation));
+        if old_root != new_root {
+            let name = |root: Option<&Name>| root.map_or("none", Name::as_str).to_owned();
+            // Only adding a root is safe, as no client can use it yet.
+            let criticality = if old_root.is_none() {
+                Criticality::Safe
+            } else {
+                Criticality::Breaking
+            };
+            diff.push(
+                ChangeCode::RootTypeChanged,
+                criticality,
+                SchemaCoordinate::ty(new_root.or(old_root).expect("one of the roots is defined")),
+                format!(
+                    "{} root type changed from {} to {}",
+                    operation,
+                    name(old_root),
+                    name(new_root)
+                ),
+                old.root_type(operation).map(|ty| ty.pos),
+                new.root_type(operation).map(|ty| ty.pos),
+            );
+        }
+    }
+
+    for old_type in old.types() {
+        let name = &old_type.node.name.node;
+        match new.get_type(name) {
+            Some(new_type) => diff.types(old_type, new_type),
+            None => diff.push(
+                ChangeCode::TypeRemoved,
+                Criticality::Breaking,
+                SchemaCoordinate::ty(name),
+                format!("type {} was removed", name),
+                Some(old_type.pos),
+                None,
+            ),
+        }
+    }
+    for new_type in new.types() {
+        let name = &new_type.node.name.node;
+        if old.get_type(name).is_none() {
+            diff.push(
+                ChangeCode::TypeAdded,
+                Criticality::Safe,
+                SchemaCoordinate::ty(name),
+                format!("type {} was added", name),
+                None,
+                Some(new_type.pos),
+            );
+        }
+    }
+
+    for old_directive in old.directives() {
+        let name = &old_directive.node.name.node;
+        match new.get_directive(name) {
+            Some(new_directive) => diff.directives(old_directive, new_directive),
+            None => diff.push(
+                ChangeCode::DirectiveRemoved,
+                Criticality::Breaking,
+                SchemaCoordinate::directive(name),
+                format!("directive @{} was removed", name),
+                Some(old_directive.pos),
+                None,
+            ),
+        }
+    }
+    for new_directive in new.directives() {
+        let name = &new_directive.node.name.node;
+        if old.get_directive(name).is_none() {
+            diff.push(
+                ChangeCode::DirectiveAdded,
+                Criticality::Safe,
+                SchemaCoordinate::directive(name),
+                format!("directive @{} was added", name),
+                None,
+                Some(new_directive.pos),
+            );
+        }
+    }
+
+    diff.changes
+}
+
+/// Where an argument or input field is defined.
+#[derive(Clone, Copy)]
+enum InputValueKind {
+    Argument,
+    InputField,
+}
+
+struct Diff<'a> {
+    /// The new schema, to check the subtypes of the changed field types.
+    new: &'a Schema,
+    changes: Vec<Change>,
+}
+
+impl<'a> Diff<'a> {
+    fn push(
+        &mut self,
+        code: ChangeCode,
+        criticality: Criticality,
+        coordinate: SchemaCoordinate,
+        message: String,
+        old_pos: Option<Pos>,
+        new_pos: Option<Pos>,
+    ) {
+        self.changes.push(Change {
+            code,
+            criticality,
+            coordinate,
+            message,
+            old_pos,
+            new_pos,
+        });
+    }
+
+    fn description<T>(
+        &mut self,
+        coordinate: &SchemaCoordinate,
+        old: &Positioned<T>,
+        old_description: &Option<Positioned<String>>,
+        new: &Positioned<T>,
+        new_description: &Option<Positioned<String>>,
+    ) {
+        let old_description = old_description.as_ref().map(|d| &d.node);
+        let new_description = new_description.as_ref().map(|d| &d.node);
+        if old_description != new_description {
+            self.push(
+                ChangeCode::DescriptionChanged,
+                Criticality::Safe,
+                coordinate.clone(),
+                format!("description of {} changed", coordinate),
+                Some(old.pos),
+                Some(new.pos),
+            );
+        }
+    }
+
+    fn deprecation<T>(
+        &mut self,
+        coordinate: &SchemaCoordinate,
+        old: &Positioned<T>,
+        old_directives: &[Positioned<ConstDirective>],
+        new: &Positioned<T>,
+        new_directives: &[Positioned<ConstDirective>],
+    ) {
+        let is_deprecated = |directives: &[Positioned<ConstDirective>]| {
+            directives
+                .iter()
+                .any(|directive| directive.node.name.node == "deprecated")
+        };
+        let (code, message) = match (is_deprecated(old_directives), is_deprecated(new_directives)) {
+            (false, true) => (
+                ChangeCode::DeprecationAdded,
+                format!("{} was deprecated", coordinate),
+            ),
+            (true, false) => (
+                ChangeCode::DeprecationRemoved,
+                format!("{} is no longer deprecated", coordinate),
+            ),
+            _ => return,
+        };
+        self.push(
+            code,
+            Criticality::Safe,
+            coordinate.clone(),
+            message,
+            Some(old.pos),
+            Some(new.pos),
+        );
+    }
+
+    fn types(&mut self, old: &Positioned<TypeDefinition>, new: &Positioned<TypeDefinition>) {
+        let name = &old.node.name.node;
+        let coordinate = SchemaCoordinate::ty(name);
+        self.description(
+            &coordinate,
+            old,
+            &old.node.description,
+            new,
+            &new.node.description,
+        );
+
+        match (&old.node.kind, &new.node.kind) {
+            (TypeKind::Scalar, TypeKind::Scalar) => {}
+            (TypeKind::Object(old_object), TypeKind::Object(new_object)) => {
+                self.interfaces(old, &old_object.implements, new, &new_object.implements);
+                self.fields(name, &old_object.fields, &new_object.fields);
+            }
+            (TypeKind::Interface(old_interface), TypeKind::Interface(new_interface)) => {
+                self.interfaces(
+                    old,
+                    &old_interface.implements,
+                    new,
+                    &new_interface.implements,
+                );
+                self.fields(name, &old_interface.fields, &new_interface.fields);
+            }
+            (TypeKind::Union(old_union), TypeKind::Union(new_union)) => {
+                self.members(
+                    old,
+                    &old_union.members,
+                    new,
+                    &new_union.members,
+                    ChangeCode::UnionMemberRemoved,
+                    ChangeCode::UnionMemberAdded,
+                );
+            }
+            (TypeKind::Enum(old_enum), TypeKind::Enum(new_enum)) => {
+                for old_value in &old_enum.values {
+                    let value = &old_value.node.value.node;
+                    let coordinate = SchemaCoordinate::member(name, value);
+                    match new_enum.values.iter().find(|v| &v.node.value.node == value) {
+                        Some(new_value) => {
+                            self.description(
+                                &coordinate,
+                                old_value,
+                                &old_value.node.description,
+                                new_value,
+                                &new_value.node.description,
+                            );
+                            self.deprecation(
+                                &coordinate,
+                                old_value,
+                                &old_value.node.directives,
+                                new_value,
+                                &new_value.node.directives,
+                            );
+                        }
+                        None => self.push(
+                            ChangeCode::EnumValueRemoved,
+                            Criticality::Breaking,
+                            coordinate.clone(),
+                            format!("enum value {} was removed", coordinate),
+                            Some(old_value.pos),
+                            None,
+                        ),
+                    }
+                }
+                for new_value in &new_enum.values {
+                    let value = &new_value.node.value.node;
+                    if old_enum.values.iter().all(|v| &v.node.value.node != value) {
+                        let coordinate = SchemaCoordinate::member(name, value);
+                        self.push(
+                            ChangeCode::EnumValueAdded,
+                            Criticality::Dangerous,
+                            coordinate.clone(),
+                            format!("enum value {} was added", coordinate),
+                            None,
+                            Some(new_value.pos),
+                        );
+                    }
+                }
+            }
+            (TypeKind::InputObject(old_input), TypeKind::InputObject(new_input)) => {
+                self.input_values(
+                    &coordinate,
+                    InputValueKind::InputField,
+                    &old_input.fields,
+                    &new_input.fields,
+                );
+            }
+            (old_kind, new_kind) => self.push(
+                ChangeCode::TypeKindChanged,
+                Criticality::Breaking,
+                coordinate,
+                format!(
+                    "type {} changed from {} to {}",
+                    name,
+                    kind_name(old_kind),
+                    kind_name(new_kind)
+                ),
+                Some(old.pos),
+                Some(new.pos),
+            ),
+        }
+    }
+
+    fn interfaces(
+        &mut self,
+        old: &Positioned<TypeDefinition>,
+        old_interfaces: &[Positioned<Name>],
+        new: &Positioned<TypeDefinition>,
+        new_interfaces: &[Positioned<Name>],
+    ) {
+        self.members(
+            old,
+            old_interfaces,
+            new,
+            new_interfaces,
+            ChangeCode::InterfaceRemoved,
+            ChangeCode::InterfaceAdded,
+        );
+    }
+
+    /// Compare the union members or the implemented interfaces of a type.
+    fn members(
+        &mut self,
+        old: &Positioned<TypeDefinition>,
+        old_members: &[Positioned<Name>],
+        new: &Positioned<TypeDefinition>,
+        new_members: &[Positioned<Name>],
+        removed: ChangeCode,
+        added: ChangeCode,
+    ) {
+        let name = &old.node.name.node;
+        let message = |member: &Name, code| match code {
+            ChangeCode::UnionMemberRemoved => {
+                format!("type {} was removed from union {}", member, name)
+            }
+            ChangeCode::UnionMemberAdded => format!("type {} was added to union {}", member, name),
+            ChangeCode::InterfaceRemoved => {
+                format!("type {} no longer implements {}", name, member)
+            }
+            _ => format!("type {} now implements {}", name, member),
+        };
+
+        for member in old_members {
+            if new_members.iter().all(|m| m.node != member.node) {
+                self.push(
+                    removed,
+                    Criticality::Breaking,
+                    SchemaCoordinate::ty(name),
+                    message(&member.node, removed),
+                    Some(member.pos),
+                    Some(new.pos),
+                );
+            }
+        }
+        for member in new_members {
+            if old_members.iter().all(|m| m.node != member.node) {
+                self.push(
+                    added,
+                    Criticality::Dangerous,
+                    SchemaCoordinate::ty(name),
+                    message(&member.node, added),
+                    Some(old.pos),
+                    Some(member.pos),
+                );
+            }
+        }
+    }
+
+    fn fields(
+        &mut self,
+        type_name: &Name,
+        old_fields: &[Positioned<FieldDefinition>],
+        new_fields: &[Positioned<FieldDefinition>],
+    ) {
+        for old_field in old_fields {
+            let name = &old_field.node.name.node;
+            let coordinate = SchemaCoordinate::member(type_name, name);
+            let Some(new_field) = new_fields.iter().find(|f| &f.node.name.node == name) else {
+                self.push(
+                    ChangeCode::FieldRemoved,
+                    Criticality::Breaking,
+                    coordinate.clone(),
+                    format!("field {} was removed", coordinate),
+                    Some(old_field.pos),
+                    None,
+                );
+                continue;
+            };
+
+            self.description(
+                &coordinate,
+                old_field,
+                &old_field.node.description,
+                new_field,
+                &new_field.node.description,
+            );
+            self.deprecation(
+                &coordinate,
+                old_field,
+                &old_field.node.directives,
+                new_field,
+                &new_field.node.directives,
+            );
+
+            let (old_type, new_type) = (&old_field.node.ty.node, &new_field.node.ty.node);
+            if old_type != new_type {
+                let criticality = if self.new.is_subtype(new_type, old_type) {
+                    Criticality::Safe
+                } else {
+                    Criticality::Breaking
+                };
+                self.push(
+                    ChangeCode::FieldTypeChanged,
+                    criticality,
+                    coordinate.clone(),
+                    format!(
+                        "field {} changed type from {} to {}",
+                        coordinate, old_type, new_type
+                    ),
+                    Some(old_field.node.ty.pos),
+                    Some(new_field.node.ty.pos),
+                );
+            }
+
+            self.input_values(
+                &coordinate,
+                InputValueKind::Argument,
+                &old_field.node.arguments,
+                &new_field.node.arguments,
+            );
+        }
+
+        for new_field in new_fields {
+            let name = &new_field.node.name.node;
+            if old_fields.iter().all(|f| &f.node.name.node != name) {
+                let coordinate = SchemaCoordinate::member(type_name, name);
+                self.push(
+                    ChangeCode::FieldAdded,
+                    Criticality::Safe,
+                    coordinate.clone(),
+                    format!("field {} was added", coordinate),
+                    None,
+                    Some(new_field.pos),
+                );
+            }
+        }
+    }
+
+    /// Compare the arguments of a field or directive, or the fields of an input object.
+    ///
+    /// The parent is the coordinate of the field, directive or input object.
+    fn input_values(
+        &mut self,
+        parent: &SchemaCoordinate,
+        kind: InputValueKind,
+        old_values: &[Positioned<InputValueDefinition>],
+        new_values: &[Positioned<InputValueDefinition>],
+    ) {
+        let (removed, added, type_changed, default_changed, label) = match kind {
+            InputValueKind::Argument => (
+                ChangeCode::ArgumentRemoved,
+                ChangeCode::ArgumentAdded,
+                ChangeCode::ArgumentTypeChanged,
+                ChangeCode::ArgumentDefaultChanged,
+                "argument",
+            ),
+            InputValueKind::InputField => (
+                ChangeCode::InputFieldRemoved,
+                ChangeCode::InputFieldAdded,
+                ChangeCode::InputFieldTypeChanged,
+                ChangeCode::InputFieldDefaultChanged,
+                "input field",
+            ),
+        };
+        let coordinate = |name: &Name| match parent {
+            SchemaCoordinate::Member { type_name, member } => {
+                SchemaCoordinate::argument(type_name, member, name)
+            }
+            SchemaCoordinate::Directive { name: directive } => {
+                SchemaCoordinate::directive_argument(directive, name)
+            }
+            _ => SchemaCoordinate::member(parent.type_name().expect("a type coordinate"), name),
+        };
+
+        for old_value in old_values {
+            let name = &old_value.node.name.node;
+            let coordinate = coordinate(name);
+            let Some(new_value) = new_values.iter().find(|v| &v.node.name.node == name) else {
+                self.push(
+                    removed,
+                    Criticality::Breaking,
+                    coordinate.clone(),
+                    format!("{} {} was removed", label, coordinate),
+                    Some(old_value.pos),
+                    None,
+                );
+                continue;
+            };
+
+            self.description(
+                &coordinate,
+                old_value,
+                &old_value.node.description,
+                new_value,
+                &new_value.node.description,
+            );
+            self.deprecation(
+                &coordinate,
+                old_value,
+                &old_value.node.directives,
+                new_value,
+                &new_value.node.directives,
+            );
+
+            let (old_type, new_type) = (&old_value.node.ty.node, &new_value.node.ty.node);
+            if old_type != new_type {
+                let criticality = if is_safe_input_type_change(old_type, new_type) {
+                    Criticality::Safe
+                } else {
+                    Criticality::Breaking
+                };
+                self.push(
+                    type_changed,
+                    criticality,
+                    coordinate.clone(),
+                    format!(
+                        "{} {} changed type from {} to {}",
+                        label, coordinate, old_type, new_type
+                    ),
+                    Some(old_value.node.ty.pos),
+                    Some(new_value.node.ty.pos),
+                );
+            }
+
+            let old_default = old_value.node.default_value.as_ref();
+            let new_default = new_value.node.default_value.as_ref();
+            if old_default.map(|v| &v.node) != new_default.map(|v| &v.node) {
+                let value = |value: Option<&Positioned<ConstValue>>| {
+                    value.map_or_else(|| "none".to_owned(), |value| value.node.to_string())
+                };
+                self.push(
+                    default_changed,
+                    Criticality::Dangerous,
+                    coordinate.clone(),
+                    format!(
+                        "default value of {} {} changed from {} to {}",
+                        label,
+                        coordinate,
+                        value(old_default),
+                        value(new_default)
+                    ),
+                    Some(old_default.map_or(old_value.pos, |v| v.pos)),
+                    Some(new_default.map_or(new_value.pos, |v| v.pos)),
+                );
+            }
+        }
+
+        for new_value in new_values {
+            let name = &new_value.node.name.node;
+            if old_values.iter().all(|v| &v.node.name.node != name) {
+                let is_required =
+                    !new_value.node.ty.node.nullable && new_value.node.default_value.is_none();
+                let (criticality, required) = if is_required {
+                    (Criticality::Breaking, "required")
+                } else {
+                    (Criticality::Dangerous, "optional")
+                };
+                let coordinate = coordinate(name);
+                self.push(
+                    added,
+                    criticality,
+                    coordinate.clone(),
+                    format!("{} {} {} was added", required, label, coordinate),
+                    None,
+                    Some(new_value.pos),
+                );
+            }
+        }
+    }
+
+    fn directives(
+        &mut self,
+        old: &Positioned<DirectiveDefinition>,
+        new: &Positioned<DirectiveDefinition>,
+    ) {
+        let coordinate = SchemaCoordinate::directive(&old.node.name.node);
+        self.description(
+            &coordinate,
+            old,
+            &old.node.description,
+            new,
+            &new.node.description,
+        );
+
+        match (old.node.is_repeatable, new.node.is_repeatable) {
+            (true, false) => self.push(
+                ChangeCode::DirectiveRepeatableRemoved,
+                Criticality::Breaking,
+                coordinate.clone(),
+                format!("directive {} is no longer repeatable", coordinate),
+                Some(old.pos),
+                Some(new.pos),
+            ),
+            (false, true) => self.push(
+                ChangeCode::DirectiveRepeatableAdded,
+                Criticality::Safe,
+                coordinate.clone(),
+                format!("directive {} is now repeatable", coordinate),
+                Some(old.pos),
+                Some(new.pos),
+            ),
+            _ => {}
+        }
+
+        for location in &old.node.locations {
+            if new.node.locations.iter().all(|l| l.node != location.node) {
+                self.push(
+                    ChangeCode::DirectiveLocationRemoved,
+                    Criticality::Breaking,
+                    coordinate.clone(),
+                    format!(
+                        "location {} was removed from directive {}",
+                        location.node.as_str(),
+                        coordinate
+                    ),
+                    Some(location.pos),
+                    Some(new.pos),
+                );
+            }
+        }
+        for location in &new.node.locations {
+            if old.node.locations.iter().all(|l| l.node != location.node) {
+                self.push(
+                    ChangeCode::DirectiveLocationAdded,
+                    Criticality::Safe,
+                    coordinate.clone(),
+                    format!(
+                        "location {} was added to directive {}",
+                        location.node.as_str(),
+                        coordinate
+                    ),
+                    Some(old.pos),
+                    Some(location.pos),
+                );
+            }
+        }
+
+        self.input_values(
+            &coordinate,
+            InputValueKind::Argument,
+            &old.node.arguments,
+            &new.node.arguments,
+        );
+    }
+}
+
+fn kind_name(kind: &TypeKind) -> &'static str {
+    match kind {
+        TypeKind::Scalar => "scalar",
+        TypeKind::Object(_) => "object",
+        TypeKind::Interface(_) => "interface",
+        TypeKind::Union(_) => "union",
+        TypeKind::Enum(_) => "enum",
+        TypeKind::InputObject(_) => "input object",
+    }
+}
+
+/// Whether changing the type of an argument or input field is safe for the clients providing
+/// it: the new type can only be looser.
+fn is_safe_input_type_change(old: &Type, new: &Type) -> bool {
+    if !old.nullable {
+        return is_safe_input_type_change(&old.to_nullable(), &new.to_nullable());
+    }
+    if !new.nullable {
+        return false;
+    }
+
+    match (&old.base, &new.base) {
+        (BaseType::List(old), BaseType::List(new)) => is_safe_input_type_change(old, new),
+        (BaseType::Named(old), BaseType::Named(new)) => old == new,
+        _ => false,
+    }
+}
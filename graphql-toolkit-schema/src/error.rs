@@ -0,0 +1,193 @@
+use std::fmt::{self, Display, Formatter};
+
+use graphql_toolkit_ast::{Name, OperationType, Pos};
+
+/// Schema build error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The schema definition is present multiple times.
+    SchemaDefinitionDuplicated {
+        /// The position of the first definition.
+        first: Pos,
+        /// The position of the second definition.
+        second: Pos,
+    },
+    /// A type is defined multiple times.
+    TypeDuplicated {
+        /// The name of the type.
+        name: Name,
+        /// The position of the first definition.
+        first: Pos,
+        /// The position of the second definition.
+        second: Pos,
+    },
+    /// A directive is defined multiple times.
+    DirectiveDuplicated {
+        /// The name of the directive.
+        name: Name,
+        /// The position of the first definition.
+        first: Pos,
+        /// The position of the second definition.
+        second: Pos,
+    },
+    /// A type extension refers to a type that is not defined.
+    ExtendedTypeNotDefined {
+        /// The name of the extended type.
+        name: Name,
+        /// The position of the extension.
+        pos: Pos,
+    },
+    /// A type extension is of a different kind than the extended type.
+    ExtensionKindMismatch {
+        /// The name of the extended type.
+        name: Name,
+        /// The position of the type definition.
+        definition: Pos,
+        /// The position of the extension.
+        pos: Pos,
+    },
+    /// A root operation type is defined by both the schema and a schema extension.
+    RootOperationDuplicated {
+        /// The duplicated root operation.
+        root: OperationType,
+        /// The position of the first definition.
+        first: Pos,
+        /// The position of the second definition.
+        second: Pos,
+    },
+    /// A field or input field is defined multiple times in a type.
+    FieldDuplicated {
+        /// The name of the type.
+        type_name: Name,
+        /// The name of the field.
+        field: Name,
+        /// The position of the first definition.
+        first: Pos,
+        /// The position of the second definition.
+        second: Pos,
+    },
+    /// An enum value is defined multiple times in an enum.
+    EnumValueDuplicated {
+        /// The name of the enum.
+        type_name: Name,
+        /// The enum value.
+        value: Name,
+        /// The position of the first definition.
+        first: Pos,
+        /// The position of the second definition.
+        second: Pos,
+    },
+    /// A member type is included multiple times in a union.
+    UnionMemberDuplicated {
+        /// The name of the union.
+        type_name: Name,
+        /// The name of the member type.
+        member: Name,
+        /// The position of the first inclusion.
+        first: Pos,
+        /// The position of the second inclusion.
+        second: Pos,
+    },
+    /// An interface is implemented multiple times by a type.
+    InterfaceDuplicated {
+        /// The name of the implementing type.
+        type_name: Name,
+        /// The name of the interface.
+        interface: Name,
+        /// The position of the first implementation.
+        first: Pos,
+        /// The position of the second implementation.
+        second: Pos,
+    },
+    /// A root operation type refers to a type that is not defined.
+    UnknownRootType {
+        /// The root operation.
+        root: OperationType,
+        /// The name of the root operation type.
+        name: Name,
+        /// The position of the root operation type reference.
+        pos: Pos,
+    },
+    /// A root operation type refers to a type that is not an object type.
+    RootTypeNotObject {
+        /// The root operation.
+        root: OperationType,
+        /// The name of the root operation type.
+        name: Name,
+        /// The position of the root operation type reference.
+        pos: Pos,
+    },
+}
+
+impl Error {
+    /// Get the positions of the error.
+    ///
+    /// The positions are ordered from most important to least important.
+    #[must_use]
+    pub fn positions(&self) -> Vec<Pos> {
+        match self {
+            Self::SchemaDefinitionDuplicated { first, second }
+            | Self::TypeDuplicated { first, second, .. }
+            | Self::DirectiveDuplicated { first, second, .. }
+            | Self::RootOperationDuplicated { first, second, .. }
+            | Self::FieldDuplicated { first, second, .. }
+            | Self::EnumValueDuplicated { first, second, .. }
+            | Self::UnionMemberDuplicated { first, second, .. }
+            | Self::InterfaceDuplicated { first, second, .. } => vec![*second, *first],
+            Self::ExtensionKindMismatch {
+                definition, pos, ..
+            } => vec![*pos, *definition],
+            Self::ExtendedTypeNotDefined { pos, .. }
+            | Self::UnknownRootType { pos, .. }
+            | Self::RootTypeNotObject { pos, .. } => vec![*pos],
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SchemaDefinitionDuplicated { .. } => f.write_str("schema is defined twice"),
+            Self::TypeDuplicated { name, .. } => write!(f, "type {} is defined twice", name),
+            Self::DirectiveDuplicated { name, .. } => {
+                write!(f, "directive @{} is defined twice", name)
+            }
+            Self::ExtendedTypeNotDefined { name, .. } => {
+                write!(f, "extended type {} is not defined", name)
+            }
+            Self::ExtensionKindMismatch { name, .. } => {
+                write!(f, "extension of type {} is of a different kind", name)
+            }
+            Self::RootOperationDuplicated { root, .. } => {
+                write!(f, "{} root type is defined twice", root)
+            }
+            Self::FieldDuplicated {
+                type_name, field, ..
+            } => write!(f, "field {}.{} is defined twice", type_name, field),
+            Self::EnumValueDuplicated {
+                type_name, value, ..
+            } => write!(f, "enum value {}.{} is defined twice", type_name, value),
+            Self::UnionMemberDuplicated {
+                type_name, member, ..
+            } => write!(
+                f,
+                "type {} is included twice in union {}",
+                member, type_name
+            ),
+            Self::InterfaceDuplicated {
+                type_name,
+                interface,
+                ..
+            } => write!(f, "type {} implements {} twice", type_name, interface),
+            Self::UnknownRootType { root, name, .. } => {
+                write!(f, "{} root type {} is not defined", root, name)
+            }
+            Self::RootTypeNotObject { root, name, .. } => {
+                write!(f, "{} root type {} is not an object type", root, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
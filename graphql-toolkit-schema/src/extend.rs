@@ -0,0 +1,297 @@
+//! Type-system extensions.
+//!
+//! [Reference](https://spec.graphql.org/October2021/#sec-Type-System-Extensions).
+
+use graphql_toolkit_ast::{
+    indexmap::IndexMap, FieldDefinition, InputValueDefinition, Name, OperationType, Pos,
+    Positioned, SchemaDefinition, ServiceDocument, TypeDefinition, TypeKind, TypeSystemDefinition,
+};
+
+use crate::error::Error;
+
+/// Fold the type-system extensions of the document into their base definitions.
+///
+/// The extensions can appear before or after the extended definition. The returned document
+/// contains the base definitions, in their original order, and no extensions.
+///
+/// # Errors
+///
+/// Fails with all the errors found if an extension refers to a type that is not defined, is of
+/// a different kind than the extended type, or redefines a field, value, member, interface or
+/// root operation type.
+pub fn apply_extensions(document: ServiceDocument) -> Result<ServiceDocument, Vec<Error>> {
+    let mut errors = Vec::new();
+
+    let mut schema: Option<Positioned<SchemaDefinition>> = None;
+    let mut schema_index = None;
+    let mut types: IndexMap<Name, (usize, Positioned<TypeDefinition>)> = IndexMap::new();
+    let mut definitions = Vec::new();
+    let mut extensions = Vec::new();
+
+    // The first definition of each type is the extension target. Duplicated definitions are
+    // kept as-is, and left to the schema builder to report.
+    for (index, def) in document.definitions.into_iter().enumerate() {
+        match def {
+            TypeSystemDefinition::Schema(def) if def.node.extend => {
+                extensions.push((index, TypeSystemDefinition::Schema(def)));
+            }
+            TypeSystemDefinition::Type(def) if def.node.extend => {
+                extensions.push((index, TypeSystemDefinition::Type(def)));
+            }
+            TypeSystemDefinition::Schema(def) if schema.is_none() => {
+                schema = Some(def);
+                schema_index = Some(index);
+            }
+            TypeSystemDefinition::Type(def) if !types.contains_key(&def.node.name.node) => {
+                types.insert(def.node.name.node.clone(), (index, def));
+            }
+            def => definitions.push((index, def)),
+        }
+    }
+
+    for (index, extension) in extensions {
+        match extension {
+            TypeSystemDefinition::Schema(extension) => {
+                // Without a schema definition, the extension applies to the implicit schema,
+                // which takes the place of the first extension.
+                let schema = schema.get_or_insert_with(|| {
+                    schema_index = Some(index);
+                    implicit_schema(extension.pos, |name| types.get(name).map(|(_, ty)| ty))
+                });
+                extend_schema(schema, extension, &mut errors);
+            }
+            TypeSystemDefinition::Type(extension) => {
+                match types.get_mut(&extension.node.name.node) {
+                    Some((_, ty)) => extend_type(ty, extension, &mut errors),
+                    None => errors.push(Error::ExtendedTypeNotDefined {
+                        name: extension.node.name.node,
+                        pos: extension.pos,
+                    }),
+                }
+            }
+            TypeSystemDefinition::Directive(_) => unreachable!("directives cannot be extended"),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    definitions.extend(
+        schema_index
+            .zip(schema)
+            .map(|(index, def)| (index, TypeSystemDefinition::Schema(def))),
+    );
+    definitions.extend(
+        types
+            .into_values()
+            .map(|(index, def)| (index, TypeSystemDefinition::Type(def))),
+    );
+    definitions.sort_by_key(|(index, _)| *index);
+
+    Ok(ServiceDocument {
+        definitions: definitions.into_iter().map(|(_, def)| def).collect(),
+    })
+}
+
+/// Build the implicit schema definition of a document without one, for a schema extension to
+/// apply to. Its root operation types are the object types with the default root names.
+pub(crate) fn implicit_schema<'a>(
+    pos: Pos,
+    get_type: impl Fn(&str) -> Option<&'a Positioned<TypeDefinition>>,
+) -> Positioned<SchemaDefinition> {
+    let root = |name: &str| {
+        let ty = get_type(name)?;
+        matches!(ty.node.kind, TypeKind::Object(_))
+            .then(|| Positioned::new(Name::new(name), ty.node.name.pos))
+    };
+
+    Positioned::new(
+        SchemaDefinition {
+            extend: false,
+            directives: Vec::new(),
+            query: root("Query"),
+            mutation: root("Mutation"),
+            subscription: root("Subscription"),
+        },
+        pos,
+    )
+}
+
+/// Extend the schema definition with the directives and root operation types of the
+/// extension.
+pub(crate) fn extend_schema(
+    schema: &mut Positioned<SchemaDefinition>,
+    extension: Positioned<SchemaDefinition>,
+    errors: &mut Vec<Error>,
+) {
+    let SchemaDefinition {
+        directives,
+        query,
+        mutation,
+        subscription,
+        ..
+    } = extension.node;
+
+    schema.node.directives.extend(directives);
+
+    for (operation, root, extension_root) in [
+        (OperationType::Query, &mut schema.node.query, query),
+        (OperationType::Mutation, &mut schema.node.mutation, mutation),
+        (
+            OperationType::Subscription,
+            &mut schema.node.subscription,
+            subscription,
+        ),
+    ] {
+        let Some(extension_root) = extension_root else {
+            continue;
+        };
+
+        match root {
+            Some(first) => errors.push(Error::RootOperationDuplicated {
+                root: operation,
+                first: first.pos,
+                second: extension_root.pos,
+            }),
+            None => *root = Some(extension_root),
+        }
+    }
+}
+
+/// Extend the type definition with the directives, and the fields, interfaces, members or values
+/// of the extension.
+pub(crate) fn extend_type(
+    ty: &mut Positioned<TypeDefinition>,
+    extension: Positioned<TypeDefinition>,
+    errors: &mut Vec<Error>,
+) {
+    let type_name = &ty.node.name.node;
+    let TypeDefinition {
+        directives, kind, ..
+    } = extension.node;
+
+    match (&mut ty.node.kind, kind) {
+        (TypeKind::Scalar, TypeKind::Scalar) => {}
+        (TypeKind::Object(object), TypeKind::Object(extension)) => {
+            extend_interfaces(
+                type_name,
+                &mut object.implements,
+                extension.implements,
+                errors,
+            );
+            extend_fields(type_name, &mut object.fields, extension.fields, errors);
+        }
+        (TypeKind::Interface(interface), TypeKind::Interface(extension)) => {
+            extend_interfaces(
+                type_name,
+                &mut interface.implements,
+                extension.implements,
+                errors,
+            );
+            extend_fields(type_name, &mut interface.fields, extension.fields, errors);
+        }
+        (TypeKind::Union(union), TypeKind::Union(extension)) => {
+            for member in extension.members {
+                match union.members.iter().find(|m| m.node == member.node) {
+                    Some(first) => errors.push(Error::UnionMemberDuplicated {
+                        type_name: type_name.clone(),
+                        member: member.node,
+                        first: first.pos,
+                        second: member.pos,
+                    }),
+                    None => union.members.push(member),
+                }
+            }
+        }
+        (TypeKind::Enum(enum_type), TypeKind::Enum(extension)) => {
+            for value in extension.values {
+                match enum_type
+                    .values
+                    .iter()
+                    .find(|v| v.node.value.node == value.node.value.node)
+                {
+                    Some(first) => errors.push(Error::EnumValueDuplicated {
+                        type_name: type_name.clone(),
+                        value: value.node.value.node,
+                        first: first.pos,
+                        second: value.pos,
+                    }),
+                    None => enum_type.values.push(value),
+                }
+            }
+        }
+        (TypeKind::InputObject(input), TypeKind::InputObject(extension)) => {
+            extend_input_fields(type_name, &mut input.fields, extension.fields, errors);
+        }
+        _ => {
+            errors.push(Error::ExtensionKindMismatch {
+                name: type_name.clone(),
+                definition: ty.pos,
+                pos: extension.pos,
+            });
+            return;
+        }
+    }
+
+    ty.node.directives.extend(directives);
+}
+
+fn extend_interfaces(
+    type_name: &Name,
+    implements: &mut Vec<Positioned<Name>>,
+    extension: Vec<Positioned<Name>>,
+    errors: &mut Vec<Error>,
+) {
+    for interface in extension {
+        match implements.iter().find(|i| i.node == interface.node) {
+            Some(first) => errors.push(Error::InterfaceDuplicated {
+                type_name: type_name.clone(),
+                interface: interface.node,
+                first: first.pos,
+                second: interface.pos,
+            }),
+            None => implements.push(interface),
+        }
+    }
+}
+
+fn extend_fields(
+    type_name: &Name,
+    fields: &mut Vec<Positioned<FieldDefinition>>,
+    extension: Vec<Positioned<FieldDefinition>>,
+    errors: &mut Vec<Error>,
+) {
+    for field in extension {
+        let name = &field.node.name.node;
+        match fields.iter().find(|f| &f.node.name.node == name) {
+            Some(first) => errors.push(Error::FieldDuplicated {
+                type_name: type_name.clone(),
+                field: field.node.name.node,
+                first: first.pos,
+                second: field.pos,
+            }),
+            None => fields.push(field),
+        }
+    }
+}
+
+fn extend_input_fields(
+    type_name: &Name,
+    fields: &mut Vec<Positioned<InputValueDefinition>>,
+    extension: Vec<Positioned<InputValueDefinition>>,
+    errors: &mut Vec<Error>,
+) {
+    for field in extension {
+        let name = &field.node.name.node;
+        match fields.iter().find(|f| &f.node.name.node == name) {
+            Some(first) => errors.push(Error::FieldDuplicated {
+                type_name: type_name.clone(),
+                field: field.node.name.node,
+                first: first.pos,
+                second: field.pos,
+            }),
+            None => fields.push(field),
+        }
+    }
+}
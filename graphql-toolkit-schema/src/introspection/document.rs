@@ -0,0 +1,406 @@
+//! Service document building from an introspection result.
+
+use std::fmt::{self, Display, Formatter};
+
+use graphql_toolkit_ast::{
+    BaseType, ConstDirective, ConstValue, DirectiveDefinition, DirectiveLocationParseError,
+    EnumType, EnumValueDefinition, FieldDefinition, InputObjectType, InputValueDefinition,
+    InterfaceType, Name, ObjectType, Pos, Positioned, SchemaDefinition, ServiceDocument, Type,
+    TypeDefinition, TypeKind, TypeSystemDefinition, UnionType,
+};
+
+use super::DEFAULT_DEPRECATION_REASON;
+use crate::builtins::{BUILTIN_DIRECTIVES, BUILTIN_SCALARS};
+
+/// Introspection result conversion error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntrospectionError {
+    /// The message of the error.
+    pub message: String,
+    /// The path to the offending value in the introspection result, such as
+    /// `__schema.types[2].name`.
+    pub path: String,
+}
+
+impl Display for IntrospectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if !self.path.is_empty() {
+            write!(f, " at {}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for IntrospectionError {}
+
+/// Build a service document from the result of an introspection query.
+///
+/// The result can be the whole response, with the `data` field, or just its data. The default
+/// values are parsed back to values, and the deprecation reasons, the `@specifiedBy` URLs and the
+/// `@oneOf` input objects are restored as directives. The introspection types, the built-in
+/// scalars and the built-in directives are not included in the document.
+///
+/// # Errors
+///
+/// Fails if the result does not have the shape of an introspection result.
+pub fn introspection_to_document(
+    result: &ConstValue,
+) -> Result<ServiceDocument, IntrospectionError> {
+    let data = match result {
+        ConstValue::Object(fields) if fields.contains_key("data") => &fields["data"],
+        _ => result,
+    };
+    let schema = Reader::new(data, String::new()).field("__schema")?;
+
+    let mut definitions = Vec::new();
+
+    let types = schema.field("types")?.list()?;
+    let mut type_names = Vec::with_capacity(types.len());
+    for ty in &types {
+        type_names.push(ty.field("name")?.name()?);
+    }
+
+    let root_names = ["queryType", "mutationType", "subscriptionType"]
+        .into_iter()
+        .map(|field| match schema.optional_field(field) {
+            Some(root) => root.field("name")?.name().map(Some),
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // The schema definition is only needed if the root types do not follow the conventional
+    // names.
+    let is_conventional = root_names
+        .iter()
+        .zip(["Query", "Mutation", "Subscription"])
+        .all(|(root, conventional)| {
+            let conventional = type_names.iter().find(|name| name.as_str() == conventional);
+            root.as_ref() == conventional
+        });
+    if !is_conventional {
+        let [query, mutation, subscription] =
+            [0, 1, 2].map(|index| root_names[index].clone().map(positioned));
+        definitions.push(TypeSystemDefinition::Schema(positioned(SchemaDefinition {
+            extend: false,
+            directives: Vec::new(),
+            query,
+            mutation,
+            subscription,
+        })));
+    }
+
+    for (ty, name) in types.iter().zip(type_names) {
+        if name.starts_with("__") || BUILTIN_SCALARS.contains(&name.as_str()) {
+            continue;
+        }
+        definitions.push(TypeSystemDefinition::Type(positioned(
+            ty.type_definition(name)?,
+        )));
+    }
+
+    if let Some(directives) = schema.optional_field("directives") {
+        for directive in directives.list()? {
+            let name = directive.field("name")?.name()?;
+            if BUILTIN_DIRECTIVES.contains(&name.as_str()) {
+                continue;
+            }
+            definitions.push(TypeSystemDefinition::Directive(positioned(
+                directive.directive_definition(name)?,
+            )));
+        }
+    }
+
+    Ok(ServiceDocument { definitions })
+}
+
+/// A value of the introspection result, with its path.
+struct Reader<'a> {
+    value: &'a ConstValue,
+    path: String,
+}
+
+impl<'a> Reader<'a> {
+    fn new(value: &'a ConstValue, path: String) -> Self {
+        Self { value, path }
+    }
+
+    fn error(&self, message: impl Into<String>) -> IntrospectionError {
+        IntrospectionError {
+            message: message.into(),
+            path: self.path.clone(),
+        }
+    }
+
+    fn optional_field(&self, name: &str) -> Option<Reader<'a>> {
+        let ConstValue::Object(fields) = self.value else {
+            return None;
+        };
+        let path = match self.path.as_str() {
+            "" => name.to_owned(),
+            path => format!("{}.{}", path, name),
+        };
+        fields
+            .get(name)
+            .filter(|value| !matches!(value, ConstValue::Null))
+            .map(|value| Reader::new(value, path))
+    }
+
+    fn field(&self, name: &str) -> Result<Reader<'a>, IntrospectionError> {
+        if !matches!(self.value, ConstValue::Object(_)) {
+            return Err(self.error("expected an object"));
+        }
+        self.optional_field(name)
+            .ok_or_else(|| self.error(format!("missing field {}", name)))
+    }
+
+    fn list(&self) -> Result<Vec<Reader<'a>>, IntrospectionError> {
+        let ConstValue::List(items) = self.value else {
+            return Err(self.error("expected a list"));
+        };
+        Ok(items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| Reader::new(item, format!("{}[{}]", self.path, index)))
+            .collect())
+    }
+
+    /// Read a string, or an enum value as its name.
+    fn string(&self) -> Result<&'a str, IntrospectionError> {
+        match self.value {
+            ConstValue::String(value) => Ok(value),
+            ConstValue::Enum(value) => Ok(value),
+            _ => Err(self.error("expected a string")),
+        }
+    }
+
+    fn name(&self) -> Result<Name, IntrospectionError> {
+        self.string().map(Name::new)
+    }
+
+    fn boolean(&self) -> Result<bool, IntrospectionError> {
+        match self.value {
+            ConstValue::Boolean(value) => Ok(*value),
+            _ => Err(self.error("expected a boolean")),
+        }
+    }
+
+    fn description(&self) -> Result<Option<Positioned<String>>, IntrospectionError> {
+        self.optional_field("description")
+            .map(|description| description.string().map(|s| positioned(s.to_owned())))
+            .transpose()
+    }
+
+    /// Read the `@deprecated` directive from the `isDeprecated` and `deprecationReason` fields.
+    fn deprecation(&self) -> Result<Vec<Positioned<ConstDirective>>, IntrospectionError> {
+        let is_deprecated = match self.optional_field("isDeprecated") {
+            Some(is_deprecated) => is_deprecated.boolean()?,
+            None => false,
+        };
+        if !is_deprecated {
+            return Ok(Vec::new());
+        }
+
+        let reason = self
+            .optional_field("deprecationReason")
+            .map(|reason| reason.string())
+            .transpose()?
+            .filter(|reason| *reason != DEFAULT_DEPRECATION_REASON);
+        let arguments = reason
+            .map(|reason| {
+                (
+                    positioned(Name::new("reason")),
+                    positioned(ConstValue::String(reason.to_owned())),
+                )
+            })
+            .into_iter()
+            .collect();
+        Ok(vec![directive("deprecated", arguments)])
+    }
+
+    fn type_definition(&self, name: Name) -> Result<TypeDefinition, IntrospectionError> {
+        let mut directives = Vec::new();
+
+        let kind = match self.field("kind")?.string()? {
+            "SCALAR" => {
+                if let Some(url) = self.optional_field("specifiedByURL") {
+                    let url = ConstValue::String(url.string()?.to_owned());
+                    directives.push(directive(
+                        "specifiedBy",
+                        vec![(positioned(Name::new("url")), positioned(url))],
+                    ));
+                }
+                TypeKind::Scalar
+            }
+            "OBJECT" => TypeKind::Object(ObjectType {
+                implements: self.type_names("interfaces")?,
+                fields: self.fields()?,
+            }),
+            "INTERFACE" => TypeKind::Interface(InterfaceType {
+                implements: self.type_names("interfaces")?,
+                fields: self.fields()?,
+            }),
+            "UNION" => TypeKind::Union(UnionType {
+                members: self.type_names("possibleTypes")?,
+            }),
+            "ENUM" => TypeKind::Enum(EnumType {
+                values: self
+                    .field("enumValues")?
+                    .list()?
+                    .iter()
+                    .map(|value| {
+                        Ok(positioned(EnumValueDefinition {
+                            description: value.description()?,
+                            value: positioned(value.field("name")?.name()?),
+                            directives: value.deprecation()?,
+                        }))
+                    })
+                    .collect::<Result<_, _>>()?,
+            }),
+            "INPUT_OBJECT" => {
+                let is_one_of = match self.optional_field("isOneOf") {
+                    Some(is_one_of) => is_one_of.boolean()?,
+                    None => false,
+                };
+                if is_one_of {
+                    directives.push(directive("oneOf", Vec::new()));
+                }
+                TypeKind::InputObject(InputObjectType {
+                    fields: self.input_values("inputFields")?,
+                })
+            }
+            kind => {
+                return Err(self
+                    .field("kind")?
+                    .error(format!("unknown type kind {}", kind)))
+            }
+        };
+
+        Ok(TypeDefinition {
+            extend: false,
+            description: self.description()?,
+            name: positioned(name),
+            directives,
+            kind,
+        })
+    }
+
+    fn directive_definition(&self, name: Name) -> Result<DirectiveDefinition, IntrospectionError> {
+        let locations = self
+            .field("locations")?
+            .list()?
+            .iter()
+            .map(|location| {
+                let name = location.string()?;
+                name.parse()
+                    .map(positioned)
+                    .map_err(|err: DirectiveLocationParseError| location.error(err.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        let is_repeatable = match self.optional_field("isRepeatable") {
+            Some(is_repeatable) => is_repeatable.boolean()?,
+            None => false,
+        };
+
+        Ok(DirectiveDefinition {
+            description: self.description()?,
+            name: positioned(name),
+            arguments: self.input_values("args")?,
+            is_repeatable,
+            locations,
+        })
+    }
+
+    fn fields(&self) -> Result<Vec<Positioned<FieldDefinition>>, IntrospectionError> {
+        self.field("fields")?
+            .list()?
+            .iter()
+            .map(|field| {
+                Ok(positioned(FieldDefinition {
+                    description: field.description()?,
+                    name: positioned(field.field("name")?.name()?),
+                    arguments: field.input_values("args")?,
+                    ty: positioned(field.field("type")?.type_ref()?),
+                    directives: field.deprecation()?,
+                }))
+            })
+            .collect()
+    }
+
+    fn input_values(
+        &self,
+        field: &str,
+    ) -> Result<Vec<Positioned<InputValueDefinition>>, IntrospectionError> {
+        self.field(field)?
+            .list()?
+            .iter()
+            .map(|value| {
+                let default_value = value
+                    .optional_field("defaultValue")
+                    .map(|default_value| {
+                        graphql_toolkit_parser::parse_value(default_value.string()?)
+                            .map(positioned)
+                            .map_err(|err| {
+                                default_value.error(format!("invalid default value: {}", err))
+                            })
+                    })
+                    .transpose()?;
+                Ok(positioned(InputValueDefinition {
+                    description: value.description()?,
+                    name: positioned(value.field("name")?.name()?),
+                    ty: positioned(value.field("type")?.type_ref()?),
+                    default_value,
+                    directives: value.deprecation()?,
+                }))
+            })
+            .collect()
+    }
+
+    fn type_names(&self, field: &str) -> Result<Vec<Positioned<Name>>, IntrospectionError> {
+        let Some(types) = self.optional_field(field) else {
+            return Ok(Vec::new());
+        };
+        types
+            .list()?
+            .iter()
+            .map(|ty| ty.field("name")?.name().map(positioned))
+            .collect()
+    }
+
+    fn type_ref(&self) -> Result<Type, IntrospectionError> {
+        match self.field("kind")?.string()? {
+            "NON_NULL" => {
+                let ty = self.field("ofType")?.type_ref()?;
+                if !ty.nullable {
+                    return Err(self.error("non-null type of a non-null type"));
+                }
+                Ok(Type {
+                    base: ty.base,
+                    nullable: false,
+                })
+            }
+            "LIST" => Ok(Type {
+                base: BaseType::List(Box::new(self.field("ofType")?.type_ref()?)),
+                nullable: true,
+            }),
+            _ => Ok(Type {
+                base: BaseType::Named(self.field("name")?.name()?),
+                nullable: true,
+            }),
+        }
+    }
+}
+
+fn directive(
+    name: &str,
+    arguments: Vec<(Positioned<Name>, Positioned<ConstValue>)>,
+) -> Positioned<ConstDirective> {
+    positioned(ConstDirective {
+        name: positioned(Name::new(name)),
+        arguments,
+    })
+}
+
+/// Position a node built from an introspection result, which has no source positions.
+fn positioned<T>(node: T) -> Positioned<T> {
+    Positioned::new(node, Pos::default())
+}
@@ -0,0 +1,434 @@
+//! Introspection result generation.
+//!
+//! [Reference](https://spec.graphql.org/October2021/#sec-Introspection).
+
+use std::sync::OnceLock;
+
+use graphql_toolkit_ast::{
+    indexmap::IndexMap, BaseType, ConstDirective, ConstValue, DirectiveDefinition,
+    EnumValueDefinition, FieldDefinition, InputValueDefinition, Name, Positioned, Type,
+    TypeDefinition, TypeKind, TypeSystemDefinition,
+};
+
+pub use self::{
+    document::{introspection_to_document, IntrospectionError},
+    query::{introspection_query, introspection_query_source, IntrospectionOptions},
+};
+use crate::schema::Schema;
+
+mod document;
+mod query;
+
+/// The SDL of the introspection types.
+///
+/// [Reference](https://spec.graphql.org/October2021/#sec-Schema-Introspection.Schema-Introspection-Schema).
+const INTROSPECTION_SDL: &str = r#"
+type __Schema {
+  description: String
+  types: [__Type!]!
+  queryType: __Type!
+  mutationType: __Type
+  subscriptionType: __Type
+  directives: [__Directive!]!
+}
+
+type __Type {
+  kind: __TypeKind!
+  name: String
+  description: String
+  specifiedByURL: String
+  fields(includeDeprecated: Boolean = false): [__Field!]
+  interfaces: [__Type!]
+  possibleTypes: [__Type!]
+  enumValues(includeDeprecated: Boolean = false): [__EnumValue!]
+  inputFields(includeDeprecated: Boolean = false): [__InputValue!]
+  ofType: __Type
+  isOneOf: Boolean
+}
+
+enum __TypeKind {
+  SCALAR
+  OBJECT
+  INTERFACE
+  UNION
+  ENUM
+  INPUT_OBJECT
+  LIST
+  NON_NULL
+}
+
+type __Field {
+  name: String!
+  description: String
+  args(includeDeprecated: Boolean = false): [__InputValue!]!
+  type: __Type!
+  isDeprecated: Boolean!
+  deprecationReason: String
+}
+
+type __InputValue {
+  name: String!
+  description: String
+  type: __Type!
+  defaultValue: String
+  isDeprecated: Boolean!
+  deprecationReason: String
+}
+
+type __EnumValue {
+  name: String!
+  description: String
+  isDeprecated: Boolean!
+  deprecationReason: String
+}
+
+type __Directive {
+  name: String!
+  description: String
+  locations: [__DirectiveLocation!]!
+  args(includeDeprecated: Boolean = false): [__InputValue!]!
+  isRepeatable: Boolean!
+}
+
+enum __DirectiveLocation {
+  QUERY
+  MUTATION
+  SUBSCRIPTION
+  FIELD
+  FRAGMENT_DEFINITION
+  FRAGMENT_SPREAD
+  INLINE_FRAGMENT
+  VARIABLE_DEFINITION
+  SCHEMA
+  SCALAR
+  OBJECT
+  FIELD_DEFINITION
+  ARGUMENT_DEFINITION
+  INTERFACE
+  UNION
+  ENUM
+  ENUM_VALUE
+  INPUT_OBJECT
+  INPUT_FIELD_DEFINITION
+}
+"#;
+
+/// Get the introspection types, parsed once from their SDL.
+fn introspection_types() -> &'static IndexMap<Name, TypeDefinition> {
+    static TYPES: OnceLock<IndexMap<Name, TypeDefinition>> = OnceLock::new();
+    TYPES.get_or_init(|| {
+        graphql_toolkit_parser::parse_schema(INTROSPECTION_SDL)
+            .expect("introspection types are valid SDL")
+            .definitions
+            .into_iter()
+            .filter_map(|def| match def {
+                TypeSystemDefinition::Type(ty) => Some((ty.node.name.node.clone(), ty.node)),
+                _ => None,
+            })
+            .collect()
+    })
+}
+
+/// The default reason of the `@deprecated` directive.
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+impl Schema {
+    /// Generate the result of the full introspection query, as the `data` of the response.
+    ///
+    /// The result has the shape of the response to the standard introspection query with all the
+    /// capabilities enabled, including the deprecated fields, arguments, input fields and enum
+    /// values. The types of the schema are followed by the introspection types.
+    ///
+    /// As the schema is not validated, the fields, arguments, input fields, interfaces and
+    /// possible types referring to undefined types are skipped.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#sec-Introspection).
+    #[must_use]
+    pub fn introspect(&self) -> ConstValue {
+        let introspector = Introspector {
+            schema: self,
+            introspection_types: introspection_types(),
+        };
+        object([("__schema", introspector.schema())])
+    }
+}
+
+struct Introspector<'a> {
+    schema: &'a Schema,
+    introspection_types: &'static IndexMap<Name, TypeDefinition>,
+}
+
+impl<'a> Introspector<'a> {
+    fn get_type(&self, name: &str) -> Option<&TypeDefinition> {
+        self.schema
+            .get_type(name)
+            .map(|ty| &ty.node)
+            .or_else(|| self.introspection_types.get(name))
+    }
+
+    fn schema(&self) -> ConstValue {
+        let root_type = |ty: Option<&Positioned<TypeDefinition>>| {
+            ty.map_or(ConstValue::Null, |ty| {
+                object([("name", string(&ty.node.name.node))])
+            })
+        };
+
+        let types = self
+            .schema
+            .types()
+            .map(|ty| &ty.node)
+            .chain(self.introspection_types.values())
+            .map(|ty| self.full_type(ty))
+            .collect();
+        let directives = self
+            .schema
+            .directives()
+            .map(|directive| self.directive(&directive.node))
+            .collect();
+
+        object([
+            ("description", ConstValue::Null),
+            ("queryType", root_type(self.schema.query_type())),
+            ("mutationType", root_type(self.schema.mutation_type())),
+            (
+                "subscriptionType",
+                root_type(self.schema.subscription_type()),
+            ),
+            ("types", ConstValue::List(types)),
+            ("directives", ConstValue::List(directives)),
+        ])
+    }
+
+    fn full_type(&self, ty: &TypeDefinition) -> ConstValue {
+        let name = &ty.name.node;
+        let type_refs = |names: &mut dyn Iterator<Item = &Name>| {
+            ConstValue::List(names.filter_map(|name| self.named_type_ref(name)).collect())
+        };
+
+        let (fields, interfaces, possible_types, enum_values, input_fields) = match &ty.kind {
+            TypeKind::Scalar => Default::default(),
+            TypeKind::Object(object) => (
+                Some(self.fields(&object.fields)),
+                Some(type_refs(&mut object.implements.iter().map(|i| &i.node))),
+                None,
+                None,
+                None,
+            ),
+            TypeKind::Interface(interface) => (
+                Some(self.fields(&interface.fields)),
+                Some(type_refs(&mut interface.implements.iter().map(|i| &i.node))),
+                Some(type_refs(&mut self.schema.possible_types(name))),
+                None,
+                None,
+            ),
+            TypeKind::Union(union) => (
+                None,
+                None,
+                Some(type_refs(&mut union.members.iter().map(|m| &m.node))),
+                None,
+                None,
+            ),
+            TypeKind::Enum(enum_type) => (
+                None,
+                None,
+                None,
+                Some(ConstValue::List(
+                    enum_type
+                        .values
+                        .iter()
+                        .map(|value| enum_value(&value.node))
+                        .collect(),
+                )),
+                None,
+            ),
+            TypeKind::InputObject(input) => (
+                None,
+                None,
+                None,
+                None,
+                Some(self.input_values(&input.fields)),
+            ),
+        };
+
+        let specified_by_url = find_directive(&ty.directives, "specifiedBy")
+            .and_then(|directive| directive.get_argument("url"))
+            .map(|url| url.node.clone());
+        let is_one_of = match ty.kind {
+            TypeKind::InputObject(_) => Some(ConstValue::Boolean(
+                find_directive(&ty.directives, "oneOf").is_some(),
+            )),
+            _ => None,
+        };
+
+        object([
+            ("kind", ConstValue::Enum(Name::new(type_kind(&ty.kind)))),
+            ("name", string(name)),
+            ("description", description(&ty.description)),
+            ("specifiedByURL", specified_by_url.unwrap_or_default()),
+            ("isOneOf", is_one_of.unwrap_or_default()),
+            ("fields", fields.unwrap_or_default()),
+            ("inputFields", input_fields.unwrap_or_default()),
+            ("interfaces", interfaces.unwrap_or_default()),
+            ("enumValues", enum_values.unwrap_or_default()),
+            ("possibleTypes", possible_types.unwrap_or_default()),
+        ])
+    }
+
+    fn fields(&self, fields: &[Positioned<FieldDefinition>]) -> ConstValue {
+        ConstValue::List(
+            fields
+                .iter()
+                .filter_map(|field| {
+                    let field = &field.node;
+                    let ty = self.type_ref(&field.ty.node)?;
+                    let (is_deprecated, deprecation_reason) = deprecation(&field.directives);
+                    Some(object([
+                        ("name", string(&field.name.node)),
+                        ("description", description(&field.description)),
+                        ("args", self.input_values(&field.arguments)),
+                        ("type", ty),
+                        ("isDeprecated", is_deprecated),
+                        ("deprecationReason", deprecation_reason),
+                    ]))
+                })
+                .collect(),
+        )
+    }
+
+    fn input_values(&self, values: &[Positioned<InputValueDefinition>]) -> ConstValue {
+        ConstValue::List(
+            values
+                .iter()
+                .filter_map(|value| {
+                    let value = &value.node;
+                    let ty = self.type_ref(&value.ty.node)?;
+                    let (is_deprecated, deprecation_reason) = deprecation(&value.directives);
+                    let default_value = value
+                        .default_value
+                        .as_ref()
+                        .map_or(ConstValue::Null, |value| {
+                            ConstValue::String(value.node.to_string())
+                        });
+                    Some(object([
+                        ("name", string(&value.name.node)),
+                        ("description", description(&value.description)),
+                        ("type", ty),
+                        ("defaultValue", default_value),
+                        ("isDeprecated", is_deprecated),
+                        ("deprecationReason", deprecation_reason),
+                    ]))
+                })
+                .collect(),
+        )
+    }
+
+    fn directive(&self, directive: &DirectiveDefinition) -> ConstValue {
+        let locations = directive
+            .locations
+            .iter()
+            .map(|location| ConstValue::Enum(Name::new(location.node.as_str())))
+            .collect();
+
+        object([
+            ("name", string(&directive.name.node)),
+            ("description", description(&directive.description)),
+            ("isRepeatable", ConstValue::Boolean(directive.is_repeatable)),
+            ("locations", ConstValue::List(locations)),
+            ("args", self.input_values(&directive.arguments)),
+        ])
+    }
+
+    /// Get the reference to the type, or `None` if it refers to an undefined type.
+    fn type_ref(&self, ty: &Type) -> Option<ConstValue> {
+        if !ty.nullable {
+            return Some(object([
+                ("kind", ConstValue::Enum(Name::new("NON_NULL"))),
+                ("name", ConstValue::Null),
+                ("ofType", self.type_ref(&ty.to_nullable())?),
+            ]));
+        }
+
+        match &ty.base {
+            BaseType::Named(name) => self.named_type_ref(name),
+            BaseType::List(item_type) => Some(object([
+                ("kind", ConstValue::Enum(Name::new("LIST"))),
+                ("name", ConstValue::Null),
+                ("ofType", self.type_ref(item_type)?),
+            ])),
+        }
+    }
+
+    /// Get the reference to the named type, or `None` if the type is undefined.
+    fn named_type_ref(&self, name: &Name) -> Option<ConstValue> {
+        let ty = self.get_type(name)?;
+        Some(object([
+            ("kind", ConstValue::Enum(Name::new(type_kind(&ty.kind)))),
+            ("name", string(name)),
+            ("ofType", ConstValue::Null),
+        ]))
+    }
+}
+
+fn enum_value(value: &EnumValueDefinition) -> ConstValue {
+    let (is_deprecated, deprecation_reason) = deprecation(&value.directives);
+    object([
+        ("name", string(&value.value.node)),
+        ("description", description(&value.description)),
+        ("isDeprecated", is_deprecated),
+        ("deprecationReason", deprecation_reason),
+    ])
+}
+
+fn type_kind(kind: &TypeKind) -> &'static str {
+    match kind {
+        TypeKind::Scalar => "SCALAR",
+        TypeKind::Object(_) => "OBJECT",
+        TypeKind::Interface(_) => "INTERFACE",
+        TypeKind::Union(_) => "UNION",
+        TypeKind::Enum(_) => "ENUM",
+        TypeKind::InputObject(_) => "INPUT_OBJECT",
+    }
+}
+
+fn find_directive<'a>(
+    directives: &'a [Positioned<ConstDirective>],
+    name: &str,
+) -> Option<&'a ConstDirective> {
+    directives
+        .iter()
+        .map(|directive| &directive.node)
+        .find(|directive| directive.name.node == name)
+}
+
+/// Get the `isDeprecated` and `deprecationReason` values of a schema element.
+fn deprecation(directives: &[Positioned<ConstDirective>]) -> (ConstValue, ConstValue) {
+    match find_directive(directives, "deprecated") {
+        Some(directive) => {
+            let reason = directive.get_argument("reason").map_or_else(
+                || ConstValue::String(DEFAULT_DEPRECATION_REASON.to_owned()),
+                |reason| reason.node.clone(),
+            );
+            (ConstValue::Boolean(true), reason)
+        }
+        None => (ConstValue::Boolean(false), ConstValue::Null),
+    }
+}
+
+fn description(description: &Option<Positioned<String>>) -> ConstValue {
+    description
+        .as_ref()
+        .map_or(ConstValue::Null, |description| string(&description.node))
+}
+
+fn string(value: &str) -> ConstValue {
+    ConstValue::String(value.to_owned())
+}
+
+fn object<const N: usize>(fields: [(&str, ConstValue); N]) -> ConstValue {
+    ConstValue::Object(
+        fields
+            .into_iter()
+            .map(|(name, value)| (Name::new(name), value))
+            .collect(),
+    )
+}
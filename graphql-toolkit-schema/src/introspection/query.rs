@@ -0,0 +1,174 @@
+//! The standard introspection query.
+
+use graphql_toolkit_ast::ExecutableDocument;
+
+/// The capabilities of the server to request in the introspection query.
+///
+/// The defaults match the capabilities of the October 2021 specification, without the later
+/// additions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntrospectionOptions {
+    /// Whether to include the descriptions. Defaults to `true`.
+    pub descriptions: bool,
+    /// Whether to include the `specifiedByURL` field of the scalars. Defaults to `false`.
+    pub specified_by_url: bool,
+    /// Whether to include the `isRepeatable` field of the directives. Defaults to `false`.
+    pub directive_is_repeatable: bool,
+    /// Whether to include the description of the schema. Defaults to `false`.
+    pub schema_description: bool,
+    /// Whether to include the deprecated arguments and input fields. Defaults to `false`.
+    pub input_value_deprecation: bool,
+    /// Whether to include the `isOneOf` field of the input objects. Defaults to `false`.
+    pub one_of: bool,
+    /// How many levels of wrapping types to request in the type references. Defaults to `9`, and
+    /// capped to [`IntrospectionOptions::MAX_TYPE_DEPTH`].
+    pub type_depth: usize,
+}
+
+impl Default for IntrospectionOptions {
+    fn default() -> Self {
+        Self {
+            descriptions: true,
+            specified_by_url: false,
+            directive_is_repeatable: false,
+            schema_description: false,
+            input_value_deprecation: false,
+            one_of: false,
+            type_depth: 9,
+        }
+    }
+}
+
+impl IntrospectionOptions {
+    /// The maximum number of levels of wrapping types in the type references.
+    ///
+    /// The type references nest a selection set per level, so deeper queries would exceed the
+    /// recursion limit of the parser.
+    pub const MAX_TYPE_DEPTH: usize = 32;
+
+    /// Get the options requesting all the capabilities, which the results of
+    /// [`Schema::introspect`](crate::Schema::introspect) answer.
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            descriptions: true,
+            specified_by_url: true,
+            directive_is_repeatable: true,
+            schema_description: true,
+            input_value_deprecation: true,
+            one_of: true,
+            type_depth: 9,
+        }
+    }
+}
+
+/// Get the standard introspection query, named `IntrospectionQuery`, requesting the given
+/// capabilities.
+///
+/// A [`type_depth`](IntrospectionOptions::type_depth) above
+/// [`IntrospectionOptions::MAX_TYPE_DEPTH`] is silently clamped to it, so the type references
+/// deeper than the limit are not requested.
+///
+/// [Reference](https://spec.graphql.org/October2021/#sec-Introspection).
+#[must_use]
+pub fn introspection_query(options: IntrospectionOptions) -> ExecutableDocument {
+    graphql_toolkit_parser::parse_query(introspection_query_source(options))
+        .expect("introspection query is a valid document")
+}
+
+/// Get the source of the standard introspection query, requesting the given capabilities.
+///
+/// The type depth is clamped like in [`introspection_query`].
+#[must_use]
+pub fn introspection_query_source(options: IntrospectionOptions) -> String {
+    let flag = |enabled: bool, selection: &'static str| if enabled { selection } else { "" };
+    let descriptions = flag(options.descriptions, "description");
+    let include_deprecated = flag(options.input_value_deprecation, "(includeDeprecated: true)");
+
+    let type_depth = options.type_depth.min(IntrospectionOptions::MAX_TYPE_DEPTH);
+    let mut type_ref = String::from("kind name");
+    for _ in 0..type_depth {
+        type_ref.push_str(" ofType { kind name");
+    }
+    for _ in 0..type_depth {
+        type_ref.push_str(" }");
+    }
+
+    let source = format!(
+        r"query IntrospectionQuery {{
+  __schema {{
+    {schema_description}
+    queryType {{ name }}
+    mutationType {{ name }}
+    subscriptionType {{ name }}
+    types {{ ...FullType }}
+    directives {{
+      name
+      {descriptions}
+      {is_repeatable}
+      locations
+      args{include_deprecated} {{ ...InputValue }}
+    }}
+  }}
+}}
+
+fragment FullType on __Type {{
+  kind
+  name
+  {descriptions}
+  {specified_by_url}
+  {one_of}
+  fields(includeDeprecated: true) {{
+    name
+    {descriptions}
+    args{include_deprecated} {{ ...InputValue }}
+    type {{ ...TypeRef }}
+    isDeprecated
+    deprecationReason
+  }}
+  inputFields{include_deprecated} {{ ...InputValue }}
+  interfaces {{ ...TypeRef }}
+  enumValues(includeDeprecated: true) {{
+    name
+    {descriptions}
+    isDeprecated
+    deprecationReason
+  }}
+  possibleTypes {{ ...TypeRef }}
+}}
+
+fragment InputValue on __InputValue {{
+  name
+  {descriptions}
+  type {{ ...TypeRef }}
+  defaultValue
+  {input_value_deprecation}
+}}
+
+fragment TypeRef on __Type {{
+  {type_ref}
+}}
+",
+        schema_description = flag(
+            options.schema_description && options.descriptions,
+            "description"
+        ),
+        is_repeatable = flag(options.directive_is_repeatable, "isRepeatable"),
+        specified_by_url = flag(options.specified_by_url, "specifiedByURL"),
+        one_of = flag(options.one_of, "isOneOf"),
+        input_value_deprecation = flag(
+            options.input_value_deprecation,
+            "isDeprecated deprecationReason"
+        ),
+    );
+
+    // Drop the lines of the capabilities that are not requested.
+    source
+        .lines()
+        .filter(|line| line.is_empty() || !line.trim().is_empty())
+        .fold(String::new(), |mut source, line| {
+            source.push_str(line);
+            source.push('\n');
+            source
+        })
+}
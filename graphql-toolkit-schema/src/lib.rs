@@ -0,0 +1,29 @@
+//! A GraphQL schema model
+#![warn(missing_docs)]
+#![allow(clippy::uninlined_format_args)]
+#![forbid(unsafe_code)]
+
+pub use builtins::{builtins, BUILTIN_DIRECTIVES, BUILTIN_SCALARS};
+pub use coerce::{CoercionError, PathSegment};
+pub use contract::{Contract, ContractIssue, ContractOutput, DirectiveFilter};
+pub use coordinate::{CoordinateIndex, CoordinateParseError, SchemaCoordinate, SchemaElement};
+pub use diff::{diff_schemas, Change, ChangeCode, Criticality};
+pub use error::Error;
+pub use extend::apply_extensions;
+pub use introspection::{
+    introspection_query, introspection_query_source, introspection_to_document, IntrospectionError,
+    IntrospectionOptions,
+};
+pub use normalize::{normalize_document, NormalizeOptions};
+pub use schema::{Schema, SchemaBuilder};
+
+mod builtins;
+mod coerce;
+mod contract;
+mod coordinate;
+mod diff;
+mod error;
+mod extend;
+mod introspection;
+mod normalize;
+mod schema;
@@ -0,0 +1,104 @@
+//! Service document normalization.
+
+use graphql_toolkit_ast::{
+    FieldDefinition, InputValueDefinition, Name, Positioned, ServiceDocument, TypeKind,
+    TypeSystemDefinition,
+};
+
+use crate::{error::Error, extend::apply_extensions};
+
+/// The options of the service document normalization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Whether to keep the fields and input fields in definition order, instead of sorting them.
+    /// Defaults to `false`.
+    pub keep_field_order: bool,
+}
+
+/// Normalize a service document, so that equivalent documents print the same SDL.
+///
+/// The extensions are folded into their base definitions. Then, the schema definition is put
+/// first, followed by the types and the directive definitions, sorted by name. The fields,
+/// arguments, enum values, union members and implemented interfaces are sorted by name too.
+/// The applied directives keep their order, as it can be meaningful for repeatable directives.
+///
+/// The documents of a schema split across multiple files can be normalized together by
+/// concatenating their definitions. Unless the field order is kept, the result does not depend on
+/// the order of the files.
+///
+/// # Errors
+///
+/// Fails with the errors of [`apply_extensions`] if the extensions cannot be applied.
+pub fn normalize_document(
+    document: ServiceDocument,
+    options: NormalizeOptions,
+) -> Result<ServiceDocument, Vec<Error>> {
+    let mut schema = Vec::new();
+    let mut types = Vec::new();
+    let mut directives = Vec::new();
+
+    for def in apply_extensions(document)?.definitions {
+        match def {
+            TypeSystemDefinition::Schema(def) => schema.push(TypeSystemDefinition::Schema(def)),
+            TypeSystemDefinition::Type(mut def) => {
+                match &mut def.node.kind {
+                    TypeKind::Object(object) => {
+                        sort_names(&mut object.implements);
+                        sort_fields(&mut object.fields, options);
+                    }
+                    TypeKind::Interface(interface) => {
+                        sort_names(&mut interface.implements);
+                        sort_fields(&mut interface.fields, options);
+                    }
+                    TypeKind::Union(union) => sort_names(&mut union.members),
+                    TypeKind::Enum(enum_type) => enum_type
+                        .values
+                        .sort_by(|a, b| a.node.value.node.cmp(&b.node.value.node)),
+                    TypeKind::InputObject(input) => {
+                        if !options.keep_field_order {
+                            sort_input_values(&mut input.fields);
+                        }
+                    }
+                    TypeKind::Scalar => {}
+                }
+                types.push(def);
+            }
+            TypeSystemDefinition::Directive(mut def) => {
+                sort_input_values(&mut def.node.arguments);
+                directives.push(def);
+            }
+        }
+    }
+
+    // The sort is stable, so duplicated definitions keep their relative order.
+    types.sort_by(|a, b| a.node.name.node.cmp(&b.node.name.node));
+    directives.sort_by(|a, b| a.node.name.node.cmp(&b.node.name.node));
+
+    Ok(ServiceDocument {
+        definitions: schema
+            .into_iter()
+            .chain(types.into_iter().map(TypeSystemDefinition::Type))
+            .chain(directives.into_iter().map(TypeSystemDefinition::Directive))
+            .collect(),
+    })
+}
+
+/// Sort the union members or implemented interfaces by name.
+fn sort_names(names: &mut [Positioned<Name>]) {
+    names.sort_by(|a, b| a.node.cmp(&b.node));
+}
+
+/// Sort the fields, unless their order is kept, and their arguments by name.
+fn sort_fields(fields: &mut [Positioned<FieldDefinition>], options: NormalizeOptions) {
+    if !options.keep_field_order {
+        fields.sort_by(|a, b| a.node.name.node.cmp(&b.node.name.node));
+    }
+    for field in fields {
+        sort_input_values(&mut field.node.arguments);
+    }
+}
+
+/// Sort the arguments or input fields by name.
+fn sort_input_values(values: &mut [Positioned<InputValueDefinition>]) {
+    values.sort_by(|a, b| a.node.name.node.cmp(&b.node.name.node));
+}
@@ -0,0 +1,587 @@
+use std::collections::{HashMap, HashSet};
+
+use graphql_toolkit_ast::{
+    indexmap::{IndexMap, IndexSet},
+    BaseType, ConstDirective, DirectiveDefinition, FieldDefinition, InputValueDefinition, Name,
+    OperationType, Positioned, SchemaDefinition, ServiceDocument, Type, TypeDefinition, TypeKind,
+    TypeSystemDefinition,
+};
+
+use crate::{
+    builtins::builtins,
+    error::Error,
+    extend::{extend_schema, extend_type, implicit_schema},
+};
+
+/// A GraphQL schema, built from one or more service documents.
+///
+/// The schema indexes the type-system definitions by name, and resolves the root operation types
+/// and the possible types of the abstract types. Type-system extensions are folded into the
+/// definitions they extend. The built-in scalars and directives are registered by default, unless
+/// a service document defines a type or directive with the same name.
+///
+/// [Reference](https://spec.graphql.org/October2021/#sec-Schema).
+#[derive(Debug, Clone)]
+pub struct Schema {
+    definition: Option<Positioned<SchemaDefinition>>,
+    query_type: Option<Name>,
+    mutation_type: Option<Name>,
+    subscription_type: Option<Name>,
+    types: IndexMap<Name, Positioned<TypeDefinition>>,
+    directives: IndexMap<Name, Positioned<DirectiveDefinition>>,
+    implementations: HashMap<Name, IndexSet<Name>>,
+    possible_types: HashMap<Name, IndexSet<Name>>,
+    builtin_types: HashSet<Name>,
+    builtin_directives: HashSet<Name>,
+}
+
+impl Schema {
+    /// Create a new schema builder.
+    #[must_use]
+    pub fn builder() -> SchemaBuilder {
+        SchemaBuilder::new()
+    }
+
+    /// Build a schema from a single service document.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the document contains duplicated definitions, invalid extensions, or invalid root
+    /// operation types.
+    pub fn from_document(document: ServiceDocument) -> Result<Self, Vec<Error>> {
+        Self::builder().add_document(document).build()
+    }
+
+    /// Get the schema definition, if the schema was explicitly defined or extended.
+    ///
+    /// The extensions of a schema without a definition apply to its implicit definition, whose
+    /// root operation types are the object types with the default root names.
+    #[must_use]
+    pub fn definition(&self) -> Option<&Positioned<SchemaDefinition>> {
+        self.definition.as_ref()
+    }
+
+    /// Get the directives applied to the schema definition.
+    #[must_use]
+    pub fn schema_directives(&self) -> &[Positioned<ConstDirective>] {
+        self.definition
+            .as_ref()
+            .map_or(&[], |definition| &definition.node.directives)
+    }
+
+    /// Get the name of the root type for the given operation type, if any.
+    #[must_use]
+    pub fn root_type_name(&self, operation: OperationType) -> Option<&Name> {
+        match operation {
+            OperationType::Query => self.query_type.as_ref(),
+            OperationType::Mutation => self.mutation_type.as_ref(),
+            OperationType::Subscription => self.subscription_type.as_ref(),
+        }
+    }
+
+    /// Get the root type for the given operation type, if any.
+    #[must_use]
+    pub fn root_type(&self, operation: OperationType) -> Option<&Positioned<TypeDefinition>> {
+        self.root_type_name(operation)
+            .and_then(|name| self.types.get(name))
+    }
+
+    /// Get the query root type, if any.
+    #[must_use]
+    pub fn query_type(&self) -> Option<&Positioned<TypeDefinition>> {
+        self.root_type(OperationType::Query)
+    }
+
+    /// Get the mutation root type, if any.
+    #[must_use]
+    pub fn mutation_type(&self) -> Option<&Positioned<TypeDefinition>> {
+        self.root_type(OperationType::Mutation)
+    }
+
+    /// Get the subscription root type, if any.
+    #[must_use]
+    pub fn subscription_type(&self) -> Option<&Positioned<TypeDefinition>> {
+        self.root_type(OperationType::Subscription)
+    }
+
+    /// Get an iterator over the types of the schema, in definition order.
+    pub fn types(&self) -> impl Iterator<Item = &Positioned<TypeDefinition>> {
+        self.types.values()
+    }
+
+    /// Get the type with the given name.
+    #[must_use]
+    pub fn get_type(&self, name: &str) -> Option<&Positioned<TypeDefinition>> {
+        self.types.get(name)
+    }
+
+    /// Whether the type with the given name is an input type: a scalar, an enum or an input
+    /// object.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#IsInputType()).
+    #[must_use]
+    pub fn is_input_type(&self, name: &str) -> bool {
+        matches!(
+            self.types.get(name).map(|ty| &ty.node.kind),
+            Some(TypeKind::Scalar | TypeKind::Enum(_) | TypeKind::InputObject(_))
+        )
+    }
+
+    /// Whether the type with the given name is an output type: a scalar, an object, an
+    /// interface, a union or an enum.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#IsOutputType()).
+    #[must_use]
+    pub fn is_output_type(&self, name: &str) -> bool {
+        matches!(
+            self.types.get(name).map(|ty| &ty.node.kind),
+            Some(
+                TypeKind::Scalar
+                    | TypeKind::Object(_)
+                    | TypeKind::Interface(_)
+                    | TypeKind::Union(_)
+                    | TypeKind::Enum(_)
+            )
+        )
+    }
+
+    /// Whether the type with the given name is a composite type: an object, an interface or a
+    /// union.
+    #[must_use]
+    pub fn is_composite_type(&self, name: &str) -> bool {
+        matches!(
+            self.types.get(name).map(|ty| &ty.node.kind),
+            Some(TypeKind::Object(_) | TypeKind::Interface(_) | TypeKind::Union(_))
+        )
+    }
+
+    /// Whether the type with the given name is an abstract type: an interface or a union.
+    #[must_use]
+    pub fn is_abstract_type(&self, name: &str) -> bool {
+        matches!(
+            self.types.get(name).map(|ty| &ty.node.kind),
+            Some(TypeKind::Interface(_) | TypeKind::Union(_))
+        )
+    }
+
+    /// Whether the type with the given name is a leaf type: a scalar or an enum.
+    #[must_use]
+    pub fn is_leaf_type(&self, name: &str) -> bool {
+        matches!(
+            self.types.get(name).map(|ty| &ty.node.kind),
+            Some(TypeKind::Scalar | TypeKind::Enum(_))
+        )
+    }
+
+    /// Whether the output type `ty` is a valid subtype of `super_type`, that is, whether a field
+    /// of type `ty` can implement an interface field of type `super_type`.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#IsValidImplementationFieldType()).
+    #[must_use]
+    pub fn is_subtype(&self, ty: &Type, super_type: &Type) -> bool {
+        if !ty.nullable {
+            let ty = Type {
+                base: ty.base.clone(),
+                nullable: true,
+            };
+            let super_type = Type {
+                base: super_type.base.clone(),
+                nullable: true,
+            };
+            return self.is_subtype(&ty, &super_type);
+        }
+        if !super_type.nullable {
+            return false;
+        }
+
+        match (&ty.base, &super_type.base) {
+            (BaseType::List(ty), BaseType::List(super_type)) => self.is_subtype(ty, super_type),
+            (BaseType::Named(name), BaseType::Named(super_name)) => {
+                name == super_name
+                    || self.is_possible_type(super_name, name)
+                    || self
+                        .implementations
+                        .get(super_name)
+                        .map_or(false, |types| types.contains(name))
+            }
+            _ => false,
+        }
+    }
+
+    /// Get an iterator over the directive definitions of the schema, in definition order.
+    pub fn directives(&self) -> impl Iterator<Item = &Positioned<DirectiveDefinition>> {
+        self.directives.values()
+    }
+
+    /// Get the directive definition with the given name.
+    #[must_use]
+    pub fn get_directive(&self, name: &str) -> Option<&Positioned<DirectiveDefinition>> {
+        self.directives.get(name)
+    }
+
+    /// Get the fields of the object or interface type with the given name.
+    ///
+    /// Returns an empty slice if the type is not defined, or has no fields.
+    #[must_use]
+    pub fn fields(&self, type_name: &str) -> &[Positioned<FieldDefinition>] {
+        match self.types.get(type_name).map(|ty| &ty.node.kind) {
+            Some(TypeKind::Object(object)) => &object.fields,
+            Some(TypeKind::Interface(interface)) => &interface.fields,
+            _ => &[],
+        }
+    }
+
+    /// Get the field with the given name of an object or interface type.
+    #[must_use]
+    pub fn get_field(
+        &self,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<&Positioned<FieldDefinition>> {
+        self.fields(type_name)
+            .iter()
+            .find(|field| field.node.name.node == field_name)
+    }
+
+    /// Get the field with the given name of an input object type.
+    #[must_use]
+    pub fn get_input_field(
+        &self,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<&Positioned<InputValueDefinition>> {
+        match self.types.get(type_name).map(|ty| &ty.node.kind) {
+            Some(TypeKind::InputObject(input)) => input
+                .fields
+                .iter()
+                .find(|field| field.node.name.node == field_name),
+            _ => None,
+        }
+    }
+
+    /// Get an iterator over the possible types of a type.
+    ///
+    /// For a union, these are its members; for an interface, the object types implementing it;
+    /// and for an object type, the object type itself.
+    pub fn possible_types(&self, type_name: &str) -> impl Iterator<Item = &Name> {
+        self.possible_types.get(type_name).into_iter().flatten()
+    }
+
+    /// Whether the object type `possible_type` is a possible type of `type_name`.
+    #[must_use]
+    pub fn is_possible_type(&self, type_name: &str, possible_type: &str) -> bool {
+        self.possible_types
+            .get(type_name)
+            .map_or(false, |types| types.contains(possible_type))
+    }
+
+    /// Get an iterator over the object and interface types implementing the given interface.
+    pub fn implementations(&self, interface: &str) -> impl Iterator<Item = &Name> {
+        self.implementations.get(interface).into_iter().flatten()
+    }
+
+    /// Whether the type with the given name is a built-in definition, not overridden by the
+    /// service documents.
+    #[must_use]
+    pub fn is_builtin_type(&self, name: &str) -> bool {
+        self.builtin_types.contains(name)
+    }
+
+    /// Whether the directive with the given name is a built-in definition, not overridden by the
+    /// service documents.
+    #[must_use]
+    pub fn is_builtin_directive(&self, name: &str) -> bool {
+        self.builtin_directives.contains(name)
+    }
+
+    /// Convert the schema back into a service document, with the extensions folded into the
+    /// definitions.
+    ///
+    /// The built-in definitions are left out, so that printing the document yields the SDL of the
+    /// service. Overridden built-in definitions are kept.
+    #[must_use]
+    pub fn to_document(&self) -> ServiceDocument {
+        self.document(false)
+    }
+
+    /// Convert the schema back into a service document, including the built-in definitions.
+    #[must_use]
+    pub fn to_document_with_builtins(&self) -> ServiceDocument {
+        self.document(true)
+    }
+
+    fn document(&self, include_builtins: bool) -> ServiceDocument {
+        let schema = self
+            .definition
+            .iter()
+            .cloned()
+            .map(TypeSystemDefinition::Schema);
+        let types = self
+            .types
+            .values()
+            .filter(|ty| include_builtins || !self.is_builtin_type(&ty.node.name.node))
+            .cloned()
+            .map(TypeSystemDefinition::Type);
+        let directives = self
+            .directives
+            .values()
+            .filter(|directive| {
+                include_builtins || !self.is_builtin_directive(&directive.node.name.node)
+            })
+            .cloned()
+            .map(TypeSystemDefinition::Directive);
+
+        ServiceDocument {
+            definitions: schema.chain(types).chain(directives).collect(),
+        }
+    }
+}
+
+/// A builder for a [`Schema`].
+///
+/// Constructed from the [`Schema::builder`] function.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaBuilder {
+    documents: Vec<ServiceDocument>,
+    builtins: Option<ServiceDocument>,
+}
+
+impl SchemaBuilder {
+    /// Create a new schema builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add the definitions of a service document to the schema.
+    #[must_use]
+    pub fn add_document(mut self, document: ServiceDocument) -> Self {
+        self.documents.push(document);
+        self
+    }
+
+    /// Replace the built-in definitions registered in the schema, which default to the
+    /// [`builtins`](crate::builtins()) of the specification.
+    ///
+    /// Like the default ones, these definitions can be overridden by the service documents.
+    #[must_use]
+    pub fn builtins(mut self, document: ServiceDocument) -> Self {
+        self.builtins = Some(document);
+        self
+    }
+
+    /// Do not register any built-in definitions in the schema.
+    #[must_use]
+    pub fn without_builtins(self) -> Self {
+        self.builtins(ServiceDocument {
+            definitions: Vec::new(),
+        })
+    }
+
+    /// Build the schema.
+    ///
+    /// # Errors
+    ///
+    /// Fails with all the errors found if the documents contain duplicated definitions, invalid
+    /// extensions, or invalid root operation types.
+    pub fn build(self) -> Result<Schema, Vec<Error>> {
+        let mut errors = Vec::new();
+
+        let mut definition: Option<Positioned<SchemaDefinition>> = None;
+        let mut types = IndexMap::new();
+        let mut directives = IndexMap::new();
+
+        // The built-in definitions can be overridden by the documents, so they are tracked
+        // separately to avoid reporting them as duplicates.
+        let mut builtin_types = HashSet::new();
+        let mut builtin_directives = HashSet::new();
+        let builtins = self.builtins.unwrap_or_else(builtins);
+        for def in builtins.definitions {
+            match def {
+                TypeSystemDefinition::Type(ty) => {
+                    builtin_types.insert(ty.node.name.node.clone());
+                    types.insert(ty.node.name.node.clone(), ty);
+                }
+                TypeSystemDefinition::Directive(directive) => {
+                    builtin_directives.insert(directive.node.name.node.clone());
+                    directives.insert(directive.node.name.node.clone(), directive);
+                }
+                TypeSystemDefinition::Schema(_) => {}
+            }
+        }
+
+        let mut extensions = Vec::new();
+        for def in self.documents.into_iter().flat_map(|doc| doc.definitions) {
+            match def {
+                TypeSystemDefinition::Schema(schema) if schema.node.extend => {
+                    extensions.push(TypeSystemDefinition::Schema(schema));
+                }
+                TypeSystemDefinition::Schema(schema) => match &definition {
+                    Some(first) => errors.push(Error::SchemaDefinitionDuplicated {
+                        first: first.pos,
+                        second: schema.pos,
+                    }),
+                    None => definition = Some(schema),
+                },
+                TypeSystemDefinition::Type(ty) if ty.node.extend => {
+                    extensions.push(TypeSystemDefinition::Type(ty));
+                }
+                TypeSystemDefinition::Type(ty) => {
+                    let name = ty.node.name.node.clone();
+                    if builtin_types.remove(&name) {
+                        types.insert(name, ty);
+                    } else if let Some(first) = types.get(&name) {
+                        errors.push(Error::TypeDuplicated {
+                            name,
+                            first: first.pos,
+                            second: ty.pos,
+                        });
+                    } else {
+                        types.insert(name, ty);
+                    }
+                }
+                TypeSystemDefinition::Directive(directive) => {
+                    let name = directive.node.name.node.clone();
+                    if builtin_directives.remove(&name) {
+                        directives.insert(name, directive);
+                    } else if let Some(first) = directives.get(&name) {
+                        errors.push(Error::DirectiveDuplicated {
+                            name,
+                            first: first.pos,
+                            second: directive.pos,
+                        });
+                    } else {
+                        directives.insert(name, directive);
+                    }
+                }
+            }
+        }
+
+        // Extensions apply once all the definitions are known, regardless of the order of the
+        // documents.
+        for extension in extensions {
+            match extension {
+                TypeSystemDefinition::Schema(extension) => {
+                    let schema = definition.get_or_insert_with(|| {
+                        implicit_schema(extension.pos, |name| types.get(name))
+                    });
+                    extend_schema(schema, extension, &mut errors);
+                }
+                TypeSystemDefinition::Type(extension) => {
+                    match types.get_mut(&extension.node.name.node) {
+                        Some(ty) => extend_type(ty, extension, &mut errors),
+                        None => errors.push(Error::ExtendedTypeNotDefined {
+                            name: extension.node.name.node,
+                            pos: extension.pos,
+                        }),
+                    }
+                }
+                TypeSystemDefinition::Directive(_) => unreachable!("directives cannot be extended"),
+            }
+        }
+
+        let [query_type, mutation_type, subscription_type] = [
+            OperationType::Query,
+            OperationType::Mutation,
+            OperationType::Subscription,
+        ]
+        .map(|operation| match &definition {
+            Some(definition) => {
+                let root = match operation {
+                    OperationType::Query => &definition.node.query,
+                    OperationType::Mutation => &definition.node.mutation,
+                    OperationType::Subscription => &definition.node.subscription,
+                };
+                let root = root.as_ref()?;
+                match types.get(&root.node).map(|ty| &ty.node.kind) {
+                    Some(TypeKind::Object(_)) => Some(root.node.clone()),
+                    Some(_) => {
+                        errors.push(Error::RootTypeNotObject {
+                            root: operation,
+                            name: root.node.clone(),
+                            pos: root.pos,
+                        });
+                        None
+                    }
+                    None => {
+                        errors.push(Error::UnknownRootType {
+                            root: operation,
+                            name: root.node.clone(),
+                            pos: root.pos,
+                        });
+                        None
+                    }
+                }
+            }
+            // Without a schema definition, the root types are the object types with the
+            // default root names.
+            None => {
+                let name = Name::new(match operation {
+                    OperationType::Query => "Query",
+                    OperationType::Mutation => "Mutation",
+                    OperationType::Subscription => "Subscription",
+                });
+                match types.get(&name).map(|ty| &ty.node.kind) {
+                    Some(TypeKind::Object(_)) => Some(name),
+                    _ => None,
+                }
+            }
+        });
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut implementations: HashMap<Name, IndexSet<Name>> = HashMap::new();
+        let mut possible_types: HashMap<Name, IndexSet<Name>> = HashMap::new();
+        for ty in types.values() {
+            let name = &ty.node.name.node;
+            match &ty.node.kind {
+                TypeKind::Object(object) => {
+                    possible_types
+                        .entry(name.clone())
+                        .or_default()
+                        .insert(name.clone());
+                    for interface in &object.implements {
+                        implementations
+                            .entry(interface.node.clone())
+                            .or_default()
+                            .insert(name.clone());
+                        possible_types
+                            .entry(interface.node.clone())
+                            .or_default()
+                            .insert(name.clone());
+                    }
+                }
+                TypeKind::Interface(interface) => {
+                    for implemented in &interface.implements {
+                        implementations
+                            .entry(implemented.node.clone())
+                            .or_default()
+                            .insert(name.clone());
+                    }
+                }
+                TypeKind::Union(union) => {
+                    possible_types
+                        .entry(name.clone())
+                        .or_default()
+                        .extend(union.members.iter().map(|member| member.node.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Schema {
+            definition,
+            query_type,
+            mutation_type,
+            subscription_type,
+            types,
+            directives,
+            implementations,
+            possible_types,
+            builtin_types,
+            builtin_directives,
+        })
+    }
+}
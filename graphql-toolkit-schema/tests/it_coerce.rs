@@ -0,0 +1,292 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{
+    value, ConstValue, DocumentOperations, Name, OperationDefinition, Type, Variables,
+};
+use graphql_toolkit_schema::{CoercionError, PathSegment, Schema};
+use indoc::indoc;
+
+const SCHEMA: &str = indoc! {r#"
+    type Query { users(filter: UserFilter): [User] }
+    type User { id: ID! name: String }
+    enum Role { ADMIN USER }
+    input UserFilter {
+        name: String
+        role: Role = USER
+        limit: Int! = 10
+        tags: [String!]
+        score: Float
+        ids: [ID!]
+        nested: UserFilter
+    }
+    input Required { id: ID! }
+    input Lookup @oneOf { id: ID name: String }
+"#};
+
+/// Test helper function to build the test schema.
+///
+/// # Panics
+/// The function asserts that the test schema is valid.
+fn schema() -> Schema {
+    let document = graphql_toolkit_parser::parse_schema(SCHEMA).expect("schema parsing failed");
+    Schema::from_document(document).expect("schema building failed")
+}
+
+/// Test helper function to coerce a value to the type given in GraphQL syntax.
+///
+/// # Panics
+/// The function asserts that the type is valid.
+fn coerce(value: ConstValue, ty: &str) -> Result<ConstValue, Vec<CoercionError>> {
+    let ty = Type::new(ty).expect("invalid type");
+    schema().coerce_input_value(&value, &ty)
+}
+
+/// Test helper function to coerce a value and return the error messages, including the paths.
+///
+/// # Panics
+/// The function asserts that the coercion fails.
+fn coerce_errors(value: ConstValue, ty: &str) -> Vec<String> {
+    coerce(value, ty)
+        .expect_err("coercion succeeded")
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Test helper function to parse an anonymous operation.
+///
+/// # Panics
+/// The function asserts that the input is a valid document with a single anonymous operation.
+fn operation(input: &str) -> OperationDefinition {
+    let document = graphql_toolkit_parser::parse_query(input).expect("query parsing failed");
+    match document.operations {
+        DocumentOperations::Single(operation) => operation.node,
+        DocumentOperations::Multiple(_) => panic!("expected a single anonymous operation"),
+    }
+}
+
+#[test]
+fn coerce_scalars() {
+    //* Then
+    assert_eq!(coerce(value!(42), "Int"), Ok(value!(42)));
+    assert_eq!(coerce(value!(42), "Float"), Ok(value!(42.0)));
+    assert_eq!(coerce(value!(1.5), "Float"), Ok(value!(1.5)));
+    assert_eq!(coerce(value!("a"), "String"), Ok(value!("a")));
+    assert_eq!(coerce(value!(true), "Boolean"), Ok(value!(true)));
+    assert_eq!(coerce(value!("1"), "ID"), Ok(value!("1")));
+    assert_eq!(coerce(value!(1), "ID"), Ok(value!("1")));
+    assert_eq!(coerce(value!(null), "Int"), Ok(value!(null)));
+}
+
+#[test]
+fn reject_invalid_scalars() {
+    //* Then
+    assert_eq!(
+        coerce_errors(value!(2147483648i64), "Int"),
+        ["expected value of type Int, found 2147483648"]
+    );
+    assert_eq!(
+        coerce_errors(value!(1.5), "Int"),
+        ["expected value of type Int, found 1.5"]
+    );
+    assert_eq!(
+        coerce_errors(value!(1), "String"),
+        ["expected value of type String, found 1"]
+    );
+    assert_eq!(
+        coerce_errors(value!(1.5), "ID!"),
+        ["expected value of type ID!, found 1.5"]
+    );
+    assert_eq!(
+        coerce_errors(value!(null), "Int!"),
+        ["expected value of type Int!, found null"]
+    );
+}
+
+#[test]
+fn coerce_enum_values_from_enums_only() {
+    //* Then
+    let admin = ConstValue::Enum(Name::new("ADMIN"));
+    assert_eq!(coerce(admin.clone(), "Role"), Ok(admin));
+    assert_eq!(
+        coerce_errors(ConstValue::Enum(Name::new("OWNER")), "Role"),
+        ["expected value of type Role, found OWNER"]
+    );
+    assert_eq!(
+        coerce_errors(value!("ADMIN"), "Role"),
+        [r#"expected value of type Role, found "ADMIN""#]
+    );
+}
+
+#[test]
+fn coerce_single_values_to_lists() {
+    //* Then
+    assert_eq!(coerce(value!(1), "[Int]"), Ok(value!([1])));
+    assert_eq!(coerce(value!(1), "[[Int]]"), Ok(value!([[1]])));
+    assert_eq!(coerce(value!([1, null]), "[Int]"), Ok(value!([1, null])));
+    assert_eq!(
+        coerce_errors(value!([1, null]), "[Int!]"),
+        ["expected value of type Int!, found null at [1]"]
+    );
+}
+
+#[test]
+fn coerce_input_objects_with_defaults_in_definition_order() {
+    //* When
+    let result = coerce(
+        value!({ "ids": [1, "2"], "score": 3, "name": "ada" }),
+        "UserFilter",
+    );
+
+    //* Then
+    assert_matches!(result, Ok(ConstValue::Object(fields)) => {
+        let names = fields.keys().map(Name::as_str).collect::<Vec<_>>();
+        assert_eq!(names, ["name", "role", "limit", "score", "ids"]);
+        assert_eq!(fields["role"], ConstValue::Enum(Name::new("USER")));
+        assert_eq!(fields["limit"], value!(10));
+        assert_eq!(fields["score"], value!(3.0));
+        assert_eq!(fields["ids"], value!(["1", "2"]));
+    });
+}
+
+#[test]
+fn report_all_input_object_errors_with_paths() {
+    //* When
+    let errors = coerce_errors(
+        value!({ "unknown": 1, "limit": null, "nested": { "tags": ["a", 1] } }),
+        "UserFilter",
+    );
+
+    //* Then
+    assert_eq!(
+        errors,
+        [
+            "field unknown is not defined by type UserFilter",
+            "expected value of type Int!, found null at limit",
+            "expected value of type String!, found 1 at nested.tags[1]",
+        ]
+    );
+    assert_eq!(
+        coerce_errors(value!({}), "Required"),
+        ["field Required.id of required type ID! was not provided"]
+    );
+    assert_eq!(
+        coerce_errors(value!("a"), "UserFilter"),
+        [r#"expected value of type UserFilter, found "a""#]
+    );
+}
+
+#[test]
+fn coerce_one_of_input_objects() {
+    //* Then
+    assert_eq!(
+        coerce(value!({ "id": 1 }), "Lookup"),
+        Ok(value!({ "id": "1" }))
+    );
+    assert_eq!(
+        coerce_errors(value!({ "id": 1, "name": "ada" }), "Lookup"),
+        ["oneOf input object Lookup must specify exactly one field"]
+    );
+    assert_eq!(
+        coerce_errors(value!({}), "Lookup"),
+        ["oneOf input object Lookup must specify exactly one field"]
+    );
+    assert_eq!(
+        coerce_errors(value!({ "id": null }), "Lookup"),
+        ["field Lookup.id must be non-null"]
+    );
+}
+
+#[test]
+fn reject_unknown_and_output_types() {
+    //* When
+    let errors = coerce(value!({}), "User").expect_err("coercion succeeded");
+
+    //* Then
+    assert_eq!(
+        errors,
+        [CoercionError::new("type User is not an input type", vec![])]
+    );
+    assert_eq!(
+        coerce_errors(value!(1), "Unknown"),
+        ["unknown type Unknown"]
+    );
+    assert_matches!(coerce(value!([{ "nested": 1 }]), "[UserFilter]"), Err(errors) => {
+        assert_eq!(
+            errors[0].path,
+            [PathSegment::Index(0), PathSegment::Field(Name::new("nested"))]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "expected value of type UserFilter, found 1 at [0].nested"
+        );
+    });
+}
+
+#[test]
+fn coerce_variable_values_with_defaults() {
+    //* Given
+    let operation = operation(indoc! {r#"
+        query ($id: ID!, $role: Role = ADMIN, $limit: Int, $filter: UserFilter) {
+            users(filter: $filter) { id }
+        }
+    "#});
+    // The enum values are given as strings in JSON.
+    let variables = Variables::from_json(serde_json::json!({
+        "id": 1,
+        "filter": { "role": "ADMIN" },
+        "unused": true,
+    }));
+
+    //* When
+    let result = schema().coerce_variable_values(&operation, &variables);
+
+    //* Then
+    assert_matches!(result, Ok(variables) => {
+        assert_eq!(
+            variables.into_value(),
+            value!({
+                "filter": { "role": ConstValue::Enum(Name::new("ADMIN")), "limit": 10 },
+                "id": "1",
+                "role": ConstValue::Enum(Name::new("ADMIN")),
+            })
+        );
+    });
+}
+
+#[test]
+fn report_invalid_variable_values_with_paths() {
+    //* Given
+    let operation = operation(indoc! {r#"
+        query ($id: ID!, $ids: [ID!]!, $limit: Int!, $filter: UserFilter, $user: User) {
+            users(filter: $filter) { id }
+        }
+    "#});
+    let variables = Variables::from_json(serde_json::json!({
+        "ids": [1, null],
+        "limit": null,
+        "filter": { "nested": { "limit": "10" } },
+    }));
+
+    //* When
+    let result = schema().coerce_variable_values(&operation, &variables);
+
+    //* Then
+    assert_matches!(result, Err(errors) => {
+        let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "variable $id of required type ID! was not provided at $id",
+                "expected value of type ID!, found null at $ids[1]",
+                "expected value of type Int!, found null at $limit",
+                r#"expected value of type Int!, found "10" at $filter.nested.limit"#,
+                "variable $user cannot be of non-input type User at $user",
+            ]
+        );
+        assert_eq!(errors[0].path, [PathSegment::Variable(Name::new("id"))]);
+        assert_eq!(
+            errors[1].path,
+            [PathSegment::Variable(Name::new("ids")), PathSegment::Index(1)]
+        );
+    });
+}
@@ -0,0 +1,200 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{Name, Pos, TypeKind};
+use graphql_toolkit_schema::{Contract, ContractIssue, DirectiveFilter, Schema, SchemaCoordinate};
+use indoc::indoc;
+
+/// Test helper function to apply a contract to a GraphQL SDL string, and build a schema from the
+/// filtered document.
+///
+/// # Panics
+/// The function asserts that the input is valid SDL, and that the schema can be built.
+fn apply(contract: &Contract, input: &str) -> (Schema, Vec<String>) {
+    let document = graphql_toolkit_parser::parse_schema(input).expect("schema parsing failed");
+    let output = contract.apply(document);
+    let issues = output.issues.iter().map(ToString::to_string).collect();
+    let schema = Schema::from_document(output.document).expect("schema building failed");
+    (schema, issues)
+}
+
+/// Test helper function to create the contract used in the tests, excluding the `@inaccessible`
+/// and `@tag(name: "internal")` elements.
+fn partner_contract() -> Contract {
+    Contract::new()
+        .exclude(DirectiveFilter::new("inaccessible"))
+        .exclude(DirectiveFilter::new("tag").argument("name", "internal"))
+}
+
+#[test]
+fn remove_tagged_elements() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query {
+          user(id: ID!, debug: Boolean @inaccessible): User
+          audit: AuditLog @tag(name: "internal")
+          stats: Stats @tag(name: "public")
+        }
+        type User { id: ID! role: Role secret: String @tag(name: "internal") }
+        enum Role { ADMIN USER STAFF @inaccessible }
+        type AuditLog { entries: [String] }
+        type Stats @tag(name: "public") { count: Int }
+        type Internal @inaccessible { id: ID }
+        input UserFilter { role: Role internal: Boolean @inaccessible }
+        extend type User { token: String @inaccessible }
+    "#};
+
+    //* When
+    let (schema, issues) = apply(&partner_contract(), sdl);
+
+    //* Then
+    assert!(issues.is_empty(), "unexpected issues: {:?}", issues);
+    let fields = |type_name| {
+        schema
+            .fields(type_name)
+            .iter()
+            .map(|field| field.node.name.node.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(fields("Query"), ["user", "stats"]);
+    assert_eq!(fields("User"), ["id", "role"]);
+    assert_eq!(
+        schema
+            .get_field("Query", "user")
+            .unwrap()
+            .node
+            .arguments
+            .len(),
+        1
+    );
+    assert_matches!(&schema.get_type("Role").unwrap().node.kind, TypeKind::Enum(role) => {
+        assert_eq!(role.values.len(), 2);
+    });
+    assert!(schema.get_type("Internal").is_none());
+    // Unreachable types, once their references are removed.
+    assert!(schema.get_type("AuditLog").is_none());
+    assert!(schema.get_type("UserFilter").is_none());
+    assert!(schema.get_type("Stats").is_some());
+}
+
+#[test]
+fn prune_unreachable_types() {
+    //* Given
+    let sdl = indoc! {r#"
+        schema { query: Root }
+        type Root { node(id: ID!, filter: Filter): Node }
+        interface Node { id: ID! }
+        type User implements Node { id: ID! }
+        input Filter { kind: Kind }
+        enum Kind { USER }
+        type Orphan { id: ID! }
+        scalar Date
+        directive @cache(ttl: Duration) on FIELD_DEFINITION
+        scalar Duration
+    "#};
+    let document = graphql_toolkit_parser::parse_schema(sdl).expect("schema parsing failed");
+
+    //* When
+    let (schema, issues) = apply(&Contract::new(), sdl);
+    let kept = Contract::new().keep_unreachable_types().apply(document);
+
+    //* Then
+    assert!(issues.is_empty(), "unexpected issues: {:?}", issues);
+    for name in ["Root", "Node", "User", "Filter", "Kind", "Duration"] {
+        assert!(schema.get_type(name).is_some(), "type {} was pruned", name);
+    }
+    assert!(schema.get_type("Orphan").is_none());
+    assert!(schema.get_type("Date").is_none());
+    assert_eq!(kept.document.definitions.len(), 10);
+}
+
+#[test]
+fn report_dangling_references() {
+    //* Given
+    let sdl = indoc! {r#"
+        schema { query: Query mutation: Mutation }
+        type Query {
+          search: [Result]
+          node: Node
+          account: Account
+          settings(input: SettingsInput): Settings
+        }
+        type Mutation @inaccessible { noop: Boolean }
+        interface Node { id: ID! }
+        type User implements Node @inaccessible { id: ID! }
+        union Result = User
+        type Account { secret: Secret }
+        type Secret @tag(name: "internal") { value: String }
+        type Settings { debug: Boolean @inaccessible }
+        input SettingsInput { debug: Boolean }
+    "#};
+    let document = graphql_toolkit_parser::parse_schema(sdl).expect("schema parsing failed");
+
+    //* When
+    let output = partner_contract().apply(document);
+
+    //* Then
+    let issues = output
+        .issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        issues,
+        [
+            "mutation root type Mutation was removed",
+            "Account.secret refers to removed type Secret",
+            "interface Node is not implemented by any object type",
+            "type Result is empty",
+            "type Settings is empty",
+        ]
+    );
+    assert_eq!(
+        output.issues[1],
+        ContractIssue::RemovedTypeReferenced {
+            referrer: SchemaCoordinate::member("Account", "secret"),
+            name: Name::new("Secret"),
+            pos: Pos {
+                line: 12,
+                column: 24
+            },
+        }
+    );
+}
+
+#[test]
+fn report_interface_fields_removed_from_implementations() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { node: Node user: User }
+        interface Node { id: ID! tags(first: Int): [String] }
+        type User implements Node {
+          id: ID! @inaccessible
+          tags(first: Int @inaccessible): [String]
+        }
+        type Group implements Node { id: ID! tags(first: Int): [String] }
+    "#};
+    let document = graphql_toolkit_parser::parse_schema(sdl).expect("schema parsing failed");
+
+    //* When
+    let output = partner_contract().apply(document);
+
+    //* Then
+    let issues = output
+        .issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        issues,
+        [
+            "User does not implement Node.id",
+            "User does not implement Node.tags(first:)",
+        ]
+    );
+    assert_matches!(
+        &output.issues[0],
+        ContractIssue::InterfaceFieldNotImplemented { type_name, pos, .. } => {
+            assert_eq!(type_name, "User");
+            assert_eq!(*pos, Pos { line: 3, column: 22 });
+        }
+    );
+}
@@ -0,0 +1,248 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{TypeKind, TypeSystemDefinition};
+use graphql_toolkit_schema::{CoordinateIndex, Schema, SchemaCoordinate, SchemaElement};
+use indoc::indoc;
+
+/// Test helper function to build the test schema.
+///
+/// # Panics
+/// The function asserts that the test schema is valid.
+fn build_schema() -> Schema {
+    let document = graphql_toolkit_parser::parse_schema(indoc! {r#"
+        type Query { user(id: ID!): User }
+        type User { id: ID! email: String }
+        enum Role { ADMIN USER }
+        input UserFilter { role: Role }
+        union Result = User
+        directive @tag(name: String!) on FIELD_DEFINITION
+    "#})
+    .expect("schema parsing failed");
+    Schema::from_document(document).expect("schema building failed")
+}
+
+/// Test helper function to parse a schema coordinate.
+///
+/// # Panics
+/// The function asserts that the coordinate is valid.
+fn coordinate(input: &str) -> SchemaCoordinate {
+    input.parse().expect("invalid schema coordinate")
+}
+
+#[test]
+fn parse_and_print_schema_coordinates() {
+    //* Then
+    assert_eq!(coordinate("User"), SchemaCoordinate::ty("User"));
+    assert_eq!(
+        coordinate("User.email"),
+        SchemaCoordinate::member("User", "email")
+    );
+    assert_eq!(
+        coordinate("Query.user(id:)"),
+        SchemaCoordinate::argument("Query", "user", "id")
+    );
+    assert_eq!(
+        coordinate("@deprecated"),
+        SchemaCoordinate::directive("deprecated")
+    );
+    assert_eq!(
+        coordinate("@deprecated(reason:)"),
+        SchemaCoordinate::directive_argument("deprecated", "reason")
+    );
+    for input in [
+        "User",
+        "Role.ADMIN",
+        "Query.user(id:)",
+        "@tag",
+        "@tag(name:)",
+    ] {
+        assert_eq!(coordinate(input).to_string(), input);
+    }
+}
+
+#[test]
+fn reject_invalid_schema_coordinates() {
+    //* Then
+    for input in [
+        "",
+        "User.",
+        "User.email.domain",
+        "User(id:)",
+        "Query.user(id)",
+        "Query.user(id:",
+        "@",
+        "@tag.name",
+        "1User",
+        "User .email",
+    ] {
+        assert_matches!(input.parse::<SchemaCoordinate>(), Err(err) => {
+            assert_eq!(err.to_string(), format!("invalid schema coordinate {}", input));
+        });
+    }
+}
+
+#[test]
+fn resolve_schema_coordinates() {
+    //* Given
+    let schema = build_schema();
+
+    //* Then
+    assert_matches!(schema.resolve_coordinate(&coordinate("User")), Some(SchemaElement::Type(ty)) => {
+        assert_eq!(ty.node.name.node, "User");
+    });
+    assert_matches!(schema.resolve_coordinate(&coordinate("User.email")), Some(SchemaElement::Field(field)) => {
+        assert_eq!(field.node.name.node, "email");
+    });
+    assert_matches!(
+        schema.resolve_coordinate(&coordinate("UserFilter.role")),
+        Some(SchemaElement::InputField(_))
+    );
+    assert_matches!(schema.resolve_coordinate(&coordinate("Role.ADMIN")), Some(SchemaElement::EnumValue(value)) => {
+        assert_eq!(value.node.value.node, "ADMIN");
+    });
+    assert_matches!(schema.resolve_coordinate(&coordinate("Query.user(id:)")), Some(SchemaElement::Argument(arg)) => {
+        assert_eq!(arg.node.name.node, "id");
+    });
+    assert_matches!(
+        schema.resolve_coordinate(&coordinate("@deprecated")),
+        Some(SchemaElement::Directive(_))
+    );
+    assert_matches!(
+        schema.resolve_coordinate(&coordinate("@tag(name:)")),
+        Some(SchemaElement::Argument(_))
+    );
+    for input in [
+        "Unknown",
+        "User.unknown",
+        "Result.User",
+        "Query.user(name:)",
+        "@unknown",
+    ] {
+        assert!(
+            schema.resolve_coordinate(&coordinate(input)).is_none(),
+            "{} resolved",
+            input
+        );
+    }
+}
+
+#[test]
+fn compute_coordinates_of_schema_elements() {
+    //* Given
+    let schema = build_schema();
+    let other_schema = build_schema();
+
+    //* When
+    let coordinates = schema
+        .coordinates()
+        .into_iter()
+        .map(|(coordinate, _)| coordinate.to_string())
+        .filter(|coordinate| coordinate.starts_with(['Q', 'U', 'R', '@']))
+        .collect::<Vec<_>>();
+
+    //* Then
+    assert_eq!(
+        coordinates,
+        [
+            "Query",
+            "Query.user",
+            "Query.user(id:)",
+            "User",
+            "User.id",
+            "User.email",
+            "Role",
+            "Role.ADMIN",
+            "Role.USER",
+            "UserFilter",
+            "UserFilter.role",
+            "Result",
+            "@include",
+            "@include(if:)",
+            "@skip",
+            "@skip(if:)",
+            "@deprecated",
+            "@deprecated(reason:)",
+            "@specifiedBy",
+            "@specifiedBy(url:)",
+            "@oneOf",
+            "@tag",
+            "@tag(name:)",
+        ]
+    );
+
+    let index = schema.coordinate_index();
+    let email = schema.get_field("User", "email").expect("field not found");
+    assert_eq!(
+        index.coordinate_of(SchemaElement::Field(email)),
+        Some(&SchemaCoordinate::member("User", "email"))
+    );
+    let id = &schema
+        .get_field("Query", "user")
+        .expect("field not found")
+        .node
+        .arguments[0];
+    assert_eq!(
+        index.coordinate_of(SchemaElement::Argument(id)),
+        Some(&SchemaCoordinate::argument("Query", "user", "id"))
+    );
+    let other_email = other_schema
+        .get_field("User", "email")
+        .expect("field not found");
+    assert_eq!(index.coordinate_of(SchemaElement::Field(other_email)), None);
+}
+
+#[test]
+fn compute_coordinates_of_service_document_elements() {
+    //* Given
+    let document = graphql_toolkit_parser::parse_schema(indoc! {r#"
+        type User { id: ID! }
+        extend type User { posts(first: Int): [String] }
+        directive @tag(name: String!) on FIELD_DEFINITION
+    "#})
+    .expect("schema parsing failed");
+
+    //* When
+    let index = CoordinateIndex::from_document(&document);
+
+    //* Then
+    let coordinates = document
+        .definitions
+        .iter()
+        .flat_map(|def| match def {
+            TypeSystemDefinition::Type(ty) => {
+                let TypeKind::Object(object) = &ty.node.kind else {
+                    panic!("not an object type");
+                };
+                let mut elements = vec![SchemaElement::Type(ty)];
+                for field in &object.fields {
+                    elements.push(SchemaElement::Field(field));
+                    elements.extend(field.node.arguments.iter().map(SchemaElement::Argument));
+                }
+                elements
+            }
+            TypeSystemDefinition::Directive(directive) => {
+                let mut elements = vec![SchemaElement::Directive(directive)];
+                elements.extend(directive.node.arguments.iter().map(SchemaElement::Argument));
+                elements
+            }
+            TypeSystemDefinition::Schema(_) => vec![],
+        })
+        .map(|element| {
+            index
+                .coordinate_of(element)
+                .expect("coordinate not found")
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        coordinates,
+        [
+            "User",
+            "User.id",
+            "User",
+            "User.posts",
+            "User.posts(first:)",
+            "@tag",
+            "@tag(name:)",
+        ]
+    );
+}
@@ -0,0 +1,339 @@
+use graphql_toolkit_ast::Pos;
+use graphql_toolkit_schema::{
+    diff_schemas, Change, ChangeCode, Criticality, Schema, SchemaCoordinate,
+};
+use indoc::indoc;
+
+/// Test helper function to parse a GraphQL SDL string and build a schema from it.
+///
+/// # Panics
+/// The function asserts that the input is valid SDL, and that the schema can be built.
+fn build_schema(input: &str) -> Schema {
+    let document = graphql_toolkit_parser::parse_schema(input).expect("schema parsing failed");
+    Schema::from_document(document).expect("schema building failed")
+}
+
+/// Test helper function to diff two schemas, and summarize the changes as
+/// `(criticality, code, coordinate)` tuples.
+fn diff(old: &str, new: &str) -> Vec<(Criticality, ChangeCode, String)> {
+    diff_schemas(&build_schema(old), &build_schema(new))
+        .into_iter()
+        .map(|change| {
+            (
+                change.criticality,
+                change.code,
+                change.coordinate.to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn identical_schemas_have_no_changes() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { user(id: ID!): User }
+        "A user."
+        type User { id: ID! name: String @deprecated }
+    "#};
+
+    //* Then
+    assert!(diff(sdl, sdl).is_empty());
+}
+
+#[test]
+fn detect_type_and_field_changes() {
+    //* Given
+    let old = indoc! {r#"
+        type Query { user: User users: [User] }
+        type User { id: ID! name: String email: String }
+        type Image { url: String }
+    "#};
+    let new = indoc! {r#"
+        type Query { user: User! users: [User!] }
+        type User { id: ID name: String @deprecated age: Int }
+        interface Image { url: String }
+        scalar Date
+    "#};
+
+    //* When
+    let changes = diff_schemas(&build_schema(old), &build_schema(new));
+
+    //* Then
+    let summary = diff(old, new);
+    let summary = summary
+        .iter()
+        .map(|(criticality, code, coordinate)| (*criticality, *code, coordinate.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (
+                Criticality::Safe,
+                ChangeCode::FieldTypeChanged,
+                "Query.user"
+            ),
+            (
+                Criticality::Safe,
+                ChangeCode::FieldTypeChanged,
+                "Query.users"
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::FieldTypeChanged,
+                "User.id"
+            ),
+            (Criticality::Safe, ChangeCode::DeprecationAdded, "User.name"),
+            (
+                Criticality::Breaking,
+                ChangeCode::FieldRemoved,
+                "User.email"
+            ),
+            (Criticality::Safe, ChangeCode::FieldAdded, "User.age"),
+            (Criticality::Breaking, ChangeCode::TypeKindChanged, "Image"),
+            (Criticality::Safe, ChangeCode::TypeAdded, "Date"),
+        ]
+    );
+    assert_eq!(
+        changes[2].to_string(),
+        "field User.id changed type from ID! to ID"
+    );
+    assert_eq!(
+        changes[6].to_string(),
+        "type Image changed from object to interface"
+    );
+    assert_eq!(
+        changes[4],
+        Change {
+            code: ChangeCode::FieldRemoved,
+            criticality: Criticality::Breaking,
+            coordinate: SchemaCoordinate::member("User", "email"),
+            message: "field User.email was removed".to_owned(),
+            old_pos: Some(Pos {
+                line: 2,
+                column: 34
+            }),
+            new_pos: None,
+        }
+    );
+    assert_eq!(
+        changes[5].new_pos,
+        Some(Pos {
+            line: 2,
+            column: 45
+        })
+    );
+}
+
+#[test]
+fn classify_input_type_changes_by_position() {
+    //* Given
+    let old = indoc! {r#"
+        type Query { users(first: Int!, after: String, filter: Filter, order: Order = ASC): [ID] }
+        input Filter { name: String role: String! tags: [String] }
+        enum Order { ASC DESC }
+    "#};
+    let new = indoc! {r#"
+        type Query { users(first: Int, after: String!, filter: Filter, order: Order = DESC, last: Int, ids: [ID!]!): [ID] }
+        input Filter { name: String! role: String tags: [String] = [] limit: Int required: Boolean! }
+        enum Order { ASC DESC RANDOM }
+    "#};
+
+    //* When
+    let changes = diff(old, new);
+
+    //* Then
+    let changes = changes
+        .iter()
+        .map(|(criticality, code, coordinate)| (*criticality, *code, coordinate.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        [
+            (
+                Criticality::Safe,
+                ChangeCode::ArgumentTypeChanged,
+                "Query.users(first:)"
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::ArgumentTypeChanged,
+                "Query.users(after:)"
+            ),
+            (
+                Criticality::Dangerous,
+                ChangeCode::ArgumentDefaultChanged,
+                "Query.users(order:)"
+            ),
+            (
+                Criticality::Dangerous,
+                ChangeCode::ArgumentAdded,
+                "Query.users(last:)"
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::ArgumentAdded,
+                "Query.users(ids:)"
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::InputFieldTypeChanged,
+                "Filter.name"
+            ),
+            (
+                Criticality::Safe,
+                ChangeCode::InputFieldTypeChanged,
+                "Filter.role"
+            ),
+            (
+                Criticality::Dangerous,
+                ChangeCode::InputFieldDefaultChanged,
+                "Filter.tags"
+            ),
+            (
+                Criticality::Dangerous,
+                ChangeCode::InputFieldAdded,
+                "Filter.limit"
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::InputFieldAdded,
+                "Filter.required"
+            ),
+            (
+                Criticality::Dangerous,
+                ChangeCode::EnumValueAdded,
+                "Order.RANDOM"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn detect_abstract_type_and_directive_changes() {
+    //* Given
+    let old = indoc! {r#"
+        schema { query: Query }
+        type Query { search: [Result] }
+        interface Node { id: ID! }
+        type User implements Node { id: ID! }
+        type Image { id: ID! }
+        union Result = User | Image
+        enum Role { ADMIN USER }
+        directive @tag(name: String!) repeatable on FIELD_DEFINITION | OBJECT
+        directive @internal on FIELD_DEFINITION
+    "#};
+    let new = indoc! {r#"
+        schema { query: Root }
+        type Root { search: [Result] }
+        type Query { search: [Result] }
+        interface Node { id: ID! }
+        type User { id: ID! }
+        type Image implements Node { id: ID! }
+        union Result = Image
+        enum Role { ADMIN }
+        directive @tag(name: String!) on FIELD_DEFINITION | INTERFACE
+    "#};
+
+    //* When
+    let changes = diff_schemas(&build_schema(old), &build_schema(new));
+
+    //* Then
+    let messages = changes
+        .iter()
+        .map(|change| format!("{} {}: {}", change.criticality, change.code, change))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "BREAKING ROOT_TYPE_CHANGED: query root type changed from Query to Root",
+            "BREAKING INTERFACE_REMOVED: type User no longer implements Node",
+            "DANGEROUS INTERFACE_ADDED: type Image now implements Node",
+            "BREAKING UNION_MEMBER_REMOVED: type User was removed from union Result",
+            "BREAKING ENUM_VALUE_REMOVED: enum value Role.USER was removed",
+            "SAFE TYPE_ADDED: type Root was added",
+            "BREAKING DIRECTIVE_REPEATABLE_REMOVED: directive @tag is no longer repeatable",
+            "BREAKING DIRECTIVE_LOCATION_REMOVED: location OBJECT was removed from directive @tag",
+            "SAFE DIRECTIVE_LOCATION_ADDED: location INTERFACE was added to directive @tag",
+            "BREAKING DIRECTIVE_REMOVED: directive @internal was removed",
+        ]
+    );
+    assert!(changes
+        .iter()
+        .any(|change| change.criticality == Criticality::Breaking));
+}
+
+#[test]
+fn classify_root_type_changes() {
+    //* Given
+    let old = indoc! {r#"
+        type Query { a: Int }
+        type Subscription { a: Int }
+    "#};
+    let new = indoc! {r#"
+        type Query { a: Int }
+        type Mutation { a: Int }
+    "#};
+
+    //* When
+    let summary = diff(old, new);
+
+    //* Then
+    assert_eq!(
+        summary[..2],
+        [
+            (
+                Criticality::Safe,
+                ChangeCode::RootTypeChanged,
+                "Mutation".to_owned()
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::RootTypeChanged,
+                "Subscription".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn narrow_field_types_to_implementations() {
+    //* Given
+    let old = indoc! {r#"
+        type Query { node: Node nodes: [Node] user: User }
+        interface Node { id: ID }
+        type User implements Node { id: ID }
+        type Post { id: ID }
+    "#};
+    let new = indoc! {r#"
+        type Query { node: User! nodes: [Post] user: Node }
+        interface Node { id: ID }
+        type User implements Node { id: ID }
+        type Post { id: ID }
+    "#};
+
+    //* When
+    let summary = diff(old, new);
+
+    //* Then
+    assert_eq!(
+        summary,
+        [
+            (
+                Criticality::Safe,
+                ChangeCode::FieldTypeChanged,
+                "Query.node".to_owned()
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::FieldTypeChanged,
+                "Query.nodes".to_owned()
+            ),
+            (
+                Criticality::Breaking,
+                ChangeCode::FieldTypeChanged,
+                "Query.user".to_owned()
+            ),
+        ]
+    );
+}
@@ -0,0 +1,207 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{OperationType, ServiceDocument, TypeKind, TypeSystemDefinition};
+use graphql_toolkit_schema::{apply_extensions, Error, Schema};
+use indoc::indoc;
+
+/// Test helper function to parse a GraphQL SDL string into a service document.
+///
+/// # Panics
+/// The function asserts that the input is valid SDL by checking the result of the
+/// `parse_schema` function.
+fn parse_document(input: &str) -> ServiceDocument {
+    graphql_toolkit_parser::parse_schema(input).expect("schema parsing failed")
+}
+
+#[test]
+fn object_extensions_merge_fields_interfaces_and_directives() {
+    //* Given
+    let document = parse_document(indoc! {r#"
+        extend type Query implements Node @tag { users: [User] }
+        interface Node { id: ID! }
+        type Query { id: ID! }
+        extend type Query { posts: [Post] }
+    "#});
+
+    //* When
+    let result = apply_extensions(document);
+
+    //* Then
+    assert_matches!(result, Ok(document) => {
+        assert_eq!(document.definitions.len(), 2);
+        assert_matches!(&document.definitions[1], TypeSystemDefinition::Type(ty) => {
+            assert!(!ty.node.extend);
+            assert_eq!(ty.node.directives[0].node.name.node, "tag");
+            assert_matches!(&ty.node.kind, TypeKind::Object(object) => {
+                let fields: Vec<_> = object.fields.iter().map(|f| f.node.name.node.as_str()).collect();
+                assert_eq!(fields, ["id", "users", "posts"]);
+                assert_eq!(object.implements[0].node, "Node");
+            });
+        });
+    });
+}
+
+#[test]
+fn union_enum_and_input_extensions_are_merged() {
+    //* Given
+    let document = parse_document(indoc! {r#"
+        union SearchResult = User
+        extend union SearchResult = Post
+        enum Role { ADMIN }
+        extend enum Role { USER }
+        input Filter { name: String }
+        extend input Filter { limit: Int }
+        scalar Date
+        extend scalar Date @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")
+    "#});
+
+    //* When
+    let result = apply_extensions(document);
+
+    //* Then
+    assert_matches!(result, Ok(document) => {
+        assert_eq!(document.definitions.len(), 4);
+        assert_matches!(&document.definitions[0], TypeSystemDefinition::Type(ty) => {
+            assert_matches!(&ty.node.kind, TypeKind::Union(union) => {
+                assert_eq!(union.members.len(), 2);
+            });
+        });
+        assert_matches!(&document.definitions[1], TypeSystemDefinition::Type(ty) => {
+            assert_matches!(&ty.node.kind, TypeKind::Enum(enum_type) => {
+                assert_eq!(enum_type.values.len(), 2);
+            });
+        });
+        assert_matches!(&document.definitions[2], TypeSystemDefinition::Type(ty) => {
+            assert_matches!(&ty.node.kind, TypeKind::InputObject(input) => {
+                assert_eq!(input.fields.len(), 2);
+            });
+        });
+        assert_matches!(&document.definitions[3], TypeSystemDefinition::Type(ty) => {
+            assert_eq!(ty.node.directives.len(), 1);
+        });
+    });
+}
+
+#[test]
+fn schema_extensions_add_root_operations() {
+    //* Given
+    let document = parse_document(indoc! {r#"
+        schema { query: Query }
+        extend schema @link { mutation: Mutation }
+    "#});
+
+    //* When
+    let result = apply_extensions(document);
+
+    //* Then
+    assert_matches!(result, Ok(document) => {
+        assert_matches!(&document.definitions[..], [TypeSystemDefinition::Schema(schema)] => {
+            assert_eq!(schema.node.directives.len(), 1);
+            assert_eq!(schema.node.mutation.as_ref().unwrap().node, "Mutation");
+        });
+    });
+}
+
+#[test]
+fn schema_extensions_without_schema_definition_extend_the_implicit_schema() {
+    //* Given
+    let document = parse_document(indoc! {r#"
+        extend schema @link(url: "https://specs.apollo.dev/federation/v2.3")
+        type Query { id: ID! }
+        type Mutation { id: ID! }
+    "#});
+
+    //* When
+    let result = apply_extensions(document);
+
+    //* Then
+    assert_matches!(result, Ok(document) => {
+        assert_matches!(&document.definitions[0], TypeSystemDefinition::Schema(schema) => {
+            assert!(!schema.node.extend);
+            assert_eq!(schema.node.directives.len(), 1);
+            assert_eq!(schema.node.query.as_ref().unwrap().node, "Query");
+            assert_eq!(schema.node.mutation.as_ref().unwrap().node, "Mutation");
+            assert!(schema.node.subscription.is_none());
+        });
+        assert_eq!(document.definitions.len(), 3);
+    });
+}
+
+#[test]
+fn invalid_extensions_are_reported() {
+    //* Given
+    let document = parse_document(indoc! {r#"
+        type Query { id: ID! }
+        extend type Query { id: ID }
+        extend type Missing { a: Int }
+        extend interface Query { b: Int }
+        extend schema { query: Query }
+    "#});
+
+    //* When
+    let result = apply_extensions(document);
+
+    //* Then
+    assert_matches!(result, Err(errors) => {
+        assert_eq!(errors.len(), 4);
+        assert_matches!(&errors[0], Error::FieldDuplicated { type_name, field, first, second } => {
+            assert_eq!(type_name, "Query");
+            assert_eq!(field, "id");
+            assert_eq!(first.line, 1);
+            assert_eq!(second.line, 2);
+        });
+        assert_matches!(&errors[1], Error::ExtendedTypeNotDefined { name, .. } => {
+            assert_eq!(name, "Missing");
+        });
+        assert_matches!(&errors[2], Error::ExtensionKindMismatch { name, .. } => {
+            assert_eq!(name, "Query");
+        });
+        assert_matches!(&errors[3], Error::RootOperationDuplicated { root, first, second } => {
+            assert_eq!(*root, OperationType::Query);
+            assert_eq!(first.line, 1);
+            assert_eq!(second.line, 5);
+        });
+    });
+}
+
+#[test]
+fn schema_builder_applies_extensions_across_documents() {
+    //* Given
+    let users = parse_document("extend type Query { user: User } type User { id: ID! }");
+    let posts = parse_document("extend type Query { post: Post } type Post { id: ID! }");
+    let query = parse_document("type Query");
+
+    //* When
+    let schema = Schema::builder()
+        .add_document(users)
+        .add_document(posts)
+        .add_document(query)
+        .build();
+
+    //* Then
+    assert_matches!(schema, Ok(schema) => {
+        assert!(schema.get_field("Query", "user").is_some());
+        assert!(schema.get_field("Query", "post").is_some());
+    });
+}
+
+#[test]
+fn schema_builder_applies_schema_extensions_to_the_implicit_schema() {
+    //* Given
+    let link =
+        parse_document(r#"extend schema @link(url: "https://specs.apollo.dev/federation/v2.3")"#);
+    let query = parse_document("type Query { id: ID! }");
+
+    //* When
+    let schema = Schema::builder()
+        .add_document(link)
+        .add_document(query)
+        .build();
+
+    //* Then
+    assert_matches!(schema, Ok(schema) => {
+        assert_matches!(schema.definition(), Some(definition) => {
+            assert_eq!(definition.node.directives.len(), 1);
+        });
+        assert_eq!(schema.query_type().map(|ty| ty.node.name.node.as_str()), Some("Query"));
+    });
+}
@@ -0,0 +1,445 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{ConstValue, DocumentOperations, Name};
+use graphql_toolkit_schema::{
+    introspection_query, introspection_query_source, introspection_to_document,
+    IntrospectionOptions, Schema,
+};
+use indoc::indoc;
+use serde_json::{json, Value};
+
+const SCHEMA: &str = indoc! {r#"
+    schema { query: Root }
+
+    "The query root."
+    type Root {
+        node(id: ID!): Node
+        users(first: Int = 10, filter: UserFilter): [User!]!
+        search(term: String @deprecated(reason: "Use filter")): [SearchResult] @deprecated
+    }
+    interface Node { id: ID! }
+    type User implements Node { id: ID! role: Role }
+    type Image implements Node { id: ID! url: Url }
+    union SearchResult = User | Image
+    enum Role { ADMIN USER @deprecated(reason: "Use MEMBER") MEMBER }
+    input UserFilter { role: Role = ADMIN, tags: [String!] = ["a"] }
+    input Lookup @oneOf { id: ID name: String }
+    scalar Url @specifiedBy(url: "https://url.spec.whatwg.org")
+    directive @tag(name: String!) repeatable on FIELD_DEFINITION | OBJECT
+"#};
+
+/// Test helper function to build the test schema and introspect it as JSON.
+///
+/// # Panics
+/// The function asserts that the test schema is valid, and that the result is valid JSON.
+fn introspect() -> Value {
+    let document = graphql_toolkit_parser::parse_schema(SCHEMA).expect("schema parsing failed");
+    let schema = Schema::from_document(document).expect("schema building failed");
+    schema
+        .introspect()
+        .into_json()
+        .expect("introspection result is valid JSON")
+}
+
+/// Test helper function to find a type of the introspection result by name.
+///
+/// # Panics
+/// The function asserts that the type exists.
+fn find_type<'a>(result: &'a Value, name: &str) -> &'a Value {
+    result["__schema"]["types"]
+        .as_array()
+        .expect("types is a list")
+        .iter()
+        .find(|ty| ty["name"] == name)
+        .unwrap_or_else(|| panic!("type {} not found", name))
+}
+
+#[test]
+fn introspect_root_types_and_type_names() {
+    //* When
+    let result = introspect();
+
+    //* Then
+    let schema = &result["__schema"];
+    assert_eq!(schema["queryType"], json!({ "name": "Root" }));
+    assert_eq!(schema["mutationType"], Value::Null);
+    assert_eq!(schema["subscriptionType"], Value::Null);
+
+    let names = schema["types"]
+        .as_array()
+        .expect("types is a list")
+        .iter()
+        .map(|ty| ty["name"].as_str().expect("type name is a string"))
+        .collect::<Vec<_>>();
+    for name in [
+        "Int",
+        "String",
+        "Root",
+        "SearchResult",
+        "__Schema",
+        "__Type",
+    ] {
+        assert!(names.contains(&name), "type {} not found", name);
+    }
+}
+
+#[test]
+fn introspect_object_fields_with_arguments_and_deprecation() {
+    //* When
+    let result = introspect();
+
+    //* Then
+    let root = find_type(&result, "Root");
+    assert_eq!(root["kind"], "OBJECT");
+    assert_eq!(root["description"], "The query root.");
+    assert_eq!(root["interfaces"], json!([]));
+    assert_eq!(root["enumValues"], Value::Null);
+
+    let users = &root["fields"][1];
+    assert_eq!(
+        users["type"],
+        json!({
+            "kind": "NON_NULL",
+            "name": null,
+            "ofType": {
+                "kind": "LIST",
+                "name": null,
+                "ofType": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": { "kind": "OBJECT", "name": "User", "ofType": null },
+                },
+            },
+        })
+    );
+    assert_eq!(users["args"][0]["defaultValue"], "10");
+    assert_eq!(users["isDeprecated"], false);
+
+    let search = &root["fields"][2];
+    assert_eq!(search["isDeprecated"], true);
+    assert_eq!(search["deprecationReason"], "No longer supported");
+    assert_eq!(
+        search["args"][0],
+        json!({
+            "name": "term",
+            "description": null,
+            "type": { "kind": "SCALAR", "name": "String", "ofType": null },
+            "defaultValue": null,
+            "isDeprecated": true,
+            "deprecationReason": "Use filter",
+        })
+    );
+}
+
+#[test]
+fn introspect_abstract_types() {
+    //* When
+    let result = introspect();
+
+    //* Then
+    let node = find_type(&result, "Node");
+    assert_eq!(node["kind"], "INTERFACE");
+    assert_eq!(
+        node["possibleTypes"],
+        json!([
+            { "kind": "OBJECT", "name": "User", "ofType": null },
+            { "kind": "OBJECT", "name": "Image", "ofType": null },
+        ])
+    );
+    assert_eq!(
+        find_type(&result, "User")["interfaces"],
+        json!([{ "kind": "INTERFACE", "name": "Node", "ofType": null }])
+    );
+
+    let search_result = find_type(&result, "SearchResult");
+    assert_eq!(search_result["kind"], "UNION");
+    assert_eq!(search_result["fields"], Value::Null);
+    assert_eq!(search_result["possibleTypes"][1]["name"], "Image");
+}
+
+#[test]
+fn skip_references_to_undefined_types() {
+    //* Given
+    let document = graphql_toolkit_parser::parse_schema(indoc! {r#"
+        type Query implements Missing { id(format: Format): ID missing: [Missing!]! }
+        union Result = Query | Gone
+    "#})
+    .expect("schema parsing failed");
+    let schema = Schema::from_document(document).expect("schema building failed");
+
+    //* When
+    let result = schema
+        .introspect()
+        .into_json()
+        .expect("introspection result is valid JSON");
+
+    //* Then
+    let query = find_type(&result, "Query");
+    assert_eq!(query["interfaces"], json!([]));
+    assert_eq!(query["fields"].as_array().map(Vec::len), Some(1));
+    assert_eq!(query["fields"][0]["name"], "id");
+    assert_eq!(query["fields"][0]["args"], json!([]));
+    assert_eq!(
+        find_type(&result, "Result")["possibleTypes"],
+        json!([{ "kind": "OBJECT", "name": "Query", "ofType": null }])
+    );
+}
+
+#[test]
+fn introspect_leaf_and_input_types() {
+    //* When
+    let result = introspect();
+
+    //* Then
+    let role = find_type(&result, "Role");
+    assert_eq!(
+        role["enumValues"][1],
+        json!({
+            "name": "USER",
+            "description": null,
+            "isDeprecated": true,
+            "deprecationReason": "Use MEMBER",
+        })
+    );
+    assert_eq!(
+        find_type(&result, "Url")["specifiedByURL"],
+        "https://url.spec.whatwg.org"
+    );
+    assert_eq!(find_type(&result, "Int")["specifiedByURL"], Value::Null);
+
+    let filter = find_type(&result, "UserFilter");
+    assert_eq!(filter["isOneOf"], false);
+    assert_eq!(filter["inputFields"][0]["defaultValue"], "ADMIN");
+    assert_eq!(filter["inputFields"][1]["defaultValue"], r#"["a"]"#);
+    assert_eq!(find_type(&result, "Lookup")["isOneOf"], true);
+    assert_eq!(find_type(&result, "Role")["isOneOf"], Value::Null);
+}
+
+#[test]
+fn introspect_directives() {
+    //* When
+    let result = introspect();
+
+    //* Then
+    let directives = result["__schema"]["directives"]
+        .as_array()
+        .expect("directives is a list");
+    let tag = directives
+        .iter()
+        .find(|directive| directive["name"] == "tag")
+        .expect("directive tag not found");
+    assert_eq!(
+        *tag,
+        json!({
+            "name": "tag",
+            "description": null,
+            "isRepeatable": true,
+            "locations": ["FIELD_DEFINITION", "OBJECT"],
+            "args": [{
+                "name": "name",
+                "description": null,
+                "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": { "kind": "SCALAR", "name": "String", "ofType": null },
+                },
+                "defaultValue": null,
+                "isDeprecated": false,
+                "deprecationReason": null,
+            }],
+        })
+    );
+    let deprecated = directives
+        .iter()
+        .find(|directive| directive["name"] == "deprecated")
+        .expect("directive deprecated not found");
+    assert_eq!(deprecated["isRepeatable"], false);
+    assert_eq!(
+        deprecated["args"][0]["defaultValue"],
+        r#""No longer supported""#
+    );
+}
+
+#[test]
+fn rebuild_schema_from_introspection_json() {
+    //* Given
+    let result = introspect();
+
+    //* When
+    let document = introspection_to_document(
+        &ConstValue::from_json(json!({ "data": result.clone() })).expect("valid JSON value"),
+    );
+
+    //* Then
+    assert_matches!(document, Ok(document) => {
+        let schema = Schema::from_document(document).expect("schema building failed");
+        let rebuilt = schema
+            .introspect()
+            .into_json()
+            .expect("introspection result is valid JSON");
+        assert_eq!(rebuilt, result);
+    });
+}
+
+#[test]
+fn restore_default_values_and_directives_from_introspection() {
+    //* Given
+    let result = ConstValue::from_json(introspect()).expect("valid JSON value");
+
+    //* When
+    let document = introspection_to_document(&result).expect("conversion failed");
+
+    //* Then
+    let schema = Schema::from_document(document).expect("schema building failed");
+    assert_matches!(schema.definition(), Some(definition) => {
+        assert_eq!(
+            definition.node.query.as_ref().map(|query| query.node.as_str()),
+            Some("Root")
+        );
+    });
+    assert_matches!(schema.get_input_field("UserFilter", "tags"), Some(field) => {
+        assert_matches!(&field.node.default_value, Some(value) => {
+            assert_eq!(value.node, ConstValue::List(vec![ConstValue::String("a".to_owned())]));
+        });
+    });
+    assert_matches!(schema.get_field("Root", "search"), Some(field) => {
+        assert_eq!(field.node.directives[0].node.name.node, "deprecated");
+        assert!(field.node.directives[0].node.arguments.is_empty());
+        let term = &field.node.arguments[0].node.directives[0].node;
+        assert_eq!(
+            term.get_argument("reason").map(|reason| &reason.node),
+            Some(&ConstValue::String("Use filter".to_owned()))
+        );
+    });
+    assert_matches!(schema.get_type("Url"), Some(url) => {
+        assert_eq!(url.node.directives[0].node.name.node, "specifiedBy");
+    });
+    assert_matches!(schema.get_type("Lookup"), Some(lookup) => {
+        assert_eq!(lookup.node.directives[0].node.name.node, "oneOf");
+    });
+    assert!(schema.get_type("__Type").is_none());
+}
+
+#[test]
+fn reject_invalid_introspection_results() {
+    //* Given
+    let missing_schema = ConstValue::from_json(json!({ "data": {} })).expect("valid JSON value");
+    let invalid_kind = ConstValue::from_json(json!({
+        "__schema": {
+            "queryType": { "name": "Query" },
+            "types": [{ "kind": "OBJECT", "name": "Query", "fields": [] }, { "kind": "TABLE", "name": "T" }],
+        },
+    }))
+    .expect("valid JSON value");
+    let invalid_default = ConstValue::from_json(json!({
+        "__schema": {
+            "queryType": { "name": "Query" },
+            "types": [{
+                "kind": "INPUT_OBJECT",
+                "name": "Filter",
+                "inputFields": [{
+                    "name": "limit",
+                    "type": { "kind": "SCALAR", "name": "Int" },
+                    "defaultValue": "$limit",
+                }],
+            }],
+        },
+    }))
+    .expect("valid JSON value");
+
+    //* Then
+    assert_matches!(introspection_to_document(&missing_schema), Err(err) => {
+        assert_eq!(err.to_string(), "missing field __schema");
+    });
+    assert_matches!(introspection_to_document(&invalid_kind), Err(err) => {
+        assert_eq!(err.to_string(), "unknown type kind TABLE at __schema.types[1].kind");
+    });
+    assert_matches!(introspection_to_document(&invalid_default), Err(err) => {
+        assert!(err.message.starts_with("invalid default value"));
+        assert_eq!(err.path, "__schema.types[0].inputFields[0].defaultValue");
+    });
+}
+
+#[test]
+fn print_default_introspection_query() {
+    //* When
+    let source = introspection_query_source(IntrospectionOptions::default());
+
+    //* Then
+    assert!(
+        source.starts_with("query IntrospectionQuery {\n  __schema {\n    queryType { name }\n")
+    );
+    assert!(source.contains("\nfragment InputValue on __InputValue {\n  name\n  description\n  type { ...TypeRef }\n  defaultValue\n}\n"));
+    for capability in [
+        "specifiedByURL",
+        "isRepeatable",
+        "isOneOf",
+        "includeDeprecated: true) { ...InputValue",
+    ] {
+        assert!(!source.contains(capability), "{} is requested", capability);
+    }
+}
+
+#[test]
+fn build_introspection_query_with_all_capabilities() {
+    //* Given
+    let options = IntrospectionOptions {
+        type_depth: 2,
+        ..IntrospectionOptions::all()
+    };
+
+    //* When
+    let document = introspection_query(options);
+    let source = introspection_query_source(options);
+
+    //* Then
+    assert_matches!(&document.operations, DocumentOperations::Multiple(operations) => {
+        assert!(operations.contains_key("IntrospectionQuery"));
+    });
+    let mut fragments = document
+        .fragments
+        .keys()
+        .map(Name::as_str)
+        .collect::<Vec<_>>();
+    fragments.sort_unstable();
+    assert_eq!(fragments, ["FullType", "InputValue", "TypeRef"]);
+    for capability in [
+        "__schema {\n    description\n",
+        "isRepeatable",
+        "specifiedByURL",
+        "isOneOf",
+        "inputFields(includeDeprecated: true) { ...InputValue }",
+        "  defaultValue\n  isDeprecated deprecationReason\n",
+        "kind name ofType { kind name ofType { kind name } }",
+    ] {
+        assert!(
+            source.contains(capability),
+            "{} is not requested",
+            capability
+        );
+    }
+}
+
+#[test]
+fn build_introspection_query_with_type_depth_over_the_limit() {
+    //* Given
+    let max_depth = IntrospectionOptions {
+        type_depth: IntrospectionOptions::MAX_TYPE_DEPTH,
+        ..IntrospectionOptions::default()
+    };
+    let over_max_depth = IntrospectionOptions {
+        type_depth: 70,
+        ..IntrospectionOptions::default()
+    };
+
+    //* When
+    let document = introspection_query(over_max_depth);
+    let source = introspection_query_source(over_max_depth);
+
+    //* Then
+    assert!(document.fragments.contains_key("TypeRef"));
+    assert_eq!(source, introspection_query_source(max_depth));
+    assert_eq!(
+        source.matches("ofType").count(),
+        IntrospectionOptions::MAX_TYPE_DEPTH
+    );
+}
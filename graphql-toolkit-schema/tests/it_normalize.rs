@@ -0,0 +1,221 @@
+use graphql_toolkit_ast::{
+    InputValueDefinition, Positioned, ServiceDocument, TypeKind, TypeSystemDefinition,
+};
+use graphql_toolkit_schema::{normalize_document, Error, NormalizeOptions};
+use indoc::indoc;
+
+/// Test helper function to parse and concatenate GraphQL SDL strings into a service document.
+///
+/// # Panics
+/// The function asserts that the inputs are valid SDL.
+fn parse_documents(inputs: &[&str]) -> ServiceDocument {
+    ServiceDocument {
+        definitions: inputs
+            .iter()
+            .flat_map(|input| {
+                graphql_toolkit_parser::parse_schema(input)
+                    .expect("schema parsing failed")
+                    .definitions
+            })
+            .collect(),
+    }
+}
+
+/// Test helper function to summarize the definitions of a service document, one line per
+/// definition, listing the names of its members in order.
+fn summary(document: &ServiceDocument) -> Vec<String> {
+    let input_values = |values: &[Positioned<InputValueDefinition>]| {
+        values
+            .iter()
+            .map(|value| value.node.name.node.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    document
+        .definitions
+        .iter()
+        .map(|def| match def {
+            TypeSystemDefinition::Schema(_) => "schema".to_owned(),
+            TypeSystemDefinition::Type(ty) => {
+                let name = &ty.node.name.node;
+                let members = match &ty.node.kind {
+                    TypeKind::Scalar => Vec::new(),
+                    TypeKind::Object(object) => object
+                        .implements
+                        .iter()
+                        .map(|name| format!("&{}", name.node))
+                        .chain(object.fields.iter().map(|field| {
+                            format!(
+                                "{}({})",
+                                field.node.name.node,
+                                input_values(&field.node.arguments)
+                            )
+                        }))
+                        .collect(),
+                    TypeKind::Interface(interface) => interface
+                        .implements
+                        .iter()
+                        .map(|name| format!("&{}", name.node))
+                        .chain(interface.fields.iter().map(|field| {
+                            format!(
+                                "{}({})",
+                                field.node.name.node,
+                                input_values(&field.node.arguments)
+                            )
+                        }))
+                        .collect(),
+                    TypeKind::Union(union) => union
+                        .members
+                        .iter()
+                        .map(|member| format!("|{}", member.node))
+                        .collect(),
+                    TypeKind::Enum(enum_type) => enum_type
+                        .values
+                        .iter()
+                        .map(|value| value.node.value.node.to_string())
+                        .collect(),
+                    TypeKind::InputObject(input) => vec![input_values(&input.fields)],
+                };
+                if members.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{} {}", name, members.join(" "))
+                }
+            }
+            TypeSystemDefinition::Directive(directive) => format!(
+                "@{}({})",
+                directive.node.name.node,
+                input_values(&directive.node.arguments)
+            ),
+        })
+        .collect()
+}
+
+#[test]
+fn sort_definitions_and_members_by_name() {
+    //* Given
+    let document = parse_documents(&[indoc! {r#"
+        directive @tag(name: String, extra: Int) on FIELD_DEFINITION
+        type Query { users(last: Int, first: Int): [User] node: Node }
+        type User implements Resource & Node { name: String id: ID! }
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! }
+        union Result = User | Query
+        enum Role { USER ADMIN }
+        input UserFilter { role: Role, name: String }
+        directive @auth on FIELD_DEFINITION
+        schema { query: Query }
+        scalar Date
+    "#}]);
+
+    //* When
+    let document =
+        normalize_document(document, NormalizeOptions::default()).expect("normalization failed");
+
+    //* Then
+    assert_eq!(
+        summary(&document),
+        [
+            "schema",
+            "Date",
+            "Node id()",
+            "Query node() users(first, last)",
+            "Resource &Node id()",
+            "Result |Query |User",
+            "Role ADMIN USER",
+            "User &Node &Resource id() name()",
+            "UserFilter name, role",
+            "@auth()",
+            "@tag(extra, name)",
+        ]
+    );
+}
+
+#[test]
+fn normalized_documents_do_not_depend_on_the_source_split() {
+    //* Given
+    let users = indoc! {r#"
+        type User { name: String }
+        extend type Query { user(id: ID!): User }
+    "#};
+    let posts = indoc! {r#"
+        extend type User { posts: [Post] }
+        type Post { title: String }
+    "#};
+    let query = "type Query { post: Post }";
+    let combined = indoc! {r#"
+        type Query { user(id: ID!): User post: Post }
+        type Post { title: String }
+        type User { posts: [Post] name: String }
+    "#};
+
+    //* When
+    let split = normalize_document(parse_documents(&[users, posts, query]), Default::default())
+        .expect("normalization failed");
+    let reordered = normalize_document(parse_documents(&[query, posts, users]), Default::default())
+        .expect("normalization failed");
+    let combined = normalize_document(parse_documents(&[combined]), Default::default())
+        .expect("normalization failed");
+
+    //* Then
+    assert_eq!(summary(&split), summary(&combined));
+    assert_eq!(summary(&reordered), summary(&combined));
+    assert!(split
+        .definitions
+        .iter()
+        .all(|def| !matches!(def, TypeSystemDefinition::Type(ty) if ty.node.extend)));
+}
+
+#[test]
+fn keep_field_order_when_requested() {
+    //* Given
+    let document = parse_documents(&[indoc! {r#"
+        type Query { users(last: Int, first: Int): [ID] count: Int }
+        input Filter { role: String, name: String }
+        extend type Query { active: Boolean }
+    "#}]);
+    let options = NormalizeOptions {
+        keep_field_order: true,
+    };
+
+    //* When
+    let document = normalize_document(document, options).expect("normalization failed");
+
+    //* Then
+    assert_eq!(
+        summary(&document),
+        [
+            "Filter role, name",
+            "Query users(first, last) count() active()",
+        ]
+    );
+}
+
+#[test]
+fn apply_schema_extensions_without_schema_definition() {
+    //* Given
+    let document = parse_documents(&[
+        r#"extend schema @link(url: "https://specs.apollo.dev/federation/v2.3")"#,
+        "type Query { id: ID }",
+    ]);
+
+    //* When
+    let document =
+        normalize_document(document, NormalizeOptions::default()).expect("normalization failed");
+
+    //* Then
+    assert_eq!(summary(&document), ["schema", "Query id()"]);
+}
+
+#[test]
+fn invalid_extensions_are_reported() {
+    //* Given
+    let document = parse_documents(&["extend type Query { a: Int }"]);
+
+    //* When
+    let result = normalize_document(document, NormalizeOptions::default());
+
+    //* Then
+    let errors = result.expect_err("normalization succeeded");
+    assert!(matches!(errors[..], [Error::ExtendedTypeNotDefined { .. }]));
+}
@@ -0,0 +1,304 @@
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{OperationType, TypeKind, TypeSystemDefinition};
+use graphql_toolkit_schema::{Error, Schema, BUILTIN_DIRECTIVES, BUILTIN_SCALARS};
+use indoc::indoc;
+
+/// Test helper function to parse a GraphQL SDL string and build a schema from it.
+///
+/// # Panics
+/// The function asserts that the input is valid SDL, and that the schema can be built.
+fn build_schema(input: &str) -> Schema {
+    let document = graphql_toolkit_parser::parse_schema(input).expect("schema parsing failed");
+    Schema::from_document(document).expect("schema building failed")
+}
+
+/// Test helper function to parse a GraphQL SDL string and return the schema build errors.
+fn build_errors(input: &str) -> Vec<Error> {
+    let document = graphql_toolkit_parser::parse_schema(input).expect("schema parsing failed");
+    Schema::from_document(document).expect_err("schema building succeeded")
+}
+
+#[test]
+fn lookup_types_and_fields_by_name() {
+    //* Given
+    let schema = build_schema(indoc! {r#"
+        type Query { user(id: ID!): User }
+        type User { id: ID! name: String }
+        input UserFilter { name: String }
+    "#});
+
+    //* When
+    let user = schema.get_type("User");
+    let field = schema.get_field("Query", "user");
+    let input_field = schema.get_input_field("UserFilter", "name");
+
+    //* Then
+    assert_matches!(user, Some(ty) => {
+        assert_matches!(&ty.node.kind, TypeKind::Object(object) => {
+            assert_eq!(object.fields.len(), 2);
+        });
+    });
+    assert_matches!(field, Some(field) => {
+        assert_eq!(field.node.ty.node.to_string(), "User");
+        assert_eq!(field.node.arguments[0].node.name.node, "id");
+    });
+    assert!(input_field.is_some());
+    assert!(schema.get_type("Unknown").is_none());
+    assert!(schema.get_field("User", "unknown").is_none());
+}
+
+#[test]
+fn root_types_default_to_conventional_names() {
+    //* Given
+    let schema = build_schema(indoc! {r#"
+        type Query { a: Int }
+        type Mutation { b: Int }
+    "#});
+
+    //* Then
+    assert_eq!(
+        schema
+            .root_type_name(OperationType::Query)
+            .map(|n| n.as_str()),
+        Some("Query")
+    );
+    assert_eq!(
+        schema
+            .root_type_name(OperationType::Mutation)
+            .map(|n| n.as_str()),
+        Some("Mutation")
+    );
+    assert!(schema.subscription_type().is_none());
+}
+
+#[test]
+fn root_types_come_from_the_schema_definition() {
+    //* Given
+    let schema = build_schema(indoc! {r#"
+        schema { query: RootQuery subscription: RootSubscription }
+        type RootQuery { a: Int }
+        type RootSubscription { b: Int }
+        type Mutation { c: Int }
+    "#});
+
+    //* Then
+    assert_matches!(schema.query_type(), Some(ty) => {
+        assert_eq!(ty.node.name.node, "RootQuery");
+    });
+    assert_matches!(schema.subscription_type(), Some(ty) => {
+        assert_eq!(ty.node.name.node, "RootSubscription");
+    });
+    assert!(schema.mutation_type().is_none());
+}
+
+#[test]
+fn possible_types_of_abstract_types() {
+    //* Given
+    let schema = build_schema(indoc! {r#"
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! url: String }
+        type User implements Node { id: ID! }
+        type Image implements Resource & Node { id: ID! url: String }
+        union SearchResult = User | Image
+    "#});
+
+    //* Then
+    let node: Vec<_> = schema.possible_types("Node").map(|n| n.as_str()).collect();
+    assert_eq!(node, ["User", "Image"]);
+
+    let resource: Vec<_> = schema
+        .possible_types("Resource")
+        .map(|n| n.as_str())
+        .collect();
+    assert_eq!(resource, ["Image"]);
+
+    let search: Vec<_> = schema
+        .possible_types("SearchResult")
+        .map(|n| n.as_str())
+        .collect();
+    assert_eq!(search, ["User", "Image"]);
+
+    let implementations: Vec<_> = schema.implementations("Node").map(|n| n.as_str()).collect();
+    assert_eq!(implementations, ["Resource", "User", "Image"]);
+
+    assert!(schema.is_possible_type("User", "User"));
+    assert!(schema.is_possible_type("SearchResult", "Image"));
+    assert!(!schema.is_possible_type("Resource", "User"));
+}
+
+#[test]
+fn builtin_scalars_and_directives_are_registered() {
+    //* Given
+    let schema = build_schema("type Query { a: Int }");
+
+    //* Then
+    for scalar in BUILTIN_SCALARS {
+        assert_matches!(schema.get_type(scalar), Some(ty) => {
+            assert_matches!(ty.node.kind, TypeKind::Scalar);
+            assert!(ty.node.description.is_some());
+        });
+    }
+    for directive in BUILTIN_DIRECTIVES {
+        assert!(schema.get_directive(directive).is_some());
+    }
+}
+
+#[test]
+fn builtin_definitions_can_be_overridden() {
+    //* Given
+    let schema = build_schema(indoc! {r#"
+        "A custom description."
+        scalar String
+        directive @deprecated(reason: String) on FIELD_DEFINITION
+    "#});
+
+    //* Then
+    assert_matches!(schema.get_type("String"), Some(ty) => {
+        assert_eq!(ty.node.description.as_ref().unwrap().node, "A custom description.");
+    });
+    assert_matches!(schema.get_directive("deprecated"), Some(directive) => {
+        assert_eq!(directive.node.locations.len(), 1);
+    });
+}
+
+#[test]
+fn duplicated_definitions_are_reported() {
+    //* Given
+    let errors = build_errors(indoc! {r#"
+        type Query { a: Int }
+        type Query { b: Int }
+        directive @tag on FIELD
+        directive @tag on FIELD
+        scalar Int
+        scalar Int
+    "#});
+
+    //* Then
+    assert_eq!(errors.len(), 3);
+    assert_matches!(&errors[0], Error::TypeDuplicated { name, first, second } => {
+        assert_eq!(name, "Query");
+        assert_eq!(first.line, 1);
+        assert_eq!(second.line, 2);
+    });
+    assert_matches!(&errors[1], Error::DirectiveDuplicated { name, .. } => {
+        assert_eq!(name, "tag");
+    });
+    assert_matches!(&errors[2], Error::TypeDuplicated { name, .. } => {
+        assert_eq!(name, "Int");
+    });
+}
+
+#[test]
+fn invalid_root_types_are_reported() {
+    //* Given
+    let errors = build_errors(indoc! {r#"
+        schema { query: Query mutation: Mutation }
+        interface Query { a: Int }
+    "#});
+
+    //* Then
+    assert_eq!(errors.len(), 2);
+    assert_matches!(&errors[0], Error::RootTypeNotObject { root: OperationType::Query, name, .. } => {
+        assert_eq!(name, "Query");
+    });
+    assert_matches!(&errors[1], Error::UnknownRootType { root: OperationType::Mutation, name, .. } => {
+        assert_eq!(name, "Mutation");
+    });
+}
+
+#[test]
+fn documents_are_combined() {
+    //* Given
+    let users = graphql_toolkit_parser::parse_schema("type User { id: ID! }").unwrap();
+    let query = graphql_toolkit_parser::parse_schema("type Query { user: User }").unwrap();
+
+    //* When
+    let schema = Schema::builder()
+        .add_document(users)
+        .add_document(query)
+        .build()
+        .expect("schema building failed");
+
+    //* Then
+    assert!(schema.query_type().is_some());
+    assert!(schema.get_type("User").is_some());
+}
+
+#[test]
+fn builtin_definitions_can_be_disabled() {
+    //* Given
+    let document = graphql_toolkit_parser::parse_schema("type Query { a: Boolean }").unwrap();
+
+    //* When
+    let schema = Schema::builder()
+        .without_builtins()
+        .add_document(document)
+        .build()
+        .expect("schema building failed");
+
+    //* Then
+    assert!(schema.get_type("Boolean").is_none());
+    assert!(schema.get_directive("skip").is_none());
+    assert_eq!(schema.types().count(), 1);
+}
+
+#[test]
+fn builtin_definitions_can_be_replaced() {
+    //* Given
+    let builtins = graphql_toolkit_parser::parse_schema(indoc! {r#"
+        scalar Boolean
+        directive @skip(if: Boolean!) on FIELD
+    "#})
+    .unwrap();
+    let document = graphql_toolkit_parser::parse_schema("type Query { a: Boolean }").unwrap();
+
+    //* When
+    let schema = Schema::builder()
+        .builtins(builtins)
+        .add_document(document)
+        .build()
+        .expect("schema building failed");
+
+    //* Then
+    assert!(schema.get_type("Int").is_none());
+    assert!(schema.is_builtin_type("Boolean"));
+    assert!(schema.is_builtin_directive("skip"));
+    assert!(!schema.is_builtin_directive("include"));
+}
+
+#[test]
+fn builtin_definitions_are_omitted_from_documents() {
+    //* Given
+    let schema = build_schema(indoc! {r#"
+        type Query { a: Int }
+        "A custom description."
+        scalar String
+        extend type Query { b: String }
+    "#});
+
+    //* When
+    let document = schema.to_document();
+    let document_with_builtins = schema.to_document_with_builtins();
+
+    //* Then
+    let names = document
+        .definitions
+        .iter()
+        .map(|def| match def {
+            TypeSystemDefinition::Type(ty) => ty.node.name.node.as_str(),
+            _ => unreachable!("only types are defined"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["String", "Query"]);
+    assert_matches!(&document.definitions[1], TypeSystemDefinition::Type(ty) => {
+        assert_matches!(&ty.node.kind, TypeKind::Object(object) => {
+            assert_eq!(object.fields.len(), 2);
+        });
+    });
+    assert!(!schema.is_builtin_type("String"));
+    assert!(schema.is_builtin_type("Int"));
+    assert_eq!(
+        document_with_builtins.definitions.len(),
+        BUILTIN_SCALARS.len() + BUILTIN_DIRECTIVES.len() + 1
+    );
+}
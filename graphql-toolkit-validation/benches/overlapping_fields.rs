@@ -0,0 +1,124 @@
+//! Benchmarks of the field merging validation on pathological documents.
+
+use std::fmt::Write;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use graphql_toolkit_ast::ExecutableDocument;
+use graphql_toolkit_schema::Schema;
+use graphql_toolkit_validation::validate_executable_document_with_schema;
+
+const SCHEMA: &str = r#"
+    type Query { user(id: ID): User }
+    type User { id: ID! name: String friends(first: Int): [User!]! }
+"#;
+
+fn schema() -> Schema {
+    let document = graphql_toolkit_parser::parse_schema(SCHEMA).expect("schema parsing failed");
+    Schema::from_document(document).expect("schema build failed")
+}
+
+fn parse(query: &str) -> ExecutableDocument {
+    graphql_toolkit_parser::parse_query(query).expect("query parsing failed")
+}
+
+/// The same field selected many times with the same response key.
+fn repeated_fields(size: usize) -> ExecutableDocument {
+    parse(&format!("{{ user {{ {} }} }}", "name ".repeat(size)))
+}
+
+/// Many different fields aliased to the same response key, all of them conflicting.
+fn wide_aliasing(size: usize) -> ExecutableDocument {
+    let fields = (0..size)
+        .map(|i| if i % 2 == 0 { "key: name " } else { "key: id " })
+        .collect::<String>();
+    parse(&format!("{{ user {{ {} }} }}", fields))
+}
+
+/// Many fragments selecting the same fields, all spread in the same selection set.
+fn many_fragment_spreads(size: usize) -> ExecutableDocument {
+    let mut query = String::from("{ user { ");
+    for i in 0..size {
+        write!(query, "...f{} ", i).unwrap();
+    }
+    query.push_str("} }\n");
+    for i in 0..size {
+        writeln!(
+            query,
+            "fragment f{} on User {{ name friends(first: 1) {{ name }} }}",
+            i
+        )
+        .unwrap();
+    }
+    parse(&query)
+}
+
+/// A chain of fragments, each spreading the next one twice, through different fields.
+fn nested_fragment_spreads(size: usize) -> ExecutableDocument {
+    let mut query = String::from("{ user { ...f0 } }\n");
+    for i in 0..size {
+        write!(query, "fragment f{} on User {{ name ", i).unwrap();
+        if i + 1 < size {
+            write!(
+                query,
+                "friends {{ ...f{next} }} friends {{ ...f{next} }} ",
+                next = i + 1
+            )
+            .unwrap();
+        }
+        query.push_str("}\n");
+    }
+    parse(&query)
+}
+
+/// Benchmark the validation of the documents built with the given sizes.
+fn bench_documents(
+    c: &mut Criterion,
+    name: &str,
+    document: fn(usize) -> ExecutableDocument,
+    sizes: &[usize],
+) {
+    let schema = schema();
+    let mut group = c.benchmark_group(name);
+    for &size in sizes {
+        let document = document(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &document, |b, doc| {
+            b.iter(|| validate_executable_document_with_schema(&schema, doc));
+        });
+    }
+    group.finish();
+}
+
+fn bench_repeated_fields(c: &mut Criterion) {
+    bench_documents(c, "repeated_fields", repeated_fields, &[100, 1000]);
+}
+
+fn bench_wide_aliasing(c: &mut Criterion) {
+    bench_documents(c, "wide_aliasing", wide_aliasing, &[100, 1000]);
+}
+
+fn bench_many_fragment_spreads(c: &mut Criterion) {
+    bench_documents(
+        c,
+        "many_fragment_spreads",
+        many_fragment_spreads,
+        &[100, 500],
+    );
+}
+
+fn bench_nested_fragment_spreads(c: &mut Criterion) {
+    bench_documents(
+        c,
+        "nested_fragment_spreads",
+        nested_fragment_spreads,
+        &[10, 50],
+    );
+}
+
+criterion_group!(
+    benches,
+    bench_repeated_fields,
+    bench_wide_aliasing,
+    bench_many_fragment_spreads,
+    bench_nested_fragment_spreads
+);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+use std::fmt::{self, Display, Formatter};
+
+use graphql_toolkit_ast::Pos;
+
+/// Validation error.
+///
+/// A violation of a validation rule, with the positions of the offending nodes in the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// The message of the error.
+    pub message: String,
+    /// The positions of the error, ordered from most important to least important.
+    pub locations: Vec<Pos>,
+}
+
+impl Error {
+    /// Create a new validation error.
+    pub fn new(message: impl Into<String>, locations: impl IntoIterator<Item = Pos>) -> Self {
+        Self {
+            message: message.into(),
+            locations: locations.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<graphql_toolkit_schema::Error> for Error {
+    fn from(err: graphql_toolkit_schema::Error) -> Self {
+        Self::new(err.to_string(), err.positions())
+    }
+}
@@ -0,0 +1,76 @@
+//! Executable document validation.
+//!
+//! The operations, fragments and input object fields with clashing names cannot be held by the
+//! [`ExecutableDocument`] maps. They are recorded in [`ExecutableDocument::name_clashes`]
+//! instead, and the uniqueness rules report them. The parser records the input object fields,
+//! and rejects the clashing operations and fragments.
+//!
+//! [Reference](https://spec.graphql.org/October2021/#sec-Validation).
+
+use graphql_toolkit_ast::ExecutableDocument;
+use graphql_toolkit_schema::Schema;
+
+use crate::{
+    error::Error,
+    rules,
+    visitor::{visit, Visitor, VisitorContext, VisitorNil},
+};
+
+/// Validate the executable document, with the rules that need no schema.
+///
+/// # Errors
+///
+/// Fails with all the violations found in the document.
+pub fn validate_executable_document(document: &ExecutableDocument) -> Result<(), Vec<Error>> {
+    let mut ctx = VisitorContext::new(None, document);
+    let mut visitor = schema_independent_rules();
+
+    visit(&mut visitor, &mut ctx, document);
+
+    ctx.into_result()
+}
+
+/// Validate the executable document against the schema, with all the rules.
+///
+/// # Errors
+///
+/// Fails with all the violations found in the document.
+pub fn validate_executable_document_with_schema(
+    schema: &Schema,
+    document: &ExecutableDocument,
+) -> Result<(), Vec<Error>> {
+    let mut ctx = VisitorContext::new(Some(schema), document);
+    let mut visitor = schema_independent_rules()
+        .with(rules::FieldsOnCorrectType)
+        .with(rules::KnownArgumentNames)
+        .with(rules::ProvidedRequiredArguments)
+        .with(rules::ScalarLeafs)
+        .with(rules::KnownTypeNames)
+        .with(rules::FragmentsOnCompositeTypes)
+        .with(rules::PossibleFragmentSpreads)
+        .with(rules::VariablesAreInputTypes)
+        .with(rules::OverlappingFieldsCanBeMerged::default())
+        .with(rules::ValuesOfCorrectType)
+        .with(rules::VariablesInAllowedPosition::default())
+        .with(rules::KnownDirectives)
+        .with(rules::UniqueDirectivesPerLocation);
+
+    visit(&mut visitor, &mut ctx, document);
+
+    ctx.into_result()
+}
+
+fn schema_independent_rules<'a>() -> impl Visitor<'a> {
+    VisitorNil
+        .with(rules::UniqueOperationNames)
+        .with(rules::LoneAnonymousOperation)
+        .with(rules::UniqueFragmentNames)
+        .with(rules::KnownFragmentNames)
+        .with(rules::NoUnusedFragments)
+        .with(rules::NoFragmentCycles::default())
+        .with(rules::UniqueVariableNames::default())
+        .with(rules::NoUndefinedVariables)
+        .with(rules::NoUnusedVariables)
+        .with(rules::UniqueArgumentNames::default())
+        .with(rules::UniqueInputFieldNames)
+}
@@ -0,0 +1,15 @@
+//! GraphQL document validation
+#![warn(missing_docs)]
+#![allow(clippy::uninlined_format_args)]
+#![forbid(unsafe_code)]
+
+pub use error::Error;
+pub use executable::{validate_executable_document, validate_executable_document_with_schema};
+pub use type_system::{validate_schema, validate_service_document};
+
+mod error;
+mod executable;
+mod rules;
+mod type_system;
+mod utils;
+mod visitor;
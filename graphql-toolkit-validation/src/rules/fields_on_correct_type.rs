@@ -0,0 +1,38 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Field-Selections).
+
+use graphql_toolkit_ast::{Field, OperationType, Positioned};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The fields selected must be defined on the type they are selected on.
+///
+/// The `__typename` meta-field can be selected on any type, and the `__schema` and `__type`
+/// meta-fields on the query root type.
+#[derive(Default)]
+pub(crate) struct FieldsOnCorrectType;
+
+impl<'a> Visitor<'a> for FieldsOnCorrectType {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let (Some(schema), Some(parent_type)) = (ctx.schema, ctx.parent_type()) else {
+            return;
+        };
+        if ctx.field_definition().is_some() {
+            return;
+        }
+
+        let name = &field.node.name.node;
+        let is_query_root =
+            schema.root_type_name(OperationType::Query) == Some(&parent_type.node.name.node);
+        if name == "__typename" || is_query_root && (name == "__schema" || name == "__type") {
+            return;
+        }
+
+        ctx.report(
+            [field.pos],
+            format!(
+                "unknown field {} on type {}",
+                name, parent_type.node.name.node
+            ),
+        );
+    }
+}
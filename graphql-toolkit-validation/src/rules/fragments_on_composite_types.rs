@@ -0,0 +1,55 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Fragments-On-Composite-Types).
+
+use graphql_toolkit_ast::{FragmentDefinition, InlineFragment, Name, Positioned};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The type conditions of fragments must refer to object, interface or union types.
+#[derive(Default)]
+pub(crate) struct FragmentsOnCompositeTypes;
+
+impl<'a> Visitor<'a> for FragmentsOnCompositeTypes {
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+        let Some(schema) = ctx.schema else {
+            return;
+        };
+
+        let on = &fragment.node.type_condition.node.on;
+        if schema.get_type(&on.node).is_some() && !schema.is_composite_type(&on.node) {
+            ctx.report(
+                [on.pos],
+                format!(
+                    "fragment {} cannot condition on non-composite type {}",
+                    name, on.node
+                ),
+            );
+        }
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        let (Some(schema), Some(condition)) = (ctx.schema, &inline_fragment.node.type_condition)
+        else {
+            return;
+        };
+
+        let on = &condition.node.on;
+        if schema.get_type(&on.node).is_some() && !schema.is_composite_type(&on.node) {
+            ctx.report(
+                [on.pos],
+                format!(
+                    "inline fragment cannot condition on non-composite type {}",
+                    on.node
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Argument-Names).
+
+use graphql_toolkit_ast::{Directive, Field, Positioned};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The arguments provided to a field or directive must be defined by the field or directive.
+#[derive(Default)]
+pub(crate) struct KnownArgumentNames;
+
+impl<'a> Visitor<'a> for KnownArgumentNames {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let (Some(parent_type), Some(definition)) = (ctx.parent_type(), ctx.field_definition())
+        else {
+            return;
+        };
+
+        for (name, _) in &field.node.arguments {
+            if definition
+                .node
+                .arguments
+                .iter()
+                .all(|arg| arg.node.name.node != name.node)
+            {
+                ctx.report(
+                    [name.pos],
+                    format!(
+                        "unknown argument {} on field {}.{}",
+                        name.node, parent_type.node.name.node, definition.node.name.node
+                    ),
+                );
+            }
+        }
+    }
+
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+        let Some(definition) = ctx.directive_definition() else {
+            return;
+        };
+
+        for (name, _) in &directive.node.arguments {
+            if definition
+                .node
+                .arguments
+                .iter()
+                .all(|arg| arg.node.name.node != name.node)
+            {
+                ctx.report(
+                    [name.pos],
+                    format!(
+                        "unknown argument {} on directive @{}",
+                        name.node, definition.node.name.node
+                    ),
+                );
+            }
+        }
+    }
+}
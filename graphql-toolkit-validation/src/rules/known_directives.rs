@@ -0,0 +1,42 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Directives-Are-Defined).
+
+use graphql_toolkit_ast::{Directive, Positioned};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The directives used must be defined in the schema, and used in one of the locations of their
+/// definition.
+#[derive(Default)]
+pub(crate) struct KnownDirectives;
+
+impl<'a> Visitor<'a> for KnownDirectives {
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+        if ctx.schema.is_none() {
+            return;
+        }
+
+        let name = &directive.node.name.node;
+        let Some(definition) = ctx.directive_definition() else {
+            ctx.report([directive.pos], format!("unknown directive @{}", name));
+            return;
+        };
+
+        let Some(location) = ctx.directive_location() else {
+            return;
+        };
+        if definition.node.locations.iter().all(|l| l.node != location) {
+            ctx.report(
+                [directive.pos],
+                format!(
+                    "directive @{} may not be used on {}",
+                    name,
+                    location.as_str()
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Fragment-spread-target-defined).
+
+use graphql_toolkit_ast::{FragmentSpread, Positioned};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The target fragment of every fragment spread must be defined in the document.
+#[derive(Default)]
+pub(crate) struct KnownFragmentNames;
+
+impl<'a> Visitor<'a> for KnownFragmentNames {
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+        let name = &fragment_spread.node.fragment_name;
+        if ctx.fragment(&name.node).is_none() {
+            ctx.report([name.pos], format!("unknown fragment {}", name.node));
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Fragment-Spread-Type-Existence).
+
+use graphql_toolkit_ast::{
+    FragmentDefinition, InlineFragment, Name, Pos, Positioned, VariableDefinition,
+};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The types referred to by variable definitions and type conditions must be defined in the
+/// schema.
+#[derive(Default)]
+pub(crate) struct KnownTypeNames;
+
+impl<'a> Visitor<'a> for KnownTypeNames {
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: &'a Name,
+        fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+        let on = &fragment.node.type_condition.node.on;
+        check_type(ctx, &on.node, on.pos);
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        let ty = &variable_definition.node.var_type;
+        check_type(ctx, ty.node.named_type(), ty.pos);
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        if let Some(condition) = &inline_fragment.node.type_condition {
+            check_type(ctx, &condition.node.on.node, condition.node.on.pos);
+        }
+    }
+}
+
+fn check_type(ctx: &mut VisitorContext<'_>, name: &Name, pos: Pos) {
+    let Some(schema) = ctx.schema else {
+        return;
+    };
+    if schema.get_type(name).is_none() {
+        ctx.report([pos], format!("unknown type {}", name));
+    }
+}
@@ -0,0 +1,29 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Lone-Anonymous-Operation).
+
+use graphql_toolkit_ast::{ExecutableDocument, NameClash};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// An anonymous operation must be the only operation of the document.
+///
+/// Only the operation defined first is kept, and the others are name clashes. The parser rejects
+/// them, but the documents built otherwise can record them.
+#[derive(Default)]
+pub(crate) struct LoneAnonymousOperation;
+
+impl<'a> Visitor<'a> for LoneAnonymousOperation {
+    fn enter_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
+        for clash in &doc.name_clashes {
+            if let NameClash::AnonymousOperation {
+                anonymous,
+                operation,
+            } = clash
+            {
+                ctx.report(
+                    [*anonymous, *operation],
+                    "anonymous operation must be the only operation in the document",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Fragment-spreads-must-not-form-cycles).
+
+use std::collections::{HashMap, HashSet};
+
+use graphql_toolkit_ast::{ExecutableDocument, FragmentSpread, Name, Positioned};
+
+use crate::{
+    utils::fragment_spreads,
+    visitor::{Visitor, VisitorContext},
+};
+
+/// The fragment spreads must not form cycles, including spreading a fragment within itself.
+#[derive(Default)]
+pub(crate) struct NoFragmentCycles<'a> {
+    visited: HashSet<&'a Name>,
+    spread_path: Vec<&'a Positioned<FragmentSpread>>,
+    spread_path_index: HashMap<&'a Name, usize>,
+}
+
+impl<'a> Visitor<'a> for NoFragmentCycles<'a> {
+    fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
+        // Each fragment is only explored once, so every cycle is reported a single time.
+        for (name, _) in ctx.document.fragments_in_order() {
+            self.detect_cycles(ctx, name);
+        }
+    }
+}
+
+impl<'a> NoFragmentCycles<'a> {
+    fn detect_cycles(&mut self, ctx: &mut VisitorContext<'a>, name: &'a Name) {
+        if !self.visited.insert(name) {
+            return;
+        }
+        let Some(fragment) = ctx.fragment(name) else {
+            return;
+        };
+
+        self.spread_path_index.insert(name, self.spread_path.len());
+
+        for spread in fragment_spreads(&fragment.node.selection_set) {
+            let target = &spread.node.fragment_name.node;
+            self.spread_path.push(spread);
+
+            match self.spread_path_index.get(target) {
+                Some(&index) => {
+                    let cycle = &self.spread_path[index..];
+                    let via = cycle[..cycle.len() - 1]
+                        .iter()
+                        .map(|s| s.node.fragment_name.node.as_str())
+                        .collect::<Vec<_>>();
+                    let message = if via.is_empty() {
+                        format!("cannot spread fragment {} within itself", target)
+                    } else {
+                        format!(
+                            "cannot spread fragment {} within itself via {}",
+                            target,
+                            via.join(", ")
+                        )
+                    };
+                    ctx.report(cycle.iter().map(|s| s.pos), message);
+                }
+                None => self.detect_cycles(ctx, target),
+            }
+
+            self.spread_path.pop();
+        }
+
+        self.spread_path_index.remove(name);
+    }
+}
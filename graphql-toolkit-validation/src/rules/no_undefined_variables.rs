@@ -0,0 +1,52 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-All-Variable-Uses-Defined).
+
+use std::collections::HashSet;
+
+use graphql_toolkit_ast::{Name, OperationDefinition, Positioned};
+
+use crate::{
+    utils::operation_variable_usages,
+    visitor::{Visitor, VisitorContext},
+};
+
+/// Every variable used by an operation, directly or through the fragments it spreads, must be
+/// defined by the operation.
+#[derive(Default)]
+pub(crate) struct NoUndefinedVariables;
+
+impl<'a> Visitor<'a> for NoUndefinedVariables {
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+        let defined = operation
+            .node
+            .variable_definitions
+            .iter()
+            .map(|def| &def.node.name.node)
+            .collect::<HashSet<_>>();
+
+        let mut reported = HashSet::new();
+        let usages = operation_variable_usages(
+            ctx.document,
+            &operation.node.directives,
+            &operation.node.selection_set,
+        );
+        for (variable, pos) in usages {
+            if defined.contains(variable) || !reported.insert(variable) {
+                continue;
+            }
+
+            let message = match name {
+                Some(name) => format!(
+                    "variable ${} is not defined by operation {}",
+                    variable, name
+                ),
+                None => format!("variable ${} is not defined", variable),
+            };
+            ctx.report([pos, operation.pos], message);
+        }
+    }
+}
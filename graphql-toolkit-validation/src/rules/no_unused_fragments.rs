@@ -0,0 +1,30 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Fragments-Must-Be-Used).
+
+use graphql_toolkit_ast::{indexmap::IndexSet, ExecutableDocument};
+
+use crate::{
+    utils::referenced_fragments,
+    visitor::{Visitor, VisitorContext},
+};
+
+/// Every fragment must be spread, directly or through other fragments, by an operation.
+#[derive(Default)]
+pub(crate) struct NoUnusedFragments;
+
+impl<'a> Visitor<'a> for NoUnusedFragments {
+    fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
+        let mut used = IndexSet::new();
+        for (_, operation) in ctx.document.operations.in_order() {
+            used.extend(referenced_fragments(
+                ctx.document,
+                &operation.node.selection_set,
+            ));
+        }
+
+        for (name, fragment) in ctx.document.fragments_in_order() {
+            if !used.contains(name) {
+                ctx.report([fragment.pos], format!("fragment {} is never used", name));
+            }
+        }
+    }
+}
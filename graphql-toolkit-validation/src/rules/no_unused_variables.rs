@@ -0,0 +1,46 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-All-Variables-Used).
+
+use std::collections::HashSet;
+
+use graphql_toolkit_ast::{Name, OperationDefinition, Positioned};
+
+use crate::{
+    utils::operation_variable_usages,
+    visitor::{Visitor, VisitorContext},
+};
+
+/// Every variable defined by an operation must be used by the operation, directly or through the
+/// fragments it spreads.
+#[derive(Default)]
+pub(crate) struct NoUnusedVariables;
+
+impl<'a> Visitor<'a> for NoUnusedVariables {
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+        let used = operation_variable_usages(
+            ctx.document,
+            &operation.node.directives,
+            &operation.node.selection_set,
+        )
+        .into_iter()
+        .map(|(variable, _)| variable)
+        .collect::<HashSet<_>>();
+
+        for def in &operation.node.variable_definitions {
+            let variable = &def.node.name.node;
+            if used.contains(variable) {
+                continue;
+            }
+
+            let message = match name {
+                Some(name) => format!("variable ${} is never used in operation {}", variable, name),
+                None => format!("variable ${} is never used", variable),
+            };
+            ctx.report([def.pos], message);
+        }
+    }
+}
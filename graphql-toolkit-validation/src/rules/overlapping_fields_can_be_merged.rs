@@ -0,0 +1,577 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Field-Selection-Merging).
+//!
+//! A naive implementation compares every pair of fields of a selection set, after expanding all
+//! the fragments, which is quadratic in the size of the expanded selection set, and exponential
+//! in the depth of fragment nesting. This implementation follows the optimized algorithm of
+//! `graphql-js`:
+//!
+//! - The fields and fragment names of each selection set are collected once, and cached.
+//! - The fragments are not expanded. The fields of a selection set are compared to the fields of
+//!   each fragment it spreads, and the fields of each pair of fragments are compared, each pair
+//!   being compared once.
+//! - The subselections of two fields are only compared when the fields have the same response
+//!   key, and the comparison keeps track of whether the parent fields are mutually exclusive.
+
+use std::{collections::HashMap, hash::Hash, rc::Rc};
+
+use graphql_toolkit_ast::{
+    indexmap::{IndexMap, IndexSet},
+    BaseType, Field, FieldDefinition, Name, Pos, Positioned, Selection, SelectionSet, Type,
+    TypeDefinition, TypeKind,
+};
+use graphql_toolkit_schema::Schema;
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The fields selected with the same response key must be mergeable: they must select the same
+/// field with the same arguments, unless their parent types are different object types, and
+/// must return compatible types.
+#[derive(Default)]
+pub(crate) struct OverlappingFieldsCanBeMerged<'a> {
+    cached_fields_and_fragment_names: HashMap<*const SelectionSet, Rc<FieldsAndFragmentNames<'a>>>,
+    compared_fragment_pairs: ComparisonSet<(&'a Name, &'a Name)>,
+    compared_fields_and_fragment_pairs: ComparisonSet<(*const SelectionSet, &'a Name)>,
+}
+
+impl<'a> Visitor<'a> for OverlappingFieldsCanBeMerged<'a> {
+    fn enter_selection_set(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        selection_set: &'a Positioned<SelectionSet>,
+    ) {
+        let Some(schema) = ctx.schema else {
+            return;
+        };
+
+        let conflicts = Finder {
+            schema,
+            ctx,
+            cache: self,
+        }
+        .find_conflicts_within_selection_set(ctx.parent_type(), selection_set);
+
+        for conflict in conflicts {
+            let locations = conflict
+                .fields1
+                .iter()
+                .chain(&conflict.fields2)
+                .copied()
+                .collect::<Vec<_>>();
+            ctx.report(
+                locations,
+                format!(
+                    "fields {} conflict because {}; use different aliases on the fields to fetch \
+                     both if this was intentional",
+                    conflict.response_key, conflict.reason
+                ),
+            );
+        }
+    }
+}
+
+/// A field selected in a selection set, with its parent type and definition, if known.
+#[derive(Clone, Copy)]
+struct FieldInfo<'a> {
+    parent_type: Option<&'a Positioned<TypeDefinition>>,
+    field: &'a Positioned<Field>,
+    definition: Option<&'a Positioned<FieldDefinition>>,
+}
+
+/// The fields of a selection set, by response key, and the fragments it spreads.
+///
+/// The fields of the inline fragments are included, but the spread fragments are not expanded.
+struct FieldsAndFragmentNames<'a> {
+    fields: IndexMap<&'a Name, Vec<FieldInfo<'a>>>,
+    fragment_names: IndexSet<&'a Name>,
+}
+
+/// A conflict between fields with the same response key.
+struct Conflict<'a> {
+    response_key: &'a Name,
+    reason: Reason<'a>,
+    fields1: Vec<Pos>,
+    fields2: Vec<Pos>,
+}
+
+enum Reason<'a> {
+    Message(String),
+    Subfields(Vec<(&'a Name, Reason<'a>)>),
+}
+
+impl std::fmt::Display for Reason<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Message(message) => f.write_str(message),
+            Self::Subfields(reasons) => {
+                for (i, (response_key, reason)) in reasons.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" and ")?;
+                    }
+                    write!(f, "subfields {} conflict because {}", response_key, reason)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A set of compared pairs, recording whether the pair was compared with mutually exclusive
+/// parent fields.
+struct ComparisonSet<K> {
+    data: HashMap<K, bool>,
+}
+
+impl<K> Default for ComparisonSet<K> {
+    fn default() -> Self {
+        Self {
+            data: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> ComparisonSet<K> {
+    /// Record the comparison of the pair, returning whether it was already compared.
+    ///
+    /// A comparison without mutually exclusive parents covers a comparison with mutually
+    /// exclusive parents, but not the other way around.
+    fn check_and_insert(&mut self, key: K, are_mutually_exclusive: bool) -> bool {
+        match self.data.get(&key) {
+            Some(&mutually_exclusive) if are_mutually_exclusive || !mutually_exclusive => true,
+            _ => {
+                self.data.insert(key, are_mutually_exclusive);
+                false
+            }
+        }
+    }
+}
+
+struct Finder<'a, 'r> {
+    schema: &'a Schema,
+    ctx: &'r VisitorContext<'a>,
+    cache: &'r mut OverlappingFieldsCanBeMerged<'a>,
+}
+
+impl<'a, 'r> Finder<'a, 'r> {
+    /// Find the conflicts between the fields of the selection set, including the fields of the
+    /// fragments it spreads.
+    fn find_conflicts_within_selection_set(
+        &mut self,
+        parent_type: Option<&'a Positioned<TypeDefinition>>,
+        selection_set: &'a Positioned<SelectionSet>,
+    ) -> Vec<Conflict<'a>> {
+        let mut conflicts = Vec::new();
+        if selection_set.node.items.is_empty() {
+            return conflicts;
+        }
+
+        let fields_and_fragments = self.fields_and_fragment_names(parent_type, selection_set);
+        let fields = &fields_and_fragments.fields;
+        let fragment_names = &fields_and_fragments.fragment_names;
+
+        for (&response_key, fields) in fields {
+            for (i, &field1) in fields.iter().enumerate() {
+                for &field2 in &fields[i + 1..] {
+                    conflicts.extend(self.find_conflict(false, response_key, field1, field2));
+                }
+            }
+        }
+
+        for (i, &fragment_name) in fragment_names.iter().enumerate() {
+            self.collect_conflicts_between_fields_and_fragment(
+                &mut conflicts,
+                false,
+                &selection_set.node,
+                fields,
+                fragment_name,
+            );
+            for &other_fragment_name in fragment_names.iter().skip(i + 1) {
+                self.collect_conflicts_between_fragments(
+                    &mut conflicts,
+                    false,
+                    fragment_name,
+                    other_fragment_name,
+                );
+            }
+        }
+
+        conflicts
+    }
+
+    /// Collect the conflicts between the fields of a selection set and the fields of a fragment,
+    /// including the fields of the fragments it spreads.
+    fn collect_conflicts_between_fields_and_fragment(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'a>>,
+        are_mutually_exclusive: bool,
+        selection_set: *const SelectionSet,
+        fields: &IndexMap<&'a Name, Vec<FieldInfo<'a>>>,
+        fragment_name: &'a Name,
+    ) {
+        if self
+            .cache
+            .compared_fields_and_fragment_pairs
+            .check_and_insert((selection_set, fragment_name), are_mutually_exclusive)
+        {
+            return;
+        }
+
+        let Some(fragment) = self.ctx.fragment(fragment_name) else {
+            return;
+        };
+        if std::ptr::eq(selection_set, &fragment.node.selection_set.node) {
+            return;
+        }
+
+        let fragment_fields = self.referenced_fields_and_fragment_names(fragment_name);
+        let Some(fragment_fields) = fragment_fields else {
+            return;
+        };
+
+        self.collect_conflicts_between(
+            conflicts,
+            are_mutually_exclusive,
+            fields,
+            &fragment_fields.fields,
+        );
+
+        for &referenced_fragment_name in &fragment_fields.fragment_names {
+            self.collect_conflicts_between_fields_and_fragment(
+                conflicts,
+                are_mutually_exclusive,
+                selection_set,
+                fields,
+                referenced_fragment_name,
+            );
+        }
+    }
+
+    /// Collect the conflicts between the fields of two fragments, including the fields of the
+    /// fragments they spread.
+    fn collect_conflicts_between_fragments(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'a>>,
+        are_mutually_exclusive: bool,
+        fragment_name1: &'a Name,
+        fragment_name2: &'a Name,
+    ) {
+        if fragment_name1 == fragment_name2 {
+            return;
+        }
+
+        let pair = if fragment_name1 < fragment_name2 {
+            (fragment_name1, fragment_name2)
+        } else {
+            (fragment_name2, fragment_name1)
+        };
+        if self
+            .cache
+            .compared_fragment_pairs
+            .check_and_insert(pair, are_mutually_exclusive)
+        {
+            return;
+        }
+
+        let (Some(fragment1), Some(fragment2)) = (
+            self.referenced_fields_and_fragment_names(fragment_name1),
+            self.referenced_fields_and_fragment_names(fragment_name2),
+        ) else {
+            return;
+        };
+
+        self.collect_conflicts_between(
+            conflicts,
+            are_mutually_exclusive,
+            &fragment1.fields,
+            &fragment2.fields,
+        );
+
+        for &referenced_fragment_name in &fragment2.fragment_names {
+            self.collect_conflicts_between_fragments(
+                conflicts,
+                are_mutually_exclusive,
+                fragment_name1,
+                referenced_fragment_name,
+            );
+        }
+        for &referenced_fragment_name in &fragment1.fragment_names {
+            self.collect_conflicts_between_fragments(
+                conflicts,
+                are_mutually_exclusive,
+                referenced_fragment_name,
+                fragment_name2,
+            );
+        }
+    }
+
+    /// Find the conflicts between the subselections of two fields with the same response key.
+    fn find_conflicts_between_sub_selection_sets(
+        &mut self,
+        are_mutually_exclusive: bool,
+        parent_type1: Option<&'a Positioned<TypeDefinition>>,
+        selection_set1: &'a Positioned<SelectionSet>,
+        parent_type2: Option<&'a Positioned<TypeDefinition>>,
+        selection_set2: &'a Positioned<SelectionSet>,
+    ) -> Vec<Conflict<'a>> {
+        let mut conflicts = Vec::new();
+
+        let fields_and_fragments1 = self.fields_and_fragment_names(parent_type1, selection_set1);
+        let fields_and_fragments2 = self.fields_and_fragment_names(parent_type2, selection_set2);
+
+        self.collect_conflicts_between(
+            &mut conflicts,
+            are_mutually_exclusive,
+            &fields_and_fragments1.fields,
+            &fields_and_fragments2.fields,
+        );
+
+        for &fragment_name in &fields_and_fragments2.fragment_names {
+            self.collect_conflicts_between_fields_and_fragment(
+                &mut conflicts,
+                are_mutually_exclusive,
+                &selection_set1.node,
+                &fields_and_fragments1.fields,
+                fragment_name,
+            );
+        }
+        for &fragment_name in &fields_and_fragments1.fragment_names {
+            self.collect_conflicts_between_fields_and_fragment(
+                &mut conflicts,
+                are_mutually_exclusive,
+                &selection_set2.node,
+                &fields_and_fragments2.fields,
+                fragment_name,
+            );
+        }
+        for &fragment_name1 in &fields_and_fragments1.fragment_names {
+            for &fragment_name2 in &fields_and_fragments2.fragment_names {
+                self.collect_conflicts_between_fragments(
+                    &mut conflicts,
+                    are_mutually_exclusive,
+                    fragment_name1,
+                    fragment_name2,
+                );
+            }
+        }
+
+        conflicts
+    }
+
+    /// Collect the conflicts between the fields with the same response key of two field maps.
+    fn collect_conflicts_between(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'a>>,
+        are_mutually_exclusive: bool,
+        fields1: &IndexMap<&'a Name, Vec<FieldInfo<'a>>>,
+        fields2: &IndexMap<&'a Name, Vec<FieldInfo<'a>>>,
+    ) {
+        for (&response_key, fields1) in fields1 {
+            let Some(fields2) = fields2.get(response_key) else {
+                continue;
+            };
+            for &field1 in fields1 {
+                for &field2 in fields2 {
+                    conflicts.extend(self.find_conflict(
+                        are_mutually_exclusive,
+                        response_key,
+                        field1,
+                        field2,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Find the conflict between two fields with the same response key, if any.
+    fn find_conflict(
+        &mut self,
+        parent_fields_are_mutually_exclusive: bool,
+        response_key: &'a Name,
+        field1: FieldInfo<'a>,
+        field2: FieldInfo<'a>,
+    ) -> Option<Conflict<'a>> {
+        // Fields of different object types can never be selected at the same time, so they only
+        // need to return compatible types.
+        let are_mutually_exclusive = parent_fields_are_mutually_exclusive
+            || match (field1.parent_type, field2.parent_type) {
+                (Some(parent_type1), Some(parent_type2)) => {
+                    parent_type1.node.name.node != parent_type2.node.name.node
+                        && matches!(parent_type1.node.kind, TypeKind::Object(_))
+                        && matches!(parent_type2.node.kind, TypeKind::Object(_))
+                }
+                _ => false,
+            };
+
+        let node1 = &field1.field.node;
+        let node2 = &field2.field.node;
+        let conflict = |reason| Conflict {
+            response_key,
+            reason: Reason::Message(reason),
+            fields1: vec![field1.field.pos],
+            fields2: vec![field2.field.pos],
+        };
+
+        if !are_mutually_exclusive {
+            if node1.name.node != node2.name.node {
+                return Some(conflict(format!(
+                    "{} and {} are different fields",
+                    node1.name.node, node2.name.node
+                )));
+            }
+            if !same_arguments(node1, node2) {
+                return Some(conflict("they have differing arguments".to_string()));
+            }
+        }
+
+        let ty1 = field1.definition.map(|def| &def.node.ty.node);
+        let ty2 = field2.definition.map(|def| &def.node.ty.node);
+        if let (Some(ty1), Some(ty2)) = (ty1, ty2) {
+            if self.do_types_conflict(ty1, ty2) {
+                return Some(conflict(format!(
+                    "they return conflicting types {} and {}",
+                    ty1, ty2
+                )));
+            }
+        }
+
+        if node1.selection_set.node.items.is_empty() || node2.selection_set.node.items.is_empty() {
+            return None;
+        }
+
+        let conflicts = self.find_conflicts_between_sub_selection_sets(
+            are_mutually_exclusive,
+            ty1.and_then(|ty| self.schema.get_type(ty.named_type())),
+            &node1.selection_set,
+            ty2.and_then(|ty| self.schema.get_type(ty.named_type())),
+            &node2.selection_set,
+        );
+        if conflicts.is_empty() {
+            return None;
+        }
+
+        let mut fields1 = vec![field1.field.pos];
+        let mut fields2 = vec![field2.field.pos];
+        let mut reasons = Vec::new();
+        for conflict in conflicts {
+            fields1.extend(conflict.fields1);
+            fields2.extend(conflict.fields2);
+            reasons.push((conflict.response_key, conflict.reason));
+        }
+
+        Some(Conflict {
+            response_key,
+            reason: Reason::Subfields(reasons),
+            fields1,
+            fields2,
+        })
+    }
+
+    /// Whether the two types conflict: they must have the same list and non-null wrappers, and
+    /// the same named type when it is a leaf type.
+    fn do_types_conflict(&self, ty1: &Type, ty2: &Type) -> bool {
+        if ty1.nullable != ty2.nullable {
+            return true;
+        }
+
+        match (&ty1.base, &ty2.base) {
+            (BaseType::List(ty1), BaseType::List(ty2)) => self.do_types_conflict(ty1, ty2),
+            (BaseType::Named(name1), BaseType::Named(name2)) => {
+                (self.schema.is_leaf_type(name1) || self.schema.is_leaf_type(name2))
+                    && name1 != name2
+            }
+            _ => true,
+        }
+    }
+
+    /// Get the fields and fragment names of the selection set, from the cache if possible.
+    fn fields_and_fragment_names(
+        &mut self,
+        parent_type: Option<&'a Positioned<TypeDefinition>>,
+        selection_set: &'a Positioned<SelectionSet>,
+    ) -> Rc<FieldsAndFragmentNames<'a>> {
+        let key = &selection_set.node as *const SelectionSet;
+        if let Some(cached) = self.cache.cached_fields_and_fragment_names.get(&key) {
+            return Rc::clone(cached);
+        }
+
+        let mut fields_and_fragments = FieldsAndFragmentNames {
+            fields: IndexMap::new(),
+            fragment_names: IndexSet::new(),
+        };
+        self.collect_fields_and_fragment_names(
+            parent_type,
+            selection_set,
+            &mut fields_and_fragments,
+        );
+
+        let fields_and_fragments = Rc::new(fields_and_fragments);
+        self.cache
+            .cached_fields_and_fragment_names
+            .insert(key, Rc::clone(&fields_and_fragments));
+        fields_and_fragments
+    }
+
+    /// Get the fields and fragment names of the fragment, if it is defined.
+    fn referenced_fields_and_fragment_names(
+        &mut self,
+        fragment_name: &str,
+    ) -> Option<Rc<FieldsAndFragmentNames<'a>>> {
+        let fragment = self.ctx.fragment(fragment_name)?;
+        let fragment_type = self
+            .schema
+            .get_type(&fragment.node.type_condition.node.on.node);
+        Some(self.fields_and_fragment_names(fragment_type, &fragment.node.selection_set))
+    }
+
+    fn collect_fields_and_fragment_names(
+        &self,
+        parent_type: Option<&'a Positioned<TypeDefinition>>,
+        selection_set: &'a Positioned<SelectionSet>,
+        fields_and_fragments: &mut FieldsAndFragmentNames<'a>,
+    ) {
+        for selection in &selection_set.node.items {
+            match &selection.node {
+                Selection::Field(field) => {
+                    let definition = parent_type.and_then(|ty| {
+                        self.schema
+                            .get_field(&ty.node.name.node, &field.node.name.node)
+                    });
+                    fields_and_fragments
+                        .fields
+                        .entry(&field.node.response_key().node)
+                        .or_default()
+                        .push(FieldInfo {
+                            parent_type,
+                            field,
+                            definition,
+                        });
+                }
+                Selection::FragmentSpread(spread) => {
+                    fields_and_fragments
+                        .fragment_names
+                        .insert(&spread.node.fragment_name.node);
+                }
+                Selection::InlineFragment(inline) => {
+                    let fragment_type = match &inline.node.type_condition {
+                        Some(condition) => self.schema.get_type(&condition.node.on.node),
+                        None => parent_type,
+                    };
+                    self.collect_fields_and_fragment_names(
+                        fragment_type,
+                        &inline.node.selection_set,
+                        fields_and_fragments,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Whether the two fields have the same arguments, with the same values.
+fn same_arguments(field1: &Field, field2: &Field) -> bool {
+    field1.arguments.len() == field2.arguments.len()
+        && field1.arguments.iter().all(|(name, value)| {
+            field2
+                .get_argument(&name.node)
+                .map_or(false, |other| other.node == value.node)
+        })
+}
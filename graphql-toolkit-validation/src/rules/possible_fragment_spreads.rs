@@ -0,0 +1,79 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Fragment-spread-is-possible).
+
+use graphql_toolkit_ast::{FragmentSpread, InlineFragment, Name, Positioned};
+use graphql_toolkit_schema::Schema;
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// A fragment can only be spread where its type condition can apply, that is, when the possible
+/// types of the parent type and of the fragment type overlap.
+#[derive(Default)]
+pub(crate) struct PossibleFragmentSpreads;
+
+impl<'a> Visitor<'a> for PossibleFragmentSpreads {
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+        let name = &fragment_spread.node.fragment_name.node;
+        let (Some(schema), Some(parent_type), Some(fragment)) =
+            (ctx.schema, ctx.parent_type(), ctx.fragment(name))
+        else {
+            return;
+        };
+
+        let parent_type = &parent_type.node.name.node;
+        let fragment_type = &fragment.node.type_condition.node.on.node;
+        if !types_overlap(schema, parent_type, fragment_type) {
+            ctx.report(
+                [fragment_spread.pos],
+                format!(
+                    "fragment {} cannot be spread here as objects of type {} can never be of type \
+                     {}",
+                    name, parent_type, fragment_type
+                ),
+            );
+        }
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        let (Some(schema), Some(parent_type), Some(condition)) = (
+            ctx.schema,
+            ctx.parent_type(),
+            &inline_fragment.node.type_condition,
+        ) else {
+            return;
+        };
+
+        let parent_type = &parent_type.node.name.node;
+        let fragment_type = &condition.node.on.node;
+        if !types_overlap(schema, parent_type, fragment_type) {
+            ctx.report(
+                [inline_fragment.pos],
+                format!(
+                    "inline fragment cannot be spread here as objects of type {} can never be of \
+                     type {}",
+                    parent_type, fragment_type
+                ),
+            );
+        }
+    }
+}
+
+/// Whether the two composite types have a possible type in common.
+///
+/// Returns `true` if any of the types is not a composite type, as that is reported by other
+/// rules.
+fn types_overlap(schema: &Schema, a: &Name, b: &Name) -> bool {
+    if !schema.is_composite_type(a) || !schema.is_composite_type(b) {
+        return true;
+    }
+    schema
+        .possible_types(a)
+        .any(|ty| schema.is_possible_type(b, ty))
+}
@@ -0,0 +1,65 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Required-Arguments).
+
+use graphql_toolkit_ast::{Directive, Field, InputValueDefinition, Name, Positioned, Value};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The non-null arguments without a default value of a field or directive must be provided.
+#[derive(Default)]
+pub(crate) struct ProvidedRequiredArguments;
+
+impl<'a> Visitor<'a> for ProvidedRequiredArguments {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let (Some(parent_type), Some(definition)) = (ctx.parent_type(), ctx.field_definition())
+        else {
+            return;
+        };
+
+        for arg in missing_arguments(&definition.node.arguments, &field.node.arguments) {
+            ctx.report(
+                [field.pos],
+                format!(
+                    "field {}.{} argument {} of type {} is required but not provided",
+                    parent_type.node.name.node,
+                    definition.node.name.node,
+                    arg.node.name.node,
+                    arg.node.ty.node
+                ),
+            );
+        }
+    }
+
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+        let Some(definition) = ctx.directive_definition() else {
+            return;
+        };
+
+        for arg in missing_arguments(&definition.node.arguments, &directive.node.arguments) {
+            ctx.report(
+                [directive.pos],
+                format!(
+                    "directive @{} argument {} of type {} is required but not provided",
+                    definition.node.name.node, arg.node.name.node, arg.node.ty.node
+                ),
+            );
+        }
+    }
+}
+
+/// Get the required argument definitions that are not provided.
+fn missing_arguments<'a>(
+    definitions: &'a [Positioned<InputValueDefinition>],
+    arguments: &'a [(Positioned<Name>, Positioned<Value>)],
+) -> impl Iterator<Item = &'a Positioned<InputValueDefinition>> {
+    definitions.iter().filter(|def| {
+        !def.node.ty.node.nullable
+            && def.node.default_value.is_none()
+            && arguments
+                .iter()
+                .all(|(name, _)| name.node != def.node.name.node)
+    })
+}
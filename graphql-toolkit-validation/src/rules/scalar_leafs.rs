@@ -0,0 +1,40 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Leaf-Field-Selections).
+
+use graphql_toolkit_ast::{Field, Positioned};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The fields of scalar and enum types must not have a selection set, and the fields of object,
+/// interface and union types must have one.
+#[derive(Default)]
+pub(crate) struct ScalarLeafs;
+
+impl<'a> Visitor<'a> for ScalarLeafs {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let (Some(schema), Some(definition)) = (ctx.schema, ctx.field_definition()) else {
+            return;
+        };
+
+        let ty = &definition.node.ty.node;
+        let type_name = ty.named_type();
+        let has_selection_set = !field.node.selection_set.node.items.is_empty();
+
+        if schema.is_leaf_type(type_name) && has_selection_set {
+            ctx.report(
+                [field.pos],
+                format!(
+                    "field {} of type {} must not have a selection set",
+                    field.node.name.node, ty
+                ),
+            );
+        } else if schema.is_composite_type(type_name) && !has_selection_set {
+            ctx.report(
+                [field.pos],
+                format!(
+                    "field {} of type {} must have a selection set",
+                    field.node.name.node, ty
+                ),
+            );
+        }
+    }
+}
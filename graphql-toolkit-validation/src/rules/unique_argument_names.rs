@@ -0,0 +1,43 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Argument-Uniqueness).
+
+use std::collections::HashMap;
+
+use graphql_toolkit_ast::{Directive, Field, Name, Pos, Positioned, Value};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The arguments provided to a field or directive must have unique names.
+#[derive(Default)]
+pub(crate) struct UniqueArgumentNames<'a> {
+    names: HashMap<&'a Name, Pos>,
+}
+
+impl<'a> Visitor<'a> for UniqueArgumentNames<'a> {
+    fn enter_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {
+        self.names.clear();
+    }
+
+    fn enter_directive(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _directive: &'a Positioned<Directive>,
+    ) {
+        self.names.clear();
+    }
+
+    fn enter_argument(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        _value: &'a Positioned<Value>,
+    ) {
+        if let Some(first) = self.names.get(&name.node) {
+            ctx.report(
+                [name.pos, *first],
+                format!("argument {} is provided twice", name.node),
+            );
+        } else {
+            self.names.insert(&name.node, name.pos);
+        }
+    }
+}
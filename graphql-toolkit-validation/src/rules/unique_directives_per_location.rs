@@ -0,0 +1,86 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Directives-Are-Unique-Per-Location).
+
+use std::collections::HashMap;
+
+use graphql_toolkit_ast::{
+    Directive, Field, FragmentDefinition, FragmentSpread, InlineFragment, Name,
+    OperationDefinition, Positioned, VariableDefinition,
+};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The non-repeatable directives must be used at most once per location.
+#[derive(Default)]
+pub(crate) struct UniqueDirectivesPerLocation;
+
+impl<'a> Visitor<'a> for UniqueDirectivesPerLocation {
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+        check_directives(ctx, &operation.node.directives);
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: &'a Name,
+        fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+        check_directives(ctx, &fragment.node.directives);
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        check_directives(ctx, &variable_definition.node.directives);
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        check_directives(ctx, &field.node.directives);
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+        check_directives(ctx, &fragment_spread.node.directives);
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        check_directives(ctx, &inline_fragment.node.directives);
+    }
+}
+
+fn check_directives(ctx: &mut VisitorContext<'_>, directives: &[Positioned<Directive>]) {
+    let Some(schema) = ctx.schema else {
+        return;
+    };
+
+    let mut seen = HashMap::new();
+    for directive in directives {
+        let name = &directive.node.name.node;
+        let is_repeatable = schema
+            .get_directive(name)
+            .map_or(true, |def| def.node.is_repeatable);
+        if is_repeatable {
+            continue;
+        }
+
+        if let Some(first) = seen.insert(name, directive.pos) {
+            ctx.report(
+                [directive.pos, first],
+                format!("directive @{} can only be used once at this location", name),
+            );
+        }
+    }
+}
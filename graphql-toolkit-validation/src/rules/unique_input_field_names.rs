@@ -0,0 +1,30 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Input-Object-Field-Uniqueness).
+
+use graphql_toolkit_ast::{ExecutableDocument, NameClash};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The fields of an input object value must have unique names.
+///
+/// The last field with a given name wins in the parsed value, so the duplicates are found
+/// in the document name clashes.
+#[derive(Default)]
+pub(crate) struct UniqueInputFieldNames;
+
+impl<'a> Visitor<'a> for UniqueInputFieldNames {
+    fn enter_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
+        for clash in &doc.name_clashes {
+            if let NameClash::InputField {
+                name,
+                first,
+                second,
+            } = clash
+            {
+                ctx.report(
+                    [*second, *first],
+                    format!("input field {} is defined twice", name),
+                );
+            }
+        }
+    }
+}
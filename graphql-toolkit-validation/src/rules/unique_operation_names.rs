@@ -0,0 +1,30 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Operation-Name-Uniqueness).
+
+use graphql_toolkit_ast::{ExecutableDocument, NameClash};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The operations of a document must have unique names.
+///
+/// Only the first operation with a given name is kept, and the others are name clashes. The
+/// parser rejects them, but the documents built otherwise can record them.
+#[derive(Default)]
+pub(crate) struct UniqueOperationNames;
+
+impl<'a> Visitor<'a> for UniqueOperationNames {
+    fn enter_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
+        for clash in &doc.name_clashes {
+            if let NameClash::Operation {
+                name,
+                first,
+                second,
+            } = clash
+            {
+                ctx.report(
+                    [*second, *first],
+                    format!("operation {} is defined twice", name),
+                );
+            }
+        }
+    }
+}
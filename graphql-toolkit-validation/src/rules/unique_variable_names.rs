@@ -0,0 +1,40 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Variable-Uniqueness).
+
+use std::collections::HashMap;
+
+use graphql_toolkit_ast::{Name, OperationDefinition, Pos, Positioned, VariableDefinition};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The variables defined by an operation must have unique names.
+#[derive(Default)]
+pub(crate) struct UniqueVariableNames<'a> {
+    names: HashMap<&'a Name, Pos>,
+}
+
+impl<'a> Visitor<'a> for UniqueVariableNames<'a> {
+    fn enter_operation_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _name: Option<&'a Name>,
+        _operation: &'a Positioned<OperationDefinition>,
+    ) {
+        self.names.clear();
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        let name = &variable_definition.node.name;
+        if let Some(first) = self.names.get(&name.node) {
+            ctx.report(
+                [name.pos, *first],
+                format!("variable ${} is defined twice", name.node),
+            );
+        } else {
+            self.names.insert(&name.node, name.pos);
+        }
+    }
+}
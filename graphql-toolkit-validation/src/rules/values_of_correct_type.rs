@@ -0,0 +1,167 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Values-of-Correct-Type).
+
+use graphql_toolkit_ast::{Name, Positioned, Type, TypeKind, Value, VariableDefinition};
+use graphql_toolkit_schema::Schema;
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The literal values of arguments and variable default values must be coercible to their
+/// input types.
+///
+/// The variables used in the values are checked by the variable usage rules.
+#[derive(Default)]
+pub(crate) struct ValuesOfCorrectType;
+
+impl<'a> Visitor<'a> for ValuesOfCorrectType {
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        let (Some(schema), Some(default_value)) =
+            (ctx.schema, &variable_definition.node.default_value)
+        else {
+            return;
+        };
+
+        let value = default_value.node.clone().into_value();
+        for message in check_value(schema, &value, &variable_definition.node.var_type.node) {
+            ctx.report([default_value.pos], message);
+        }
+    }
+
+    fn enter_argument(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
+    ) {
+        let (Some(schema), Some(definition)) = (ctx.schema, ctx.argument_definition()) else {
+            return;
+        };
+
+        for message in check_value(schema, &value.node, &definition.node.ty.node) {
+            ctx.report([value.pos], message);
+        }
+    }
+}
+
+/// Check the literal value against the input type, returning the error messages.
+fn check_value(schema: &Schema, value: &Value, ty: &Type) -> Vec<String> {
+    let mut errors = Vec::new();
+    collect_errors(schema, value, ty, &mut errors);
+    errors
+}
+
+fn collect_errors(schema: &Schema, value: &Value, ty: &Type, errors: &mut Vec<String>) {
+    match value {
+        Value::Variable(_) => return,
+        Value::Null => {
+            if !ty.nullable {
+                errors.push(format!("expected value of type {}, found null", ty));
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    // A single value is coerced to a list of one item.
+    if let Some(item_type) = ty.item_type() {
+        match value {
+            Value::List(items) => {
+                for item in items {
+                    collect_errors(schema, item, item_type, errors);
+                }
+            }
+            value => collect_errors(schema, value, item_type, errors),
+        }
+        return;
+    }
+
+    let Some(definition) = schema.get_type(ty.named_type()) else {
+        return;
+    };
+    let type_name = &definition.node.name.node;
+    let unexpected = || format!("expected value of type {}, found {}", ty, value);
+
+    match &definition.node.kind {
+        TypeKind::InputObject(input) => {
+            let Value::Object(fields) = value else {
+                errors.push(unexpected());
+                return;
+            };
+
+            for (name, value) in fields {
+                match input.fields.iter().find(|f| &f.node.name.node == name) {
+                    Some(field) => collect_errors(schema, value, &field.node.ty.node, errors),
+                    None => errors.push(format!(
+                        "field {} is not defined by type {}",
+                        name, type_name
+                    )),
+                }
+            }
+
+            for field in &input.fields {
+                let field = &field.node;
+                if !field.ty.node.nullable
+                    && field.default_value.is_none()
+                    && !fields.contains_key(&field.name.node)
+                {
+                    errors.push(format!(
+                        "field {}.{} of required type {} was not provided",
+                        type_name, field.name.node, field.ty.node
+                    ));
+                }
+            }
+
+            let is_one_of = definition
+                .node
+                .directives
+                .iter()
+                .any(|d| d.node.name.node == "oneOf");
+            if is_one_of {
+                match fields.first() {
+                    Some((name, Value::Null)) if fields.len() == 1 => {
+                        errors.push(format!("field {}.{} must be non-null", type_name, name));
+                    }
+                    Some(_) if fields.len() == 1 => {}
+                    _ => errors.push(format!(
+                        "oneOf input object {} must specify exactly one field",
+                        type_name
+                    )),
+                }
+            }
+        }
+        TypeKind::Enum(enum_type) => {
+            let is_valid = match value {
+                Value::Enum(name) => enum_type.values.iter().any(|v| &v.node.value.node == name),
+                _ => false,
+            };
+            if !is_valid {
+                errors.push(unexpected());
+            }
+        }
+        TypeKind::Scalar => {
+            let is_valid = match type_name.as_str() {
+                "Int" => match value {
+                    Value::Number(n) => n.as_i64().map_or(false, |n| i32::try_from(n).is_ok()),
+                    _ => false,
+                },
+                "Float" => matches!(value, Value::Number(_)),
+                "String" => matches!(value, Value::String(_)),
+                "Boolean" => matches!(value, Value::Boolean(_)),
+                "ID" => {
+                    matches!(value, Value::String(_))
+                        || matches!(value, Value::Number(n) if n.is_i64() || n.is_u64())
+                }
+                // Custom scalars accept any literal value.
+                _ => true,
+            };
+            if !is_valid {
+                errors.push(unexpected());
+            }
+        }
+        // Output types are reported by the type-system validation.
+        TypeKind::Object(_) | TypeKind::Interface(_) | TypeKind::Union(_) => {}
+    }
+}
@@ -0,0 +1,33 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-Variables-Are-Input-Types).
+
+use graphql_toolkit_ast::{Positioned, VariableDefinition};
+
+use crate::visitor::{Visitor, VisitorContext};
+
+/// The types of the variables must be input types: scalars, enums or input objects.
+#[derive(Default)]
+pub(crate) struct VariablesAreInputTypes;
+
+impl<'a> Visitor<'a> for VariablesAreInputTypes {
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        let Some(schema) = ctx.schema else {
+            return;
+        };
+
+        let ty = &variable_definition.node.var_type;
+        let type_name = ty.node.named_type();
+        if schema.get_type(type_name).is_some() && !schema.is_input_type(type_name) {
+            ctx.report(
+                [ty.pos],
+                format!(
+                    "variable ${} cannot be of non-input type {}",
+                    variable_definition.node.name.node, ty.node
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! [Reference](https://spec.graphql.org/October2021/#sec-All-Variable-Usages-are-Allowed).
+
+use std::collections::HashMap;
+
+use graphql_toolkit_ast::{
+    ConstValue, ExecutableDocument, FragmentDefinition, Name, OperationDefinition, Pos, Positioned,
+    Type, TypeKind, Value,
+};
+use graphql_toolkit_schema::Schema;
+
+use crate::{
+    utils::referenced_fragments,
+    visitor::{Visitor, VisitorContext},
+};
+
+/// The type of every variable must be compatible with the type expected where it is used.
+///
+/// A nullable variable can be used where a non-null value is expected if the variable or the
+/// location has a default value.
+#[derive(Default)]
+pub(crate) struct VariablesInAllowedPosition<'a> {
+    scope: Option<Scope<'a>>,
+    usages: HashMap<Scope<'a>, Vec<VariableUsage<'a>>>,
+}
+
+/// An operation, identified by its position, or a fragment.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Scope<'a> {
+    Operation(Pos),
+    Fragment(&'a Name),
+}
+
+/// A variable used where a value of the given type is expected.
+struct VariableUsage<'a> {
+    name: &'a Name,
+    pos: Pos,
+    ty: &'a Type,
+    has_location_default: bool,
+}
+
+impl<'a> Visitor<'a> for VariablesInAllowedPosition<'a> {
+    fn enter_operation_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+        self.scope = Some(Scope::Operation(operation.pos));
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        _fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+        self.scope = Some(Scope::Fragment(name));
+    }
+
+    fn enter_argument(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
+    ) {
+        let (Some(schema), Some(definition), Some(scope)) =
+            (ctx.schema, ctx.argument_definition(), self.scope)
+        else {
+            return;
+        };
+
+        let usages = self.usages.entry(scope).or_default();
+        collect_usages(
+            schema,
+            &value.node,
+            value.pos,
+            &definition.node.ty.node,
+            definition.node.default_value.is_some(),
+            usages,
+        );
+    }
+
+    fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, document: &'a ExecutableDocument) {
+        let Some(schema) = ctx.schema else {
+            return;
+        };
+
+        for (_, operation) in ctx.document.operations.in_order() {
+            let fragments = referenced_fragments(document, &operation.node.selection_set);
+            let usages = std::iter::once(Scope::Operation(operation.pos))
+                .chain(fragments.into_iter().map(Scope::Fragment))
+                .filter_map(|scope| self.usages.get(&scope))
+                .flatten();
+
+            for usage in usages {
+                let Some(definition) = operation
+                    .node
+                    .variable_definitions
+                    .iter()
+                    .find(|def| &def.node.name.node == usage.name)
+                else {
+                    continue;
+                };
+
+                // Variables of undefined types are reported by the known type names rule.
+                let variable_type = &definition.node.var_type.node;
+                if schema.get_type(variable_type.named_type()).is_none() {
+                    continue;
+                }
+
+                let is_allowed = if !usage.ty.nullable && variable_type.nullable {
+                    let has_variable_default = definition
+                        .node
+                        .default_value
+                        .as_ref()
+                        .map_or(false, |value| value.node != ConstValue::Null);
+                    (has_variable_default || usage.has_location_default)
+                        && variable_type.is_subtype_of(&usage.ty.to_nullable())
+                } else {
+                    variable_type.is_subtype_of(usage.ty)
+                };
+
+                if !is_allowed {
+                    ctx.report(
+                        [definition.pos, usage.pos],
+                        format!(
+                            "variable ${} of type {} used in position expecting type {}",
+                            usage.name, variable_type, usage.ty
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Collect the variables used in the value, with the types expected where they are used.
+fn collect_usages<'a>(
+    schema: &'a Schema,
+    value: &'a Value,
+    pos: Pos,
+    ty: &'a Type,
+    has_location_default: bool,
+    usages: &mut Vec<VariableUsage<'a>>,
+) {
+    match value {
+        Value::Variable(name) => usages.push(VariableUsage {
+            name,
+            pos,
+            ty,
+            has_location_default,
+        }),
+        Value::List(items) => {
+            if let Some(item_type) = ty.item_type() {
+                for item in items {
+                    collect_usages(schema, item, pos, item_type, false, usages);
+                }
+            }
+        }
+        Value::Object(fields) => {
+            let Some(TypeKind::InputObject(input)) =
+                schema.get_type(ty.named_type()).map(|ty| &ty.node.kind)
+            else {
+                return;
+            };
+            for (name, value) in fields {
+                if let Some(field) = input.fields.iter().find(|f| &f.node.name.node == name) {
+                    collect_usages(
+                        schema,
+                        value,
+                        pos,
+                        &field.node.ty.node,
+                        field.node.default_value.is_some(),
+                        usages,
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,539 @@
+//! Type-system validation.
+//!
+//! [Reference](https://spec.graphql.org/October2021/#sec-Type-System).
+
+use std::collections::{HashMap, HashSet};
+
+use graphql_toolkit_ast::{
+    BaseType, ConstDirective, DirectiveDefinition, FieldDefinition, InputValueDefinition, Name,
+    Pos, Positioned, ServiceDocument, TypeDefinition, TypeKind,
+};
+use graphql_toolkit_schema::Schema;
+
+use crate::error::Error;
+
+/// Validate the type system defined by the service document.
+///
+/// The document is first built into a [`Schema`], applying its extensions, and then validated
+/// with [`validate_schema`].
+///
+/// # Errors
+///
+/// Fails with all the violations found in the document.
+pub fn validate_service_document(document: &ServiceDocument) -> Result<(), Vec<Error>> {
+    let schema = Schema::from_document(document.clone())
+        .map_err(|errors| errors.into_iter().map(Error::from).collect::<Vec<_>>())?;
+    validate_schema(&schema)
+}
+
+/// Validate the type system of the schema.
+///
+/// # Errors
+///
+/// Fails with all the violations found in the schema.
+pub fn validate_schema(schema: &Schema) -> Result<(), Vec<Error>> {
+    let mut ctx = Context {
+        schema,
+        errors: Vec::new(),
+    };
+
+    ctx.validate_root_types();
+    for ty in schema.types() {
+        ctx.validate_type(ty);
+    }
+    for directive in schema.directives() {
+        ctx.validate_directive_definition(directive);
+    }
+    ctx.validate_input_object_cycles();
+
+    if ctx.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ctx.errors)
+    }
+}
+
+struct Context<'a> {
+    schema: &'a Schema,
+    errors: Vec<Error>,
+}
+
+impl<'a> Context<'a> {
+    fn report(&mut self, message: impl Into<String>, locations: impl IntoIterator<Item = Pos>) {
+        self.errors.push(Error::new(message, locations));
+    }
+
+    fn validate_root_types(&mut self) {
+        if self.schema.query_type().is_none() {
+            let locations = self.schema.definition().map(|def| def.pos);
+            self.report("query root type must be provided", locations);
+        }
+    }
+
+    fn validate_name(&mut self, kind: &str, name: &Positioned<Name>) {
+        if name.node.starts_with("__") {
+            self.report(
+                format!(
+                    "{} \"{}\" must not begin with \"__\", which is reserved by GraphQL \
+                     introspection",
+                    kind, name.node
+                ),
+                [name.pos],
+            );
+        }
+    }
+
+    /// Report the names that appear more than once.
+    fn validate_unique<'b>(
+        &mut self,
+        items: impl IntoIterator<Item = &'b Positioned<Name>>,
+        describe: impl Fn(&Name) -> String,
+    ) {
+        let mut seen = HashMap::new();
+        for name in items {
+            match seen.get(&name.node) {
+                Some(first) => self.report(describe(&name.node), [name.pos, *first]),
+                None => {
+                    seen.insert(&name.node, name.pos);
+                }
+            }
+        }
+    }
+
+    fn validate_type(&mut self, ty: &'a Positioned<TypeDefinition>) {
+        let name = &ty.node.name.node;
+        self.validate_name("type", &ty.node.name);
+
+        match &ty.node.kind {
+            TypeKind::Scalar => {}
+            TypeKind::Object(object) => {
+                if object.fields.is_empty() {
+                    self.report(
+                        format!("object type {} must define one or more fields", name),
+                        [ty.pos],
+                    );
+                }
+                self.validate_fields(name, &object.fields);
+                self.validate_implements(ty, &object.implements, &object.fields);
+            }
+            TypeKind::Interface(interface) => {
+                if interface.fields.is_empty() {
+                    self.report(
+                        format!("interface type {} must define one or more fields", name),
+                        [ty.pos],
+                    );
+                }
+                self.validate_fields(name, &interface.fields);
+                self.validate_implements(ty, &interface.implements, &interface.fields);
+            }
+            TypeKind::Union(union) => {
+                if union.members.is_empty() {
+                    self.report(
+                        format!("union type {} must define one or more member types", name),
+                        [ty.pos],
+                    );
+                }
+                self.validate_unique(&union.members, |member| {
+                    format!("union type {} can only include type {} once", name, member)
+                });
+                for member in &union.members {
+                    if !matches!(
+                        self.schema.get_type(&member.node).map(|ty| &ty.node.kind),
+                        Some(TypeKind::Object(_))
+                    ) {
+                        self.report(
+                            format!(
+                                "union type {} can only include object types, it cannot \
+                                 include {}",
+                                name, member.node
+                            ),
+                            [member.pos],
+                        );
+                    }
+                }
+            }
+            TypeKind::Enum(enum_type) => {
+                if enum_type.values.is_empty() {
+                    self.report(
+                        format!("enum type {} must define one or more values", name),
+                        [ty.pos],
+                    );
+                }
+                self.validate_unique(enum_type.values.iter().map(|v| &v.node.value), |value| {
+                    format!("enum value {}.{} can only be defined once", name, value)
+                });
+                for value in &enum_type.values {
+                    self.validate_name("enum value", &value.node.value);
+                    if matches!(value.node.value.node.as_str(), "true" | "false" | "null") {
+                        self.report(
+                            format!(
+                                "enum type {} cannot include value {}",
+                                name, value.node.value.node
+                            ),
+                            [value.pos],
+                        );
+                    }
+                }
+            }
+            TypeKind::InputObject(input) => {
+                if input.fields.is_empty() {
+                    self.report(
+                        format!("input object type {} must define one or more fields", name),
+                        [ty.pos],
+                    );
+                }
+                self.validate_unique(input.fields.iter().map(|f| &f.node.name), |field| {
+                    format!("input field {}.{} can only be defined once", name, field)
+                });
+
+                let is_one_of = has_directive(&ty.node.directives, "oneOf");
+                for field in &input.fields {
+                    let coordinate = format!("{}.{}", name, field.node.name.node);
+                    self.validate_input_value(&coordinate, field);
+
+                    if is_one_of
+                        && (!field.node.ty.node.nullable || field.node.default_value.is_some())
+                    {
+                        self.report(
+                            format!(
+                                "oneOf input field {} must be nullable and have no default value",
+                                coordinate
+                            ),
+                            [field.pos],
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_fields(&mut self, type_name: &Name, fields: &[Positioned<FieldDefinition>]) {
+        self.validate_unique(fields.iter().map(|f| &f.node.name), |field| {
+            format!("field {}.{} can only be defined once", type_name, field)
+        });
+
+        for field in fields {
+            let coordinate = format!("{}.{}", type_name, field.node.name.node);
+            self.validate_name("field", &field.node.name);
+
+            let ty = field.node.ty.node.named_type();
+            if !self.schema.is_output_type(ty) {
+                self.report(
+                    format!(
+                        "the type of {} must be an output type but got {}",
+                        coordinate, field.node.ty.node
+                    ),
+                    [field.node.ty.pos],
+                );
+            }
+
+            self.validate_arguments(&coordinate, &field.node.arguments);
+        }
+    }
+
+    fn validate_arguments(&mut self, owner: &str, arguments: &[Positioned<InputValueDefinition>]) {
+        self.validate_unique(arguments.iter().map(|a| &a.node.name), |argument| {
+            format!("argument {}({}:) can only be defined once", owner, argument)
+        });
+
+        for argument in arguments {
+            let coordinate = format!("{}({}:)", owner, argument.node.name.node);
+            self.validate_input_value(&coordinate, argument);
+        }
+    }
+
+    fn validate_input_value(&mut self, coordinate: &str, value: &Positioned<InputValueDefinition>) {
+        self.validate_name("input value", &value.node.name);
+
+        let ty = value.node.ty.node.named_type();
+        if !self.schema.is_input_type(ty) {
+            self.report(
+                format!(
+                    "the type of {} must be an input type but got {}",
+                    coordinate, value.node.ty.node
+                ),
+                [value.node.ty.pos],
+            );
+        }
+    }
+
+    fn validate_implements(
+        &mut self,
+        ty: &'a Positioned<TypeDefinition>,
+        implements: &'a [Positioned<Name>],
+        fields: &'a [Positioned<FieldDefinition>],
+    ) {
+        let type_name = &ty.node.name.node;
+        self.validate_unique(implements, |interface| {
+            format!("type {} can only implement {} once", type_name, interface)
+        });
+
+        for interface_name in implements {
+            if &interface_name.node == type_name {
+                self.report(
+                    format!("type {} cannot implement itself", type_name),
+                    [interface_name.pos],
+                );
+                continue;
+            }
+
+            let Some(interface) = self.schema.get_type(&interface_name.node) else {
+                self.report(
+                    format!(
+                        "type {} implements unknown interface {}",
+                        type_name, interface_name.node
+                    ),
+                    [interface_name.pos],
+                );
+                continue;
+            };
+            let TypeKind::Interface(interface_type) = &interface.node.kind else {
+                self.report(
+                    format!(
+                        "type {} must only implement interface types, it cannot implement {}",
+                        type_name, interface_name.node
+                    ),
+                    [interface_name.pos],
+                );
+                continue;
+            };
+
+            // The interfaces implemented by the interface must also be implemented.
+            for transitive in &interface_type.implements {
+                if &transitive.node != type_name
+                    && !implements.iter().any(|i| i.node == transitive.node)
+                {
+                    self.report(
+                        format!(
+                            "type {} must implement {} because it is implemented by {}",
+                            type_name, transitive.node, interface_name.node
+                        ),
+                        [interface_name.pos, transitive.pos],
+                    );
+                }
+            }
+
+            for interface_field in &interface_type.fields {
+                self.validate_field_implementation(
+                    type_name,
+                    fields,
+                    &interface_name.node,
+                    interface_field,
+                    ty.pos,
+                );
+            }
+        }
+    }
+
+    fn validate_field_implementation(
+        &mut self,
+        type_name: &Name,
+        fields: &[Positioned<FieldDefinition>],
+        interface_name: &Name,
+        interface_field: &Positioned<FieldDefinition>,
+        type_pos: Pos,
+    ) {
+        let field_name = &interface_field.node.name.node;
+        let Some(field) = fields.iter().find(|f| &f.node.name.node == field_name) else {
+            self.report(
+                format!(
+                    "interface field {}.{} expected but {} does not provide it",
+                    interface_name, field_name, type_name
+                ),
+                [type_pos, interface_field.pos],
+            );
+            return;
+        };
+
+        // Field types are covariant.
+        if !self
+            .schema
+            .is_subtype(&field.node.ty.node, &interface_field.node.ty.node)
+        {
+            self.report(
+                format!(
+                    "interface field {}.{} expects type {} but {}.{} is type {}",
+                    interface_name,
+                    field_name,
+                    interface_field.node.ty.node,
+                    type_name,
+                    field_name,
+                    field.node.ty.node
+                ),
+                [field.node.ty.pos, interface_field.node.ty.pos],
+            );
+        }
+
+        // Argument types are invariant.
+        for interface_argument in &interface_field.node.arguments {
+            let argument_name = &interface_argument.node.name.node;
+            match field
+                .node
+                .arguments
+                .iter()
+                .find(|a| &a.node.name.node == argument_name)
+            {
+                None => self.report(
+                    format!(
+                        "interface field argument {}.{}({}:) expected but {}.{} does not provide \
+                         it",
+                        interface_name, field_name, argument_name, type_name, field_name
+                    ),
+                    [field.pos, interface_argument.pos],
+                ),
+                Some(argument) if argument.node.ty.node != interface_argument.node.ty.node => {
+                    self.report(
+                        format!(
+                            "interface field argument {}.{}({}:) expects type {} but {}.{}({}:) \
+                             is type {}",
+                            interface_name,
+                            field_name,
+                            argument_name,
+                            interface_argument.node.ty.node,
+                            type_name,
+                            field_name,
+                            argument_name,
+                            argument.node.ty.node
+                        ),
+                        [argument.node.ty.pos, interface_argument.node.ty.pos],
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+
+        // Additional arguments must be optional.
+        for argument in &field.node.arguments {
+            let argument_name = &argument.node.name.node;
+            let is_required =
+                !argument.node.ty.node.nullable && argument.node.default_value.is_none();
+            if is_required
+                && !interface_field
+                    .node
+                    .arguments
+                    .iter()
+                    .any(|a| &a.node.name.node == argument_name)
+            {
+                self.report(
+                    format!(
+                        "field argument {}.{}({}:) is of required type {} but is not also \
+                         provided by the interface {}.{}",
+                        type_name,
+                        field_name,
+                        argument_name,
+                        argument.node.ty.node,
+                        interface_name,
+                        field_name
+                    ),
+                    [argument.pos, interface_field.pos],
+                );
+            }
+        }
+    }
+
+    fn validate_directive_definition(&mut self, directive: &Positioned<DirectiveDefinition>) {
+        let name = &directive.node.name.node;
+        self.validate_name("directive", &directive.node.name);
+        self.validate_arguments(&format!("@{}", name), &directive.node.arguments);
+
+        for argument in &directive.node.arguments {
+            if let Some(usage) = argument
+                .node
+                .directives
+                .iter()
+                .find(|d| &d.node.name.node == name)
+            {
+                self.report(
+                    format!("directive @{} cannot reference itself", name),
+                    [usage.pos, directive.pos],
+                );
+            }
+        }
+
+        let mut locations = Vec::new();
+        for location in &directive.node.locations {
+            if locations.contains(&location.node) {
+                self.report(
+                    format!("directive @{} can only include a location once", name),
+                    [location.pos],
+                );
+            } else {
+                locations.push(location.node);
+            }
+        }
+    }
+
+    /// Input objects must not reference themselves through a chain of non-null fields, as no
+    /// finite value could be provided for them.
+    fn validate_input_object_cycles(&mut self) {
+        let mut visited = HashSet::new();
+        for ty in self.schema.types() {
+            if let TypeKind::InputObject(_) = &ty.node.kind {
+                let mut path = Vec::new();
+                let mut path_index = HashMap::new();
+                self.detect_input_object_cycle(ty, &mut visited, &mut path, &mut path_index);
+            }
+        }
+    }
+
+    fn detect_input_object_cycle(
+        &mut self,
+        ty: &'a Positioned<TypeDefinition>,
+        visited: &mut HashSet<&'a Name>,
+        path: &mut Vec<&'a Positioned<InputValueDefinition>>,
+        path_index: &mut HashMap<&'a Name, usize>,
+    ) {
+        let name = &ty.node.name.node;
+        if !visited.insert(name) {
+            return;
+        }
+        let TypeKind::InputObject(input) = &ty.node.kind else {
+            return;
+        };
+
+        path_index.insert(name, path.len());
+        for field in &input.fields {
+            let field_type = &field.node.ty.node;
+            let BaseType::Named(field_type_name) = &field_type.base else {
+                continue;
+            };
+            if field_type.nullable {
+                continue;
+            }
+            let Some(field_type_def) = self.schema.get_type(field_type_name) else {
+                continue;
+            };
+            if !matches!(field_type_def.node.kind, TypeKind::InputObject(_)) {
+                continue;
+            }
+
+            path.push(field);
+            match path_index.get(field_type_name) {
+                None => self.detect_input_object_cycle(field_type_def, visited, path, path_index),
+                Some(&start) => {
+                    let cycle = &path[start..];
+                    let fields = cycle
+                        .iter()
+                        .map(|f| f.node.name.node.as_str())
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    self.report(
+                        format!(
+                            "input object {} cannot reference itself through a series of \
+                             non-null fields: {}",
+                            field_type_name, fields
+                        ),
+                        cycle.iter().map(|f| f.pos),
+                    );
+                }
+            }
+            path.pop();
+        }
+        path_index.remove(name);
+    }
+}
+
+/// Whether the directive with the given name is applied.
+fn has_directive(directives: &[Positioned<ConstDirective>], name: &str) -> bool {
+    directives.iter().any(|d| d.node.name.node == name)
+}
@@ -0,0 +1,150 @@
+//! Helpers shared by the validation rules.
+
+use graphql_toolkit_ast::{
+    indexmap::IndexSet, Directive, ExecutableDocument, FragmentSpread, Name, Pos, Positioned,
+    Selection, SelectionSet, Value,
+};
+
+/// Get the fragment spreads of the selection set, including the ones nested in fields and inline
+/// fragments, without following the spread fragments.
+pub(crate) fn fragment_spreads(
+    selection_set: &Positioned<SelectionSet>,
+) -> Vec<&Positioned<FragmentSpread>> {
+    fn collect<'a>(
+        selection_set: &'a Positioned<SelectionSet>,
+        spreads: &mut Vec<&'a Positioned<FragmentSpread>>,
+    ) {
+        for selection in &selection_set.node.items {
+            match &selection.node {
+                Selection::Field(field) => collect(&field.node.selection_set, spreads),
+                Selection::FragmentSpread(spread) => spreads.push(spread),
+                Selection::InlineFragment(inline) => collect(&inline.node.selection_set, spreads),
+            }
+        }
+    }
+
+    let mut spreads = Vec::new();
+    collect(selection_set, &mut spreads);
+    spreads
+}
+
+/// Get the names of the fragments spread by the selection set, directly or through other
+/// fragments, in the order they are found. Undefined fragments are included but not followed.
+pub(crate) fn referenced_fragments<'a>(
+    document: &'a ExecutableDocument,
+    selection_set: &'a Positioned<SelectionSet>,
+) -> IndexSet<&'a Name> {
+    let mut fragments = IndexSet::new();
+    let mut stack = vec![selection_set];
+
+    while let Some(selection_set) = stack.pop() {
+        for spread in fragment_spreads(selection_set) {
+            let name = &spread.node.fragment_name.node;
+            if !fragments.insert(name) {
+                continue;
+            }
+            if let Some(fragment) = document.fragments.get(name) {
+                stack.push(&fragment.node.selection_set);
+            }
+        }
+    }
+
+    fragments
+}
+
+/// Get the variables used in the arguments of the selection set, with the position of the
+/// argument value using them, without following the spread fragments.
+pub(crate) fn variable_usages(selection_set: &Positioned<SelectionSet>) -> Vec<(&Name, Pos)> {
+    fn collect_directives<'a>(
+        directives: &'a [Positioned<Directive>],
+        usages: &mut Vec<(&'a Name, Pos)>,
+    ) {
+        for directive in directives {
+            collect_arguments(&directive.node.arguments, usages);
+        }
+    }
+
+    fn collect_arguments<'a>(
+        arguments: &'a [(Positioned<Name>, Positioned<Value>)],
+        usages: &mut Vec<(&'a Name, Pos)>,
+    ) {
+        for (_, value) in arguments {
+            usages.extend(
+                value_variables(&value.node)
+                    .into_iter()
+                    .map(|n| (n, value.pos)),
+            );
+        }
+    }
+
+    fn collect<'a>(selection_set: &'a Positioned<SelectionSet>, usages: &mut Vec<(&'a Name, Pos)>) {
+        for selection in &selection_set.node.items {
+            match &selection.node {
+                Selection::Field(field) => {
+                    collect_arguments(&field.node.arguments, usages);
+                    collect_directives(&field.node.directives, usages);
+                    collect(&field.node.selection_set, usages);
+                }
+                Selection::FragmentSpread(spread) => {
+                    collect_directives(&spread.node.directives, usages);
+                }
+                Selection::InlineFragment(inline) => {
+                    collect_directives(&inline.node.directives, usages);
+                    collect(&inline.node.selection_set, usages);
+                }
+            }
+        }
+    }
+
+    let mut usages = Vec::new();
+    collect(selection_set, &mut usages);
+    usages
+}
+
+/// Get the variables used in the value, including the ones nested in lists and objects.
+pub(crate) fn value_variables(value: &Value) -> Vec<&Name> {
+    fn collect<'a>(value: &'a Value, variables: &mut Vec<&'a Name>) {
+        match value {
+            Value::Variable(name) => variables.push(name),
+            Value::List(items) => items.iter().for_each(|item| collect(item, variables)),
+            Value::Object(fields) => fields.values().for_each(|field| collect(field, variables)),
+            _ => {}
+        }
+    }
+
+    let mut variables = Vec::new();
+    collect(value, &mut variables);
+    variables
+}
+
+/// Get the variables used by the operation, directly or through the fragments it spreads,
+/// including the ones used in the directives of the operation and of the fragment definitions.
+pub(crate) fn operation_variable_usages<'a>(
+    document: &'a ExecutableDocument,
+    directives: &'a [Positioned<Directive>],
+    selection_set: &'a Positioned<SelectionSet>,
+) -> Vec<(&'a Name, Pos)> {
+    let mut usages = Vec::new();
+    let mut add = |directives: &'a [Positioned<Directive>],
+                   selection_set: &'a Positioned<SelectionSet>| {
+        for directive in directives {
+            for (_, value) in &directive.node.arguments {
+                usages.extend(
+                    value_variables(&value.node)
+                        .into_iter()
+                        .map(|n| (n, value.pos)),
+                );
+            }
+        }
+        usages.extend(variable_usages(selection_set));
+    };
+
+    add(directives, selection_set);
+    for name in referenced_fragments(document, selection_set) {
+        if let Some(fragment) = document.fragments.get(name) {
+            add(&fragment.node.directives, &fragment.node.selection_set);
+        }
+    }
+
+    usages
+}
@@ -0,0 +1,486 @@
+//! A visitor over executable documents, used to implement the validation rules.
+
+use graphql_toolkit_ast::{
+    Directive, DirectiveDefinition, DirectiveLocation, ExecutableDocument, Field, FieldDefinition,
+    FragmentDefinition, FragmentSpread, InlineFragment, InputValueDefinition, Name,
+    OperationDefinition, Pos, Positioned, Selection, SelectionSet, TypeDefinition, Value,
+    VariableDefinition,
+};
+use graphql_toolkit_schema::Schema;
+
+use crate::error::Error;
+
+/// The state shared by the validation rules while visiting a document.
+///
+/// When validating against a schema, the context tracks the composite type of the selection set
+/// being visited, and the definitions of the field, directive and argument being visited. They
+/// are `None` when unknown, either because there is no schema, or because the document refers to
+/// undefined types, fields, directives or arguments, or to types that cannot have selection sets.
+pub(crate) struct VisitorContext<'a> {
+    pub(crate) document: &'a ExecutableDocument,
+    pub(crate) schema: Option<&'a Schema>,
+    pub(crate) errors: Vec<Error>,
+    type_stack: Vec<Option<&'a Positioned<TypeDefinition>>>,
+    field_stack: Vec<Option<&'a Positioned<FieldDefinition>>>,
+    directive: Option<&'a Positioned<DirectiveDefinition>>,
+    directive_location: Option<DirectiveLocation>,
+    argument: Option<&'a Positioned<InputValueDefinition>>,
+}
+
+impl<'a> VisitorContext<'a> {
+    pub(crate) fn new(schema: Option<&'a Schema>, document: &'a ExecutableDocument) -> Self {
+        Self {
+            document,
+            schema,
+            errors: Vec::new(),
+            type_stack: Vec::new(),
+            field_stack: Vec::new(),
+            directive: None,
+            directive_location: None,
+            argument: None,
+        }
+    }
+
+    /// Consume the context, returning the errors reported, if any.
+    pub(crate) fn into_result(self) -> Result<(), Vec<Error>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Get the type of the selection set being visited.
+    ///
+    /// Inside a field, this is the type the field is selected on.
+    pub(crate) fn parent_type(&self) -> Option<&'a Positioned<TypeDefinition>> {
+        self.type_stack.last().copied().flatten()
+    }
+
+    /// Get the definition of the field being visited.
+    pub(crate) fn field_definition(&self) -> Option<&'a Positioned<FieldDefinition>> {
+        self.field_stack.last().copied().flatten()
+    }
+
+    /// Get the definition of the directive being visited.
+    pub(crate) fn directive_definition(&self) -> Option<&'a Positioned<DirectiveDefinition>> {
+        self.directive
+    }
+
+    /// Get the location of the directive being visited.
+    pub(crate) fn directive_location(&self) -> Option<DirectiveLocation> {
+        self.directive_location
+    }
+
+    /// Get the definition of the argument being visited, of a field or a directive.
+    pub(crate) fn argument_definition(&self) -> Option<&'a Positioned<InputValueDefinition>> {
+        self.argument
+    }
+
+    fn get_composite_type(&self, name: &str) -> Option<&'a Positioned<TypeDefinition>> {
+        self.schema
+            .filter(|schema| schema.is_composite_type(name))
+            .and_then(|schema| schema.get_type(name))
+    }
+
+    pub(crate) fn report(
+        &mut self,
+        locations: impl IntoIterator<Item = Pos>,
+        message: impl Into<String>,
+    ) {
+        self.errors.push(Error::new(message, locations));
+    }
+
+    pub(crate) fn fragment(&self, name: &str) -> Option<&'a Positioned<FragmentDefinition>> {
+        self.document.fragments.get(name)
+    }
+}
+
+/// A validation rule, visiting the nodes of an executable document.
+///
+/// All the methods are no-ops by default.
+#[allow(unused_variables)]
+pub(crate) trait Visitor<'a> {
+    /// Chain another visitor, visiting each node with it after this one.
+    fn with<V>(self, visitor: V) -> VisitorCons<Self, V>
+    where
+        Self: Sized,
+    {
+        VisitorCons(self, visitor)
+    }
+
+    fn enter_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {}
+    fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {}
+
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+    }
+    fn exit_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+    }
+    fn exit_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+    }
+    fn exit_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+    }
+
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+    }
+    fn exit_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+    }
+
+    fn enter_argument(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
+    ) {
+    }
+    fn exit_argument(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
+    ) {
+    }
+
+    fn enter_selection_set(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        selection_set: &'a Positioned<SelectionSet>,
+    ) {
+    }
+    fn exit_selection_set(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        selection_set: &'a Positioned<SelectionSet>,
+    ) {
+    }
+
+    fn enter_selection(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        selection: &'a Positioned<Selection>,
+    ) {
+    }
+    fn exit_selection(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        selection: &'a Positioned<Selection>,
+    ) {
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {}
+    fn exit_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {}
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+    }
+    fn exit_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+    }
+    fn exit_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+    }
+}
+
+/// A pair of visitors, visiting each node with the first visitor and then the second one.
+pub(crate) struct VisitorCons<A, B>(pub(crate) A, pub(crate) B);
+
+macro_rules! delegate {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(&mut self, ctx: &mut VisitorContext<'a>, $($arg: $ty),*) {
+                self.0.$method(ctx, $($arg),*);
+                self.1.$method(ctx, $($arg),*);
+            }
+        )*
+    };
+}
+
+impl<'a, A, B> Visitor<'a> for VisitorCons<A, B>
+where
+    A: Visitor<'a>,
+    B: Visitor<'a>,
+{
+    delegate! {
+        enter_document(doc: &'a ExecutableDocument);
+        exit_document(doc: &'a ExecutableDocument);
+        enter_operation_definition(
+            name: Option<&'a Name>,
+            operation: &'a Positioned<OperationDefinition>
+        );
+        exit_operation_definition(
+            name: Option<&'a Name>,
+            operation: &'a Positioned<OperationDefinition>
+        );
+        enter_fragment_definition(name: &'a Name, fragment: &'a Positioned<FragmentDefinition>);
+        exit_fragment_definition(name: &'a Name, fragment: &'a Positioned<FragmentDefinition>);
+        enter_variable_definition(variable_definition: &'a Positioned<VariableDefinition>);
+        exit_variable_definition(variable_definition: &'a Positioned<VariableDefinition>);
+        enter_directive(directive: &'a Positioned<Directive>);
+        exit_directive(directive: &'a Positioned<Directive>);
+        enter_argument(name: &'a Positioned<Name>, value: &'a Positioned<Value>);
+        exit_argument(name: &'a Positioned<Name>, value: &'a Positioned<Value>);
+        enter_selection_set(selection_set: &'a Positioned<SelectionSet>);
+        exit_selection_set(selection_set: &'a Positioned<SelectionSet>);
+        enter_selection(selection: &'a Positioned<Selection>);
+        exit_selection(selection: &'a Positioned<Selection>);
+        enter_field(field: &'a Positioned<Field>);
+        exit_field(field: &'a Positioned<Field>);
+        enter_fragment_spread(fragment_spread: &'a Positioned<FragmentSpread>);
+        exit_fragment_spread(fragment_spread: &'a Positioned<FragmentSpread>);
+        enter_inline_fragment(inline_fragment: &'a Positioned<InlineFragment>);
+        exit_inline_fragment(inline_fragment: &'a Positioned<InlineFragment>);
+    }
+}
+
+/// The visitor that visits nothing, used to terminate a chain of [`VisitorCons`].
+pub(crate) struct VisitorNil;
+
+impl<'a> Visitor<'a> for VisitorNil {}
+
+/// Visit the document, in document order.
+pub(crate) fn visit<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    doc: &'a ExecutableDocument,
+) {
+    visitor.enter_document(ctx, doc);
+
+    for (name, operation) in ctx.document.operations.in_order() {
+        visit_operation_definition(visitor, ctx, name, operation);
+    }
+    for (name, fragment) in ctx.document.fragments_in_order() {
+        visit_fragment_definition(visitor, ctx, name, fragment);
+    }
+
+    visitor.exit_document(ctx, doc);
+}
+
+fn visit_operation_definition<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    name: Option<&'a Name>,
+    operation: &'a Positioned<OperationDefinition>,
+) {
+    let root_type = ctx
+        .schema
+        .and_then(|schema| schema.root_type(operation.node.ty));
+    ctx.type_stack.push(root_type);
+
+    visitor.enter_operation_definition(ctx, name, operation);
+    for variable_definition in &operation.node.variable_definitions {
+        visitor.enter_variable_definition(ctx, variable_definition);
+        visit_directives(
+            visitor,
+            ctx,
+            variable_definition.node.directive_location(),
+            &variable_definition.node.directives,
+        );
+        visitor.exit_variable_definition(ctx, variable_definition);
+    }
+    visit_directives(
+        visitor,
+        ctx,
+        operation.node.directive_location(),
+        &operation.node.directives,
+    );
+    visit_selection_set(visitor, ctx, &operation.node.selection_set);
+    visitor.exit_operation_definition(ctx, name, operation);
+
+    ctx.type_stack.pop();
+}
+
+fn visit_fragment_definition<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    name: &'a Name,
+    fragment: &'a Positioned<FragmentDefinition>,
+) {
+    let fragment_type = ctx.get_composite_type(&fragment.node.type_condition.node.on.node);
+    ctx.type_stack.push(fragment_type);
+
+    visitor.enter_fragment_definition(ctx, name, fragment);
+    visit_directives(
+        visitor,
+        ctx,
+        fragment.node.directive_location(),
+        &fragment.node.directives,
+    );
+    visit_selection_set(visitor, ctx, &fragment.node.selection_set);
+    visitor.exit_fragment_definition(ctx, name, fragment);
+
+    ctx.type_stack.pop();
+}
+
+fn visit_selection_set<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    selection_set: &'a Positioned<SelectionSet>,
+) {
+    visitor.enter_selection_set(ctx, selection_set);
+    for selection in &selection_set.node.items {
+        visitor.enter_selection(ctx, selection);
+        match &selection.node {
+            Selection::Field(field) => visit_field(visitor, ctx, field),
+            Selection::FragmentSpread(fragment_spread) => {
+                visitor.enter_fragment_spread(ctx, fragment_spread);
+                visit_directives(
+                    visitor,
+                    ctx,
+                    fragment_spread.node.directive_location(),
+                    &fragment_spread.node.directives,
+                );
+                visitor.exit_fragment_spread(ctx, fragment_spread);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                let fragment_type = match &inline_fragment.node.type_condition {
+                    Some(condition) => ctx.get_composite_type(&condition.node.on.node),
+                    None => ctx.parent_type(),
+                };
+
+                visitor.enter_inline_fragment(ctx, inline_fragment);
+                visit_directives(
+                    visitor,
+                    ctx,
+                    inline_fragment.node.directive_location(),
+                    &inline_fragment.node.directives,
+                );
+                ctx.type_stack.push(fragment_type);
+                visit_selection_set(visitor, ctx, &inline_fragment.node.selection_set);
+                ctx.type_stack.pop();
+                visitor.exit_inline_fragment(ctx, inline_fragment);
+            }
+        }
+        visitor.exit_selection(ctx, selection);
+    }
+    visitor.exit_selection_set(ctx, selection_set);
+}
+
+fn visit_field<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    field: &'a Positioned<Field>,
+) {
+    let field_definition = ctx
+        .schema
+        .zip(ctx.parent_type())
+        .and_then(|(schema, ty)| schema.get_field(&ty.node.name.node, &field.node.name.node));
+    ctx.field_stack.push(field_definition);
+
+    visitor.enter_field(ctx, field);
+    let arguments = field_definition.map_or(&[][..], |def| &def.node.arguments);
+    visit_arguments(visitor, ctx, arguments, &field.node.arguments);
+    visit_directives(
+        visitor,
+        ctx,
+        field.node.directive_location(),
+        &field.node.directives,
+    );
+
+    let field_type =
+        field_definition.and_then(|def| ctx.get_composite_type(def.node.ty.node.named_type()));
+    ctx.type_stack.push(field_type);
+    visit_selection_set(visitor, ctx, &field.node.selection_set);
+    ctx.type_stack.pop();
+
+    visitor.exit_field(ctx, field);
+
+    ctx.field_stack.pop();
+}
+
+fn visit_directives<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    location: DirectiveLocation,
+    directives: &'a [Positioned<Directive>],
+) {
+    ctx.directive_location = Some(location);
+    for directive in directives {
+        ctx.directive = ctx
+            .schema
+            .and_then(|schema| schema.get_directive(&directive.node.name.node));
+
+        visitor.enter_directive(ctx, directive);
+        let arguments = ctx.directive.map_or(&[][..], |def| &def.node.arguments);
+        visit_arguments(visitor, ctx, arguments, &directive.node.arguments);
+        visitor.exit_directive(ctx, directive);
+
+        ctx.directive = None;
+    }
+    ctx.directive_location = None;
+}
+
+fn visit_arguments<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    definitions: &'a [Positioned<InputValueDefinition>],
+    arguments: &'a [(Positioned<Name>, Positioned<Value>)],
+) {
+    for (name, value) in arguments {
+        ctx.argument = definitions
+            .iter()
+            .find(|def| def.node.name.node == name.node);
+
+        visitor.enter_argument(ctx, name, value);
+        visitor.exit_argument(ctx, name, value);
+
+        ctx.argument = None;
+    }
+}
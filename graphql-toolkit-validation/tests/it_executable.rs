@@ -0,0 +1,268 @@
+use graphql_toolkit_parser::{Name, NameClash, Pos};
+use graphql_toolkit_validation::{validate_executable_document, Error};
+use indoc::indoc;
+
+/// Test helper function to parse and validate a GraphQL executable document string.
+///
+/// # Panics
+/// The function asserts that the input is a valid executable document by checking the result of
+/// the `parse_query` function.
+fn validate(input: &str) -> Result<(), Vec<Error>> {
+    let document = graphql_toolkit_parser::parse_query(input).expect("query parsing failed");
+    validate_executable_document(&document)
+}
+
+/// Test helper function to validate a GraphQL executable document string and return the error
+/// messages.
+fn error_messages(input: &str) -> Vec<String> {
+    validate(input)
+        .expect_err("validation succeeded")
+        .into_iter()
+        .map(|err| err.message)
+        .collect()
+}
+
+#[test]
+fn valid_document_passes() {
+    //* Given
+    let query = indoc! {r#"
+        query User($id: ID!, $size: Int = 64, $withFriends: Boolean!) {
+          user(id: $id) {
+            ...userFields
+            friends @include(if: $withFriends) { ...userFields }
+          }
+        }
+        query Viewer($filter: Filter) {
+          viewer { search(filter: { nested: [$filter] }) { id } }
+        }
+        fragment userFields on User {
+          name
+          ... on User { avatar(size: $size) }
+        }
+    "#};
+
+    //* When
+    let result = validate(query);
+
+    //* Then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn unknown_and_unused_fragments_are_reported() {
+    //* Given
+    let query = indoc! {r#"
+        { user { ...userFields ...missingFields } }
+        fragment userFields on User { name }
+        fragment unusedFields on User { name }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "unknown fragment missingFields",
+            "fragment unusedFields is never used",
+        ]
+    );
+}
+
+#[test]
+fn fragment_cycles_are_reported() {
+    //* Given
+    let query = indoc! {r#"
+        { user { ...a ...self } }
+        fragment a on User { ...b }
+        fragment b on User { friends { ...c } }
+        fragment c on User { ...a }
+        fragment self on User { ... on User { ...self } }
+    "#};
+
+    //* When
+    let result = validate(query);
+
+    //* Then
+    let errors = result.expect_err("validation succeeded");
+    let messages = errors
+        .iter()
+        .map(|err| err.message.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "cannot spread fragment a within itself via b, c",
+            "cannot spread fragment self within itself",
+        ]
+    );
+    assert_eq!(errors[0].locations.len(), 3);
+}
+
+#[test]
+fn duplicated_variables_are_reported() {
+    //* Given
+    let query = "query Q($id: ID, $id: ID) { user(id: $id) { name } }";
+
+    //* When
+    let result = validate(query);
+
+    //* Then
+    let errors = result.expect_err("validation succeeded");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "variable $id is defined twice");
+    assert_eq!(errors[0].locations[0].column, 19);
+    assert_eq!(errors[0].locations[1].column, 10);
+}
+
+#[test]
+fn undefined_variables_are_reported_through_fragments() {
+    //* Given
+    let query = indoc! {r#"
+        query User($id: ID) { user(id: $id) { ...userFields } }
+        query Other { user(id: $id) { ...userFields } }
+        fragment userFields on User { avatar(size: $size) @skip(if: $size) }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "variable $size is not defined by operation User",
+            "variable $id is not defined by operation Other",
+            "variable $size is not defined by operation Other",
+        ]
+    );
+}
+
+#[test]
+fn unused_variables_are_reported() {
+    //* Given
+    let query = indoc! {r#"
+        query ($id: ID, $unused: Int, $size: Int) { user(id: $id) { ...userFields } }
+        fragment userFields on User { avatar(size: $size) }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(messages, ["variable $unused is never used"]);
+}
+
+#[test]
+fn duplicated_arguments_are_reported() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          user(id: 1, id: 2) { name @include(if: true, if: false) }
+          other: user(id: 1) @skip(if: false) { name }
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "argument id is provided twice",
+            "argument if is provided twice"
+        ]
+    );
+}
+
+#[test]
+fn duplicated_operations_and_fragments_are_reported() {
+    //* Given
+    // The parser rejects the clashing definitions, so they are recorded as if the document was
+    // built from several sources.
+    let mut document = graphql_toolkit_parser::parse_query(indoc! {r#"
+        query User { user { ...userFields } }
+        fragment userFields on User { name }
+    "#})
+    .expect("query parsing failed");
+    document.name_clashes = vec![
+        NameClash::Operation {
+            name: Name::new("User"),
+            first: Pos { line: 1, column: 1 },
+            second: Pos { line: 3, column: 1 },
+        },
+        NameClash::Fragment {
+            name: Name::new("userFields"),
+            first: Pos { line: 2, column: 1 },
+            second: Pos { line: 4, column: 1 },
+        },
+    ];
+
+    //* When
+    let result = validate_executable_document(&document);
+
+    //* Then
+    let errors = result.expect_err("validation succeeded");
+    let messages = errors.iter().map(|err| &err.message).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "operation User is defined twice",
+            "fragment userFields is defined twice"
+        ]
+    );
+    assert_eq!(errors[0].locations[0].line, 3);
+    assert_eq!(errors[0].locations[1].line, 1);
+    assert_eq!(errors[1].locations[0].line, 4);
+    assert_eq!(errors[1].locations[1].line, 2);
+}
+
+#[test]
+fn anonymous_operation_with_other_operations_is_reported() {
+    //* Given
+    let mut document = graphql_toolkit_parser::parse_query("query User { user { name } }")
+        .expect("query parsing failed");
+    document.name_clashes = vec![NameClash::AnonymousOperation {
+        anonymous: Pos { line: 2, column: 1 },
+        operation: Pos { line: 1, column: 1 },
+    }];
+
+    //* When
+    let result = validate_executable_document(&document);
+
+    //* Then
+    let errors = result.expect_err("validation succeeded");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "anonymous operation must be the only operation in the document"
+    );
+    assert_eq!(errors[0].locations[0].line, 2);
+    assert_eq!(errors[0].locations[1].line, 1);
+}
+
+#[test]
+fn duplicated_input_fields_are_reported() {
+    //* Given
+    let query = "{ search(filter: { name: \"a\", nested: { id: 1, id: 2 }, name: \"b\" }) }";
+
+    //* When
+    let result = validate(query);
+
+    //* Then
+    let errors = result.expect_err("validation succeeded");
+    let messages = errors.iter().map(|err| &err.message).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "input field id is defined twice",
+            "input field name is defined twice"
+        ]
+    );
+    assert_eq!(errors[0].locations[0].column, 48);
+    assert_eq!(errors[0].locations[1].column, 41);
+    assert_eq!(errors[1].locations[0].column, 57);
+    assert_eq!(errors[1].locations[1].column, 20);
+}
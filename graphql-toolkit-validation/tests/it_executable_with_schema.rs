@@ -0,0 +1,408 @@
+use graphql_toolkit_schema::Schema;
+use graphql_toolkit_validation::{validate_executable_document_with_schema, Error};
+use indoc::indoc;
+
+const SCHEMA: &str = indoc! {r#"
+    type Query {
+      user(id: ID!): User
+      node(id: ID!): Node
+      search(term: String!, first: Int = 10): [SearchResult!]!
+      pet: Pet
+      users(filter: Filter, ids: [ID!], first: Int, active: Boolean! = true): [User!]!
+      findUser(input: UserInput!): User
+      lookup(by: Lookup!): User
+    }
+    interface Node { id: ID! }
+    type User implements Node {
+      id: ID!
+      name: String
+      role: Role
+      avatar(size: Int! = 64, format: String): String
+      friends(first: Int!): [User!]!
+    }
+    type Image implements Node { id: ID! url: String! }
+    type Dog { name: String barks: Boolean }
+    type Cat { name: String meows: Boolean }
+    union Pet = Dog | Cat
+    union SearchResult = User | Image
+    enum Role { ADMIN USER }
+    input Filter { role: Role }
+    input UserInput { name: String! tags: [String!] limit: Int = 10 }
+    input Lookup @oneOf { id: ID name: String }
+    directive @cached(ttl: Int!) on QUERY | FIELD
+    directive @tag(name: String!) repeatable on FIELD | FRAGMENT_SPREAD
+"#};
+
+/// Test helper function to parse a GraphQL executable document string, and validate it against
+/// the test schema.
+///
+/// # Panics
+/// The function asserts that the schema and the document are valid by checking the results of
+/// the `parse_schema` and `parse_query` functions, and of the schema build.
+fn validate(input: &str) -> Result<(), Vec<Error>> {
+    let schema = graphql_toolkit_parser::parse_schema(SCHEMA).expect("schema parsing failed");
+    let schema = Schema::from_document(schema).expect("schema build failed");
+    let document = graphql_toolkit_parser::parse_query(input).expect("query parsing failed");
+    validate_executable_document_with_schema(&schema, &document)
+}
+
+/// Test helper function to validate a GraphQL executable document string and return the error
+/// messages.
+fn error_messages(input: &str) -> Vec<String> {
+    validate(input)
+        .expect_err("validation succeeded")
+        .into_iter()
+        .map(|err| err.message)
+        .collect()
+}
+
+#[test]
+fn valid_document_passes() {
+    //* Given
+    let query = indoc! {r#"
+        query Search($term: String!) {
+          __typename
+          __type(name: "User") { name }
+          search(term: $term) {
+            __typename
+            ... on Node { id }
+            ...userFields
+          }
+          pet { ... on Dog { barks } ... on Cat { meows } ... { __typename } }
+          node(id: 1) { ... on User { friends(first: 1) { id } } }
+          user(id: 1) @skip(if: false) { avatar }
+        }
+        query Other($id: ID!) { user(id: $id) { ...userFields } }
+        fragment userFields on User { name role @include(if: true) }
+    "#};
+
+    //* When
+    let result = validate(query);
+
+    //* Then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn unknown_fields_are_reported() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          __schema { types { name } }
+          user(id: 1) { name email __schema { types { name } } }
+          pet { name }
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "unknown field email on type User",
+            "unknown field __schema on type User",
+            "unknown field name on type Pet",
+        ]
+    );
+}
+
+#[test]
+fn arguments_are_checked() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          user { friends(first: 1, after: 2) { id } avatar }
+          search(first: 1) { __typename }
+          node(id: 1) @include(unless: true) { id }
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "field Query.user argument id of type ID! is required but not provided",
+            "unknown argument after on field User.friends",
+            "field Query.search argument term of type String! is required but not provided",
+            "unknown argument unless on directive @include",
+            "directive @include argument if of type Boolean! is required but not provided",
+        ]
+    );
+}
+
+#[test]
+fn leaf_selections_are_checked() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          user(id: 1) { name { length } friends(first: 1) }
+          pet
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "field name of type String must not have a selection set",
+            "field friends of type [User!]! must have a selection set",
+            "field pet of type Pet must have a selection set",
+        ]
+    );
+}
+
+#[test]
+fn type_conditions_are_checked() {
+    //* Given
+    let query = indoc! {r#"
+        query ($id: UUID) {
+          user(id: $id) { ... on Account { id } ... on Role { name } ...imageFields }
+        }
+        fragment imageFields on Image { url }
+        fragment roleFields on Role { name }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "unknown type UUID",
+            "unknown type Account",
+            "inline fragment cannot condition on non-composite type Role",
+            "fragment imageFields cannot be spread here as objects of type User can never be of \
+             type Image",
+            "fragment roleFields cannot condition on non-composite type Role",
+            "fragment roleFields is never used",
+        ]
+    );
+}
+
+#[test]
+fn abstract_fragment_spreads_are_checked() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          search(term: "") { ... on Node { id } ... on Dog { name } }
+          pet { ... on Node { id } ...dogFields }
+        }
+        fragment dogFields on Dog { barks }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "inline fragment cannot be spread here as objects of type SearchResult can never be \
+             of type Dog",
+            "inline fragment cannot be spread here as objects of type Pet can never be of type \
+             Node",
+        ]
+    );
+}
+
+#[test]
+fn variables_must_be_input_types() {
+    //* Given
+    let query = "query ($user: User, $users: [User!], $role: Role) { user(id: 1) { name } }";
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "variable $user is never used",
+            "variable $users is never used",
+            "variable $role is never used",
+            "variable $user cannot be of non-input type User",
+            "variable $users cannot be of non-input type [User!]",
+        ]
+    );
+}
+
+#[test]
+fn overlapping_fields_are_reported() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          user(id: 1) { name: role name }
+          other: user(id: 1) { id }
+          other: user(id: 2) { id }
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "fields other conflict because they have differing arguments; use different aliases \
+             on the fields to fetch both if this was intentional",
+            "fields name conflict because role and name are different fields; use different \
+             aliases on the fields to fetch both if this was intentional",
+        ]
+    );
+}
+
+#[test]
+fn overlapping_subfields_are_reported_through_fragments() {
+    //* Given
+    let query = indoc! {r#"
+        { ...first ...second }
+        fragment first on Query { user(id: 1) { key: name } }
+        fragment second on Query { user(id: 1) { key: id } }
+    "#};
+
+    //* When
+    let result = validate(query);
+
+    //* Then
+    let errors = result.expect_err("validation succeeded");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "fields user conflict because subfields key conflict because name and id are different \
+         fields; use different aliases on the fields to fetch both if this was intentional"
+    );
+    assert_eq!(errors[0].locations.len(), 4);
+}
+
+#[test]
+fn overlapping_fields_of_exclusive_types_must_have_compatible_types() {
+    //* Given
+    let query = indoc! {r#"
+        {
+          pet {
+            ... on Dog { flag: barks detail: name }
+            ... on Cat { flag: meows detail: meows }
+          }
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "fields detail conflict because they return conflicting types String and Boolean; use \
+          different aliases on the fields to fetch both if this was intentional"
+        ]
+    );
+}
+
+#[test]
+fn literal_values_are_checked() {
+    //* Given
+    let query = indoc! {r#"
+        query ($first: Int = "ten") {
+          users(filter: { role: OWNER, age: 3 }, ids: [1, "2", 3.5], first: $first) { id }
+          a: users(ids: 1, first: 2147483648) { id }
+          b: users(active: null) { id }
+          findUser(input: { tags: "admin" }) { id }
+          lookup(by: { id: 1, name: "x" }) { id }
+          c: lookup(by: { id: null }) { id }
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "expected value of type Int, found \"ten\"",
+            "expected value of type Role, found OWNER",
+            "field age is not defined by type Filter",
+            "expected value of type ID!, found 3.5",
+            "expected value of type Int, found 2147483648",
+            "expected value of type Boolean!, found null",
+            "field UserInput.name of required type String! was not provided",
+            "oneOf input object Lookup must specify exactly one field",
+            "field Lookup.id must be non-null",
+        ]
+    );
+}
+
+#[test]
+fn variables_must_be_in_allowed_positions() {
+    //* Given
+    let query = indoc! {r#"
+        query (
+          $active: Boolean,
+          $ids: [ID],
+          $id: ID!,
+          $name: String,
+          $limit: Int,
+          $withDefault: String = "x",
+        ) {
+          users(active: $active, ids: $ids) { id }
+          a: users(ids: [$id], first: $limit) { id }
+          findUser(input: { name: $name, limit: $limit }) { ...userFields }
+          b: findUser(input: { name: $withDefault }) { id }
+        }
+        fragment userFields on User { friends(first: $limit) { id } }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "variable $ids of type [ID] used in position expecting type [ID!]",
+            "variable $name of type String used in position expecting type String!",
+            "variable $limit of type Int used in position expecting type Int!",
+        ]
+    );
+}
+
+#[test]
+fn directives_are_checked() {
+    //* Given
+    let query = indoc! {r#"
+        query ($flag: Boolean!) @cached(ttl: 10) @cached(ttl: 20) @tag(name: "q") {
+          user(id: 1) @cached(ttl: "long") @tag(name: "a") @tag(name: "b") @unknown {
+            ...userFields @skip(if: $flag) @skip(if: true)
+          }
+        }
+        fragment userFields on User @cached { name }
+    "#};
+
+    //* When
+    let messages = error_messages(query);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "directive @cached can only be used once at this location",
+            "directive @tag may not be used on QUERY",
+            "expected value of type Int!, found \"long\"",
+            "unknown directive @unknown",
+            "directive @skip can only be used once at this location",
+            "directive @cached argument ttl of type Int! is required but not provided",
+            "directive @cached may not be used on FRAGMENT_DEFINITION",
+        ]
+    );
+}
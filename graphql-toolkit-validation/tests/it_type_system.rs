@@ -0,0 +1,230 @@
+use graphql_toolkit_validation::{validate_service_document, Error};
+use indoc::indoc;
+
+/// Test helper function to parse and validate a GraphQL SDL string.
+///
+/// # Panics
+/// The function asserts that the input is valid SDL by checking the result of the
+/// `parse_schema` function.
+fn validate(input: &str) -> Result<(), Vec<Error>> {
+    let document = graphql_toolkit_parser::parse_schema(input).expect("schema parsing failed");
+    validate_service_document(&document)
+}
+
+/// Test helper function to validate a GraphQL SDL string and return the error messages.
+fn error_messages(input: &str) -> Vec<String> {
+    validate(input)
+        .expect_err("validation succeeded")
+        .into_iter()
+        .map(|err| err.message)
+        .collect()
+}
+
+#[test]
+fn valid_schema_passes() {
+    //* Given
+    let sdl = indoc! {r#"
+        schema { query: Query }
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! url(size: Int): String }
+        type Image implements Resource & Node {
+          id: ID!
+          url(size: Int, format: String = "png"): String!
+        }
+        union SearchResult = Image
+        enum Role { ADMIN USER }
+        input Filter { role: Role next: Filter }
+        input Selector @oneOf { id: ID name: String }
+        type Query { search(filter: Filter, selector: Selector): [SearchResult!]! node: Node }
+    "#};
+
+    //* When
+    let result = validate(sdl);
+
+    //* Then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn missing_query_root_is_reported() {
+    //* Given
+    let sdl = "type User { id: ID! }";
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(messages, ["query root type must be provided"]);
+}
+
+#[test]
+fn interface_implementations_are_checked() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { node: Node }
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! url(size: Int): String }
+        type Image implements Resource {
+          id: ID
+          url(size: String, format: String!): String
+        }
+    "#};
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "type Image must implement Node because it is implemented by Resource",
+            "interface field Resource.id expects type ID! but Image.id is type ID",
+            "interface field argument Resource.url(size:) expects type Int but Image.url(size:) \
+             is type String",
+            "field argument Image.url(format:) is of required type String! but is not also \
+             provided by the interface Resource.url",
+        ]
+    );
+}
+
+#[test]
+fn missing_interface_fields_are_reported_with_positions() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { node: Node }
+        interface Node { id: ID! }
+        type User implements Node { name: String }
+    "#};
+
+    //* When
+    let errors = validate(sdl).expect_err("validation succeeded");
+
+    //* Then
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "interface field Node.id expected but User does not provide it"
+    );
+    assert_eq!(
+        errors[0]
+            .locations
+            .iter()
+            .map(|pos| pos.line)
+            .collect::<Vec<_>>(),
+        [3, 2]
+    );
+}
+
+#[test]
+fn union_members_must_be_object_types() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { result: Result }
+        type User { id: ID! }
+        union Result = User | String | User
+    "#};
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "union type Result can only include type User once",
+            "union type Result can only include object types, it cannot include String",
+        ]
+    );
+}
+
+#[test]
+fn input_object_cycles_through_non_null_fields_are_reported() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { a(input: A): Int }
+        input A { b: B! }
+        input B { a: A! list: [B!]! }
+    "#};
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(
+        messages,
+        ["input object A cannot reference itself through a series of non-null fields: b.a"]
+    );
+}
+
+#[test]
+fn reserved_names_are_reported() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { __field(__arg: Int): Int }
+        type __Type { a: Int }
+        directive @__dir on FIELD
+    "#};
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "field \"__field\" must not begin with \"__\", which is reserved by GraphQL \
+             introspection",
+            "input value \"__arg\" must not begin with \"__\", which is reserved by GraphQL \
+             introspection",
+            "type \"__Type\" must not begin with \"__\", which is reserved by GraphQL \
+             introspection",
+            "directive \"__dir\" must not begin with \"__\", which is reserved by GraphQL \
+             introspection",
+        ]
+    );
+}
+
+#[test]
+fn invalid_definitions_are_reported() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { user(filter: User): Filter }
+        type User { id: ID! id: ID! }
+        input Filter { id: ID }
+        enum Empty
+        input Choice @oneOf { id: ID! }
+        directive @tag(name: String @tag) on FIELD | FIELD
+    "#};
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(
+        messages,
+        [
+            "the type of Query.user must be an output type but got Filter",
+            "the type of Query.user(filter:) must be an input type but got User",
+            "field User.id can only be defined once",
+            "enum type Empty must define one or more values",
+            "oneOf input field Choice.id must be nullable and have no default value",
+            "directive @tag cannot reference itself",
+            "directive @tag can only include a location once",
+        ]
+    );
+}
+
+#[test]
+fn build_errors_are_reported() {
+    //* Given
+    let sdl = indoc! {r#"
+        type Query { a: Int }
+        extend type Missing { a: Int }
+    "#};
+
+    //* When
+    let messages = error_messages(sdl);
+
+    //* Then
+    assert_eq!(messages, ["extended type Missing is not defined"]);
+}
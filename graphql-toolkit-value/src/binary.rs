@@ -0,0 +1,90 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use bytes::Bytes;
+
+use crate::ConstValue;
+
+/// The representation used for [`ConstValue::Binary`] values when written as JSON or as a
+/// GraphQL literal.
+///
+/// Neither JSON nor GraphQL have a native binary type, so binary values must be encoded into one
+/// of the supported value kinds. Both the value serde implementation and the writer accept this
+/// type, so the two outputs agree on how bytes are represented.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BinaryEncoding {
+    /// A list of integers, one per byte (e.g., `[104, 105]`). This is the default.
+    #[default]
+    IntList,
+    /// A string with the standard, padded base64 encoding (e.g., `"aGk="`).
+    Base64,
+    /// A string with the lowercase hexadecimal encoding (e.g., `"6869"`).
+    Hex,
+}
+
+impl BinaryEncoding {
+    /// Encode the bytes as a string, or return `None` if this encoding is not string based.
+    #[must_use]
+    pub fn encode_to_string(&self, bytes: &[u8]) -> Option<String> {
+        match self {
+            Self::IntList => None,
+            Self::Base64 => Some(BASE64.encode(bytes)),
+            Self::Hex => Some(encode_hex(bytes)),
+        }
+    }
+
+    /// Encode the bytes as a value with this encoding.
+    #[must_use]
+    pub fn encode(&self, bytes: &[u8]) -> ConstValue {
+        match self.encode_to_string(bytes) {
+            Some(encoded) => ConstValue::String(encoded),
+            None => ConstValue::List(bytes.iter().map(|byte| ConstValue::from(*byte)).collect()),
+        }
+    }
+
+    /// Decode a value written with this encoding back into bytes.
+    ///
+    /// Returns `None` if the value is not a valid encoding of a binary value. Binary values are
+    /// returned as-is, regardless of the encoding.
+    #[must_use]
+    pub fn decode(&self, value: &ConstValue) -> Option<Bytes> {
+        match (self, value) {
+            (_, ConstValue::Binary(bytes)) => Some(bytes.clone()),
+            (Self::IntList, ConstValue::List(items)) => items
+                .iter()
+                .map(|item| match item {
+                    ConstValue::Number(n) => n.as_u64().and_then(|n| u8::try_from(n).ok()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<u8>>>()
+                .map(Bytes::from),
+            (Self::Base64, ConstValue::String(s)) => BASE64.decode(s).ok().map(Bytes::from),
+            (Self::Hex, ConstValue::String(s)) => decode_hex(s).map(Bytes::from),
+            _ => None,
+        }
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut encoded = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        encoded.push(DIGITS[(byte >> 4) as usize] as char);
+        encoded.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    encoded
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high << 4 | low) as u8)
+        })
+        .collect()
+}
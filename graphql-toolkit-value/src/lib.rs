@@ -4,6 +4,7 @@
 #![allow(clippy::uninlined_format_args)]
 #![forbid(unsafe_code)]
 
+mod binary;
 mod deserializer;
 mod macros;
 mod serializer;
@@ -17,6 +18,7 @@ use std::{
     sync::Arc,
 };
 
+pub use binary::BinaryEncoding;
 use bytes::Bytes;
 pub use deserializer::{from_value, DeserializerError};
 #[doc(hidden)]
@@ -25,6 +27,7 @@ use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub use serde_json::Number;
 pub use serializer::{to_value, SerializerError};
+pub use value_serde::BinaryEncoded;
 pub use variables::Variables;
 
 /// A GraphQL name.
@@ -324,6 +327,23 @@ impl ConstValue {
     pub fn from_json(json: serde_json::Value) -> serde_json::Result<Self> {
         json.try_into()
     }
+
+    /// Wrap the value so it serializes binary values with the given encoding.
+    ///
+    /// Use [`BinaryEncoding::decode`] to turn the encoded values back into bytes.
+    #[must_use]
+    pub fn with_binary_encoding(&self, encoding: BinaryEncoding) -> BinaryEncoded<'_, Self> {
+        BinaryEncoded::new(self, encoding)
+    }
+
+    /// Attempt to convert the value to JSON, writing binary values with the given encoding.
+    ///
+    /// # Errors
+    ///
+    /// Fails if serialization fails (see enum docs for more info).
+    pub fn to_json_with(&self, encoding: BinaryEncoding) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self.with_binary_encoding(encoding))
+    }
 }
 
 impl Default for ConstValue {
@@ -455,6 +475,21 @@ impl Value {
     pub fn from_json(json: serde_json::Value) -> serde_json::Result<Self> {
         json.try_into()
     }
+
+    /// Wrap the value so it serializes binary values with the given encoding.
+    #[must_use]
+    pub fn with_binary_encoding(&self, encoding: BinaryEncoding) -> BinaryEncoded<'_, Self> {
+        BinaryEncoded::new(self, encoding)
+    }
+
+    /// Attempt to convert the value to JSON, writing binary values with the given encoding.
+    ///
+    /// # Errors
+    ///
+    /// Fails if serialization fails (see enum docs for more info).
+    pub fn to_json_with(&self, encoding: BinaryEncoding) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self.with_binary_encoding(encoding))
+    }
 }
 
 impl Default for Value {
@@ -7,7 +7,7 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::{ConstValue, Name, Number, Value};
+use crate::{BinaryEncoding, ConstValue, Name, Number, Value};
 
 impl Serialize for ConstValue {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -312,6 +312,63 @@ impl<'de> Deserialize<'de> for Value {
     }
 }
 
+/// A wrapper that serializes a [`ConstValue`] or [`Value`], writing binary values with the given
+/// [`BinaryEncoding`].
+///
+/// Constructed from the `with_binary_encoding` method of the value types.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryEncoded<'a, T> {
+    value: &'a T,
+    encoding: BinaryEncoding,
+}
+
+impl<'a, T> BinaryEncoded<'a, T> {
+    pub(crate) fn new(value: &'a T, encoding: BinaryEncoding) -> Self {
+        Self { value, encoding }
+    }
+
+    fn wrap<U>(&self, value: &'a U) -> BinaryEncoded<'a, U> {
+        BinaryEncoded::new(value, self.encoding)
+    }
+}
+
+fn serialize_binary<S: Serializer>(
+    bytes: &[u8],
+    encoding: BinaryEncoding,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match encoding.encode_to_string(bytes) {
+        Some(encoded) => serializer.serialize_str(&encoded),
+        None => serializer.serialize_bytes(bytes),
+    }
+}
+
+impl<'a> Serialize for BinaryEncoded<'a, ConstValue> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            ConstValue::Binary(v) => serialize_binary(v, self.encoding, serializer),
+            ConstValue::List(v) => serializer.collect_seq(v.iter().map(|item| self.wrap(item))),
+            ConstValue::Object(v) => {
+                serializer.collect_map(v.iter().map(|(key, value)| (key, self.wrap(value))))
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for BinaryEncoded<'a, Value> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            Value::Binary(v) => serialize_binary(v, self.encoding, serializer),
+            Value::List(v) => serializer.collect_seq(v.iter().map(|item| self.wrap(item))),
+            Value::Object(v) => {
+                serializer.collect_map(v.iter().map(|(key, value)| (key, self.wrap(value))))
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
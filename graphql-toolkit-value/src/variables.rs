@@ -4,7 +4,10 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{
+    de::{self, Unexpected},
+    Deserialize, Deserializer, Serialize,
+};
 
 use crate::{ConstValue, Name};
 
@@ -46,12 +49,35 @@ impl Variables {
     /// Get the values from a JSON value.
     ///
     /// If the value is not a map or the keys of a map are not valid GraphQL
-    /// names, then no variables will be returned.
+    /// names, then no variables will be returned. Use [`Variables::try_from_json`]
+    /// to reject such values instead.
     #[must_use]
     pub fn from_json(value: serde_json::Value) -> Self {
-        ConstValue::from_json(value)
-            .map(Self::from_value)
-            .unwrap_or_default()
+        Self::try_from_json(value).unwrap_or_default()
+    }
+
+    /// Attempt to get the values from a JSON value.
+    ///
+    /// A `null` value is an empty set of variables, as in a request without variables.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value is neither a map nor `null`, or if the conversion of the map fails
+    /// (see [`ConstValue::from_json`]).
+    pub fn try_from_json(value: serde_json::Value) -> serde_json::Result<Self> {
+        let unexpected = match &value {
+            serde_json::Value::Null => return Ok(Self::default()),
+            serde_json::Value::Object(_) => None,
+            serde_json::Value::Bool(value) => Some(Unexpected::Bool(*value)),
+            serde_json::Value::Number(_) => Some(Unexpected::Other("number")),
+            serde_json::Value::String(value) => Some(Unexpected::Str(value)),
+            serde_json::Value::Array(_) => Some(Unexpected::Seq),
+        };
+        if let Some(unexpected) = unexpected {
+            return Err(de::Error::invalid_type(unexpected, &"a map of variables"));
+        }
+
+        ConstValue::from_json(value).map(Self::from_value)
     }
 
     /// Get the variables as a GraphQL value.
@@ -66,3 +66,50 @@ fn test_binary() {
         Bytes::from_static(b"123456")
     );
 }
+
+#[test]
+fn test_binary_encoding() {
+    let bytes = Bytes::from_static(b"\x00graphql\xff");
+    let value = ConstValue::List(vec![ConstValue::Binary(bytes.clone())]);
+
+    for (encoding, expected) in [
+        (
+            BinaryEncoding::IntList,
+            serde_json::json!([[0, 103, 114, 97, 112, 104, 113, 108, 255]]),
+        ),
+        (BinaryEncoding::Base64, serde_json::json!(["AGdyYXBocWz/"])),
+        (
+            BinaryEncoding::Hex,
+            serde_json::json!(["006772617068716cff"]),
+        ),
+    ] {
+        let json = value.to_json_with(encoding).unwrap();
+        assert_eq!(json, expected);
+
+        let ConstValue::List(items) = ConstValue::from_json(json).unwrap() else {
+            panic!("expected a list");
+        };
+        assert_eq!(encoding.decode(&items[0]), Some(bytes.clone()));
+        assert_eq!(encoding.encode(&bytes), items[0]);
+    }
+
+    assert_eq!(BinaryEncoding::Hex.decode(&ConstValue::from("0g")), None);
+    assert_eq!(BinaryEncoding::Base64.decode(&ConstValue::from(1)), None);
+}
+
+#[test]
+fn test_variables_try_from_json() {
+    let variables = Variables::try_from_json(serde_json::json!({ "id": 1 })).unwrap();
+    assert_eq!(variables.get("id"), Some(&ConstValue::Number(1.into())));
+    assert!(Variables::try_from_json(serde_json::Value::Null)
+        .unwrap()
+        .is_empty());
+
+    let err = Variables::try_from_json(serde_json::json!([1, 2])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: sequence, expected a map of variables"
+    );
+    assert!(Variables::try_from_json(serde_json::json!("id")).is_err());
+    assert!(Variables::from_json(serde_json::json!(1)).is_empty());
+}
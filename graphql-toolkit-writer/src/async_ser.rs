@@ -0,0 +1,103 @@
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    error::Result,
+    fmt::{compact::CompactFormatter, formatter::Formatter, pretty::PrettyFormatter},
+    ser::{Serialize, Serializer},
+    BinaryEncoding,
+};
+
+/// A structure for serializing Rust GraphQL AST types to GraphQL documents into a
+/// [`tokio::io::AsyncWrite`] sink.
+///
+/// The formatters are synchronous, so each value is first rendered into an in-memory buffer,
+/// and then written to the sink with a single `write_all` call. The buffer is reused across
+/// calls to [`serialize`](AsyncSerializer::serialize), so serializing many documents with the
+/// same serializer does not re-allocate.
+pub struct AsyncSerializer<W, F> {
+    writer: W,
+    ser: Serializer<Vec<u8>, F>,
+}
+
+impl<W> AsyncSerializer<W, CompactFormatter>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Create a new serializer that writes to the given async sink using the compact formatter.
+    pub fn new(writer: W) -> Self {
+        AsyncSerializer::with_formatter(writer, CompactFormatter)
+    }
+}
+
+impl<W, F> AsyncSerializer<W, F>
+where
+    W: AsyncWrite + Unpin,
+    F: Formatter,
+{
+    /// Create a new serializer with a custom formatter.
+    pub fn with_formatter(writer: W, formatter: F) -> Self {
+        Self {
+            writer,
+            ser: Serializer::with_formatter(Vec::with_capacity(128), formatter),
+        }
+    }
+
+    /// Set the encoding used to write binary values.
+    ///
+    /// See [`Serializer::with_binary_encoding`].
+    #[must_use]
+    pub fn with_binary_encoding(mut self, encoding: BinaryEncoding) -> Self {
+        self.ser = self.ser.with_binary_encoding(encoding);
+        self
+    }
+
+    /// Serialize the given GraphQL AST and write it to the async sink.
+    ///
+    /// Nothing is written to the sink if the serialization fails.
+    pub async fn serialize<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.ser.writer_mut().clear();
+        value.serialize(&mut self.ser)?;
+        self.writer.write_all(self.ser.writer_mut()).await?;
+        Ok(())
+    }
+
+    /// Flush the async sink.
+    pub async fn flush(&mut self) -> Result<()> {
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Consume the serializer, returning the wrapped async sink.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Serialize the given GraphQL AST as a compact GraphQL document into the async sink.
+///
+/// The sink is flushed once the document has been written.
+pub async fn to_async_writer<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: ?Sized + Serialize,
+{
+    let mut ser = AsyncSerializer::new(writer);
+    ser.serialize(value).await?;
+    ser.flush().await
+}
+
+/// Serialize the given GraphQL AST as a pretty-printed GraphQL document into the async sink.
+///
+/// The sink is flushed once the document has been written.
+pub async fn to_async_writer_pretty<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: ?Sized + Serialize,
+{
+    let mut ser = AsyncSerializer::with_formatter(writer, PrettyFormatter::new());
+    ser.serialize(value).await?;
+    ser.flush().await
+}
@@ -0,0 +1,105 @@
+use std::{fmt, io, str};
+
+use crate::{
+    fmt::{compact::CompactFormatter, pretty::PrettyFormatter},
+    ser::{Serialize, Serializer},
+};
+
+/// An adapter that allows a [`fmt::Write`] sink, like a `String` or a `fmt::Formatter`, to be
+/// used as the [`io::Write`] writer of a [`Serializer`].
+///
+/// The serializer always writes complete UTF-8 fragments, so no re-encoding takes place.
+#[derive(Debug)]
+pub struct FmtWriter<W> {
+    inner: W,
+}
+
+impl<W> FmtWriter<W>
+where
+    W: fmt::Write,
+{
+    /// Wrap the given `fmt::Write` sink.
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the adapter, returning the wrapped `fmt::Write` sink.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> io::Write for FmtWriter<W>
+where
+    W: fmt::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let fragment =
+            str::from_utf8(buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.inner
+            .write_str(fragment)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> Serializer<FmtWriter<W>, CompactFormatter>
+where
+    W: fmt::Write,
+{
+    /// Create a new serializer that writes to the given `fmt::Write` sink using the compact
+    /// formatter.
+    pub fn from_fmt_writer(writer: W) -> Self {
+        Serializer::new(FmtWriter::new(writer))
+    }
+}
+
+/// A wrapper that implements [`fmt::Display`] for any serializable GraphQL AST.
+///
+/// The default format writes a compact GraphQL document, and the alternate format (`{:#}`)
+/// writes a pretty-printed GraphQL document.
+///
+/// Constructed from the [`display`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Display<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+/// Wrap the given GraphQL AST so it can be printed with `format!` and friends.
+///
+/// ```
+/// use graphql_toolkit_writer::display;
+///
+/// let document = graphql_toolkit_parser::parse_query("{ user { name } }").unwrap();
+/// assert_eq!(format!("{}", display(&document)), "{user{name}}");
+/// ```
+pub fn display<T>(value: &T) -> Display<'_, T>
+where
+    T: ?Sized + Serialize,
+{
+    Display { value }
+}
+
+impl<'a, T> fmt::Display for Display<'a, T>
+where
+    T: ?Sized + Serialize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pretty = f.alternate();
+        let writer = FmtWriter::new(f);
+
+        let result = if pretty {
+            let mut ser = Serializer::with_formatter(writer, PrettyFormatter::new());
+            self.value.serialize(&mut ser)
+        } else {
+            let mut ser = Serializer::new(writer);
+            self.value.serialize(&mut ser)
+        };
+
+        result.map_err(|_| fmt::Error)
+    }
+}
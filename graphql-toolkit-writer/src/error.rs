@@ -0,0 +1,55 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+use graphql_toolkit_ast::Name;
+
+/// Writer error.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An I/O error occurred while writing to the underlying writer.
+    Io(io::Error),
+    /// A floating point number is NaN or infinite, and has no GraphQL representation.
+    NonFiniteFloat(f64),
+    /// A name is not a valid GraphQL name.
+    ///
+    /// [Reference](https://spec.graphql.org/October2021/#Name).
+    InvalidName(Name),
+    /// A binary value cannot be represented by the formatter.
+    UnprintableBinary,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "io error: {}", err),
+            Self::NonFiniteFloat(value) => {
+                write!(f, "float {} is not a finite number", value)
+            }
+            Self::InvalidName(name) => write!(f, "{:?} is not a valid GraphQL name", name.as_str()),
+            Self::UnprintableBinary => {
+                f.write_str("binary values are not supported by the formatter")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// An alias for `Result<T, Error>`.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -1,3 +1,6 @@
+pub mod ansi;
 pub mod compact;
 pub mod formatter;
+pub mod highlight;
+pub mod html;
 pub mod pretty;
@@ -0,0 +1,143 @@
+use std::io;
+
+use super::{
+    formatter::Formatter,
+    highlight::{HighlightFormatter, Highlighter, Token},
+};
+
+/// A formatter that highlights GraphQL documents with ANSI color escape sequences.
+///
+/// The layout is delegated to the wrapped formatter, for example:
+///
+/// ```
+/// use graphql_toolkit_writer::{AnsiFormatter, PrettyFormatter, Serializer};
+///
+/// let mut out = Vec::new();
+/// let mut ser = Serializer::with_formatter(&mut out, AnsiFormatter::new(PrettyFormatter::new()));
+/// ```
+pub type AnsiFormatter<F> = HighlightFormatter<F, AnsiHighlighter>;
+
+impl<F> AnsiFormatter<F>
+where
+    F: Formatter,
+{
+    /// Construct an ANSI highlighting formatter that uses the default theme.
+    pub fn new(inner: F) -> Self {
+        Self::with_highlighter(inner, AnsiHighlighter::default())
+    }
+
+    /// Construct an ANSI highlighting formatter that uses the given theme.
+    pub fn with_theme(inner: F, theme: AnsiTheme) -> Self {
+        Self::with_highlighter(inner, AnsiHighlighter::new(theme))
+    }
+}
+
+/// The [SGR](https://en.wikipedia.org/wiki/ANSI_escape_code#SGR) parameters used to highlight
+/// each kind of token, for example `"1;34"` for bold blue.
+///
+/// An empty string leaves that kind of token unhighlighted.
+#[derive(Clone, Debug)]
+pub struct AnsiTheme {
+    /// Keywords, booleans and `null`. Defaults to magenta.
+    pub keyword: &'static str,
+    /// Operation and fragment names. Defaults to bold yellow.
+    pub name: &'static str,
+    /// Field names and aliases. Defaults to blue.
+    pub field_name: &'static str,
+    /// Type names. Defaults to yellow.
+    pub type_name: &'static str,
+    /// Argument and input object field names. Defaults to cyan.
+    pub argument_name: &'static str,
+    /// Variables. Defaults to red.
+    pub variable: &'static str,
+    /// Directives. Defaults to bright magenta.
+    pub directive: &'static str,
+    /// Enum values. Defaults to bright cyan.
+    pub enum_value: &'static str,
+    /// String values. Defaults to green.
+    pub string: &'static str,
+    /// Numeric values. Defaults to bright blue.
+    pub number: &'static str,
+    /// Punctuators. Defaults to dimmed.
+    pub punctuation: &'static str,
+}
+
+impl AnsiTheme {
+    /// Get the SGR parameters for the given kind of token.
+    #[must_use]
+    pub fn style(&self, token: Token) -> &'static str {
+        match token {
+            Token::Keyword => self.keyword,
+            Token::Name => self.name,
+            Token::FieldName => self.field_name,
+            Token::TypeName => self.type_name,
+            Token::ArgumentName => self.argument_name,
+            Token::Variable => self.variable,
+            Token::Directive => self.directive,
+            Token::EnumValue => self.enum_value,
+            Token::String => self.string,
+            Token::Number => self.number,
+            Token::Punctuation => self.punctuation,
+        }
+    }
+}
+
+impl Default for AnsiTheme {
+    fn default() -> Self {
+        Self {
+            keyword: "35",
+            name: "1;33",
+            field_name: "34",
+            type_name: "33",
+            argument_name: "36",
+            variable: "31",
+            directive: "95",
+            enum_value: "96",
+            string: "32",
+            number: "94",
+            punctuation: "2",
+        }
+    }
+}
+
+/// A highlighter that surrounds each token with ANSI color escape sequences.
+#[derive(Clone, Debug, Default)]
+pub struct AnsiHighlighter {
+    theme: AnsiTheme,
+}
+
+impl AnsiHighlighter {
+    /// Construct an ANSI highlighter that uses the given theme.
+    pub fn new(theme: AnsiTheme) -> Self {
+        Self { theme }
+    }
+}
+
+impl Highlighter for AnsiHighlighter {
+    #[inline]
+    fn begin_token<W>(&mut self, writer: &mut W, token: Token) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let style = self.theme.style(token);
+        if style.is_empty() {
+            return Ok(());
+        }
+
+        writer.write_all(b"\x1b[")?;
+        writer.write_all(style.as_bytes())?;
+        writer.write_all(b"m")
+    }
+
+    #[inline]
+    fn end_token<W>(&mut self, writer: &mut W, token: Token) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if self.theme.style(token).is_empty() {
+            return Ok(());
+        }
+
+        writer.write_all(b"\x1b[0m")
+    }
+}
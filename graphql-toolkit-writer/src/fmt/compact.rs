@@ -1,7 +1,11 @@
-use std::io;
+use std::{fmt, io};
 
 use super::formatter::Formatter;
-use crate::ser::{Serialize, Serializer};
+use crate::{
+    display::FmtWriter,
+    error::Result,
+    ser::{Serialize, Serializer},
+};
 
 /// A compact document formatter that writes GraphQL documents with no extra whitespace.
 ///
@@ -9,7 +13,12 @@ use crate::ser::{Serialize, Serializer};
 #[derive(Clone, Debug)]
 pub struct CompactFormatter;
 
-impl Formatter for CompactFormatter {}
+impl Formatter for CompactFormatter {
+    #[inline]
+    fn supports_binary(&self) -> bool {
+        true
+    }
+}
 
 impl<W> Serializer<W, CompactFormatter>
 where
@@ -23,7 +32,7 @@ where
 
 /// Serialize the given GraphQL AST as a compact GraphQL document into the I/O stream.
 #[inline]
-pub fn to_writer<W, T>(writer: W, value: &T) -> anyhow::Result<()>
+pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
 where
     W: io::Write,
     T: ?Sized + Serialize,
@@ -31,9 +40,20 @@ where
     let mut ser = Serializer::new(writer);
     value.serialize(&mut ser)
 }
+
+/// Serialize the given GraphQL AST as a compact GraphQL document into the `fmt::Write` sink.
+#[inline]
+pub fn to_fmt_writer<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: fmt::Write,
+    T: ?Sized + Serialize,
+{
+    to_writer(FmtWriter::new(writer), value)
+}
+
 /// Serialize the given GraphQL AST as a compact GraphQL document byte vector.
 #[inline]
-pub fn to_vec<T>(value: &T) -> anyhow::Result<Vec<u8>>
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
 where
     T: ?Sized + Serialize,
 {
@@ -44,7 +64,7 @@ where
 
 /// Serialize the given GraphQL AST as a compact GraphQL document string.
 #[inline]
-pub fn to_string<T>(value: &T) -> anyhow::Result<String>
+pub fn to_string<T>(value: &T) -> Result<String>
 where
     T: ?Sized + Serialize,
 {
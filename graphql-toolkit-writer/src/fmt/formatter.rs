@@ -11,6 +11,7 @@ pub trait Formatter {
         writer.write_all(fragment.as_bytes())
     }
 
+    /// Writes a GraphQL keyword, such as `query`, `fragment` or `on`, to the specified writer.
     #[inline]
     fn write_keyword<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
     where
@@ -19,6 +20,89 @@ pub trait Formatter {
         writer.write_all(name.as_bytes())
     }
 
+    /// Writes a GraphQL name to the specified writer.
+    ///
+    /// This is used for operation and fragment names. The more specific `write_*_name` methods
+    /// default to this one.
+    #[inline]
+    fn write_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(name.as_bytes())
+    }
+
+    /// Writes the name, or the alias, of a selected field to the specified writer.
+    #[inline]
+    fn write_field_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_name(writer, name)
+    }
+
+    /// Writes a type name to the specified writer.
+    ///
+    /// This is used for variable types and fragment type conditions.
+    #[inline]
+    fn write_type_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_name(writer, name)
+    }
+
+    /// Writes an argument name, or an input object field name, to the specified writer.
+    #[inline]
+    fn write_argument_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_name(writer, name)
+    }
+
+    /// Writes a variable name, without the preceding `$`, to the specified writer.
+    ///
+    /// This is always called after [`Formatter::begin_variable`].
+    #[inline]
+    fn write_variable_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_name(writer, name)
+    }
+
+    /// Writes a directive name, without the preceding `@`, to the specified writer.
+    ///
+    /// This is always called after [`Formatter::begin_directive`].
+    #[inline]
+    fn write_directive_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_name(writer, name)
+    }
+
+    /// Writes an enum value, such as `ADMIN`, to the specified writer.
+    #[inline]
+    fn write_enum_value<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_name(writer, name)
+    }
+
+    /// Writes a `...` to the specified writer.
+    ///
+    /// This must be called before writing a fragment spread or an inline fragment.
+    #[inline]
+    fn write_spread_operator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(b"...")
+    }
+
     /// Writes a whitespace separator to the specified writer.
     ///
     /// This is used to separate different parts of the GraphQL query,
@@ -101,7 +185,6 @@ pub trait Formatter {
     }
 
     /// Writes a string fragment that doesn't need any escaping to the specified writer.
-    // TODO: Add an escaped variant, and rename to string_unescaped
     #[inline]
     fn write_string_fragment<W>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()>
     where
@@ -110,6 +193,16 @@ pub trait Formatter {
         writer.write_all(fragment.as_bytes())
     }
 
+    /// Writes an escape sequence of a string value (e.g. `\n` or `\u0000`) to the specified
+    /// writer.
+    #[inline]
+    fn write_string_escape<W>(&mut self, writer: &mut W, escape: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(escape.as_bytes())
+    }
+
     /// Writes a `$` to the specified writer.
     ///
     /// This must be called before writing a variable name.
@@ -274,6 +367,22 @@ pub trait Formatter {
         writer.write_all(b"]")
     }
 
+    /// Whether this formatter can represent binary values as byte arrays, with
+    /// [`Formatter::write_byte_array`].
+    ///
+    /// If `false`, serializing a binary value with the
+    /// [`BinaryEncoding::IntList`](graphql_toolkit_ast::BinaryEncoding::IntList) encoding fails
+    /// with [`Error::UnprintableBinary`](crate::Error::UnprintableBinary) instead of writing it.
+    /// The string encodings are written as string values by all the formatters.
+    ///
+    /// Byte array support is opt-in: the compact and pretty formatters support it, the highlight
+    /// formatter supports it if its inner formatter does, and the other formatters reject byte
+    /// arrays unless they override this method.
+    #[inline]
+    fn supports_binary(&self) -> bool {
+        false
+    }
+
     /// Writes the representation of a byte array. Formatters can choose whether
     /// to represent bytes as a JSON array of integers (the default), or some
     /// JSON string encoding like hex or base64.
@@ -0,0 +1,617 @@
+use std::io;
+
+use super::formatter::Formatter;
+
+/// The kind of GraphQL token being written by a [`HighlightFormatter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Token {
+    /// A keyword, such as `query`, `fragment`, `on`, `true` or `null`.
+    Keyword,
+    /// An operation or fragment name.
+    Name,
+    /// The name, or the alias, of a selected field.
+    FieldName,
+    /// A type name, in a variable type or a type condition.
+    TypeName,
+    /// An argument name, or an input object field name.
+    ArgumentName,
+    /// A variable, including the preceding `$`.
+    Variable,
+    /// A directive name, including the preceding `@`.
+    Directive,
+    /// An enum value.
+    EnumValue,
+    /// A string value, including the quotes.
+    String,
+    /// A numeric value.
+    Number,
+    /// A punctuator, such as `{`, `(`, `:` or `...`.
+    Punctuation,
+}
+
+impl Token {
+    /// Returns the kebab-case name of the token kind, for example `field-name`.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Keyword => "keyword",
+            Self::Name => "name",
+            Self::FieldName => "field-name",
+            Self::TypeName => "type-name",
+            Self::ArgumentName => "argument-name",
+            Self::Variable => "variable",
+            Self::Directive => "directive",
+            Self::EnumValue => "enum-value",
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Punctuation => "punctuation",
+        }
+    }
+}
+
+/// A trait for decorating the tokens written by a [`HighlightFormatter`].
+pub trait Highlighter {
+    /// Called before writing a token of the given kind.
+    fn begin_token<W>(&mut self, writer: &mut W, token: Token) -> io::Result<()>
+    where
+        W: ?Sized + io::Write;
+
+    /// Called after writing a token of the given kind.
+    fn end_token<W>(&mut self, writer: &mut W, token: Token) -> io::Result<()>
+    where
+        W: ?Sized + io::Write;
+
+    /// Writes a fragment of free-form text, like the contents of a string value.
+    ///
+    /// Highlighters can override this to escape the text for the target format.
+    #[inline]
+    fn write_text<W>(&mut self, writer: &mut W, text: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(text.as_bytes())
+    }
+}
+
+/// A formatter that wraps another formatter and highlights the tokens it writes.
+///
+/// The layout (whitespace, indentation, separators) is delegated to the inner formatter, so it
+/// can be combined with both the [`CompactFormatter`](super::compact::CompactFormatter) and the
+/// [`PrettyFormatter`](super::pretty::PrettyFormatter). The whitespace written by the inner
+/// formatter is never highlighted.
+#[derive(Clone, Debug)]
+pub struct HighlightFormatter<F, H> {
+    inner: F,
+    highlighter: H,
+}
+
+impl<F, H> HighlightFormatter<F, H>
+where
+    F: Formatter,
+    H: Highlighter,
+{
+    /// Construct a highlighting formatter from an inner formatter and a highlighter.
+    pub fn with_highlighter(inner: F, highlighter: H) -> Self {
+        Self { inner, highlighter }
+    }
+
+    /// Consume the formatter, returning the inner formatter.
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+/// Wrap the inner formatter call `$call` with the highlighter's begin and end token calls.
+macro_rules! highlight {
+    ($self:ident, $writer:ident, $token:expr, $call:expr) => {{
+        $self.highlighter.begin_token($writer, $token)?;
+        $call?;
+        $self.highlighter.end_token($writer, $token)
+    }};
+}
+
+/// Highlight the punctuators written by the inner formatter method `$method`, leaving out the
+/// whitespace written around them.
+macro_rules! punctuation {
+    ($self:ident, $writer:ident, $method:ident) => {{
+        let mut writer = PunctuationWriter {
+            writer: $writer,
+            highlighter: &mut $self.highlighter,
+            is_in_token: false,
+        };
+        $self.inner.$method(&mut writer)?;
+        writer.finish()
+    }};
+}
+
+/// A writer highlighting the punctuators written through it.
+///
+/// The layout of the inner formatter can surround a punctuator with whitespace, such as the
+/// newline and indentation following the `{` of a block in the pretty formatter. The runs of
+/// whitespace are written as-is, outside of the punctuation token.
+struct PunctuationWriter<'a, W: ?Sized, H> {
+    writer: &'a mut W,
+    highlighter: &'a mut H,
+    is_in_token: bool,
+}
+
+impl<W, H> PunctuationWriter<'_, W, H>
+where
+    W: ?Sized + io::Write,
+    H: Highlighter,
+{
+    /// Close the punctuation token, if open.
+    fn finish(mut self) -> io::Result<()> {
+        self.set_in_token(false)
+    }
+
+    fn set_in_token(&mut self, is_in_token: bool) -> io::Result<()> {
+        match (self.is_in_token, is_in_token) {
+            (false, true) => self
+                .highlighter
+                .begin_token(self.writer, Token::Punctuation)?,
+            (true, false) => self
+                .highlighter
+                .end_token(self.writer, Token::Punctuation)?,
+            _ => {}
+        }
+        self.is_in_token = is_in_token;
+        Ok(())
+    }
+}
+
+impl<W, H> io::Write for PunctuationWriter<'_, W, H>
+where
+    W: ?Sized + io::Write,
+    H: Highlighter,
+{
+    /// Writes the leading run of whitespace or non-whitespace bytes of the buffer.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Some(first) = buf.first() else {
+            return Ok(0);
+        };
+        let is_whitespace = first.is_ascii_whitespace();
+        let len = buf
+            .iter()
+            .position(|b| b.is_ascii_whitespace() != is_whitespace)
+            .unwrap_or(buf.len());
+
+        self.set_in_token(!is_whitespace)?;
+        self.writer.write_all(&buf[..len])?;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<F, H> Formatter for HighlightFormatter<F, H>
+where
+    F: Formatter,
+    H: Highlighter,
+{
+    #[inline]
+    fn write_raw<W>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.write_raw(writer, fragment)
+    }
+
+    #[inline]
+    fn write_keyword<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Keyword,
+            self.inner.write_keyword(writer, name)
+        )
+    }
+
+    #[inline]
+    fn write_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Name,
+            self.inner.write_name(writer, name)
+        )
+    }
+
+    #[inline]
+    fn write_field_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::FieldName,
+            self.inner.write_field_name(writer, name)
+        )
+    }
+
+    #[inline]
+    fn write_type_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::TypeName,
+            self.inner.write_type_name(writer, name)
+        )
+    }
+
+    #[inline]
+    fn write_argument_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::ArgumentName,
+            self.inner.write_argument_name(writer, name)
+        )
+    }
+
+    /// Writes the variable name and closes the token opened by [`Formatter::begin_variable`].
+    #[inline]
+    fn write_variable_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.write_variable_name(writer, name)?;
+        self.highlighter.end_token(writer, Token::Variable)
+    }
+
+    /// Writes the directive name and closes the token opened by [`Formatter::begin_directive`].
+    #[inline]
+    fn write_directive_name<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.write_directive_name(writer, name)?;
+        self.highlighter.end_token(writer, Token::Directive)
+    }
+
+    #[inline]
+    fn write_enum_value<W>(&mut self, writer: &mut W, name: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::EnumValue,
+            self.inner.write_enum_value(writer, name)
+        )
+    }
+
+    #[inline]
+    fn write_spread_operator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, write_spread_operator)
+    }
+
+    #[inline]
+    fn write_separator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.write_separator(writer)
+    }
+
+    #[inline]
+    fn before_operation_or_fragment_definition<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.before_operation_or_fragment_definition(writer)
+    }
+
+    #[inline]
+    fn before_operation_variable_definitions<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.before_operation_variable_definitions(writer)
+    }
+
+    #[inline]
+    fn after_operation_or_fragment_signature<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.after_operation_or_fragment_signature(writer)
+    }
+
+    #[inline]
+    fn after_selection_signature<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.after_selection_signature(writer)
+    }
+
+    #[inline]
+    fn before_type_condition<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.before_type_condition(writer)
+    }
+
+    #[inline]
+    fn before_directive<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.before_directive(writer)
+    }
+
+    #[inline]
+    fn write_string_fragment<W>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.highlighter.write_text(writer, fragment)
+    }
+
+    #[inline]
+    fn write_string_escape<W>(&mut self, writer: &mut W, escape: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.highlighter.write_text(writer, escape)
+    }
+
+    /// Opens a variable token, closed by [`Formatter::write_variable_name`].
+    #[inline]
+    fn begin_variable<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.highlighter.begin_token(writer, Token::Variable)?;
+        self.inner.begin_variable(writer)
+    }
+
+    #[inline]
+    fn write_non_null_type_indicator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, write_non_null_type_indicator)
+    }
+
+    /// Opens a directive token, closed by [`Formatter::write_directive_name`].
+    #[inline]
+    fn begin_directive<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.highlighter.begin_token(writer, Token::Directive)?;
+        self.inner.begin_directive(writer)
+    }
+
+    #[inline]
+    fn write_name_value_separator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, write_name_value_separator)
+    }
+
+    #[inline]
+    fn write_variable_default_value_separator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, write_variable_default_value_separator)
+    }
+
+    #[inline]
+    fn write_item_separator<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, write_item_separator)
+    }
+
+    #[inline]
+    fn begin_parentheses<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, begin_parentheses)
+    }
+
+    #[inline]
+    fn end_parentheses<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, end_parentheses)
+    }
+
+    #[inline]
+    fn begin_block<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, begin_block)
+    }
+
+    #[inline]
+    fn end_block<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, end_block)
+    }
+
+    #[inline]
+    fn before_block_item<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.before_block_item(writer)
+    }
+
+    #[inline]
+    fn after_block_item<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.after_block_item(writer)
+    }
+
+    /// Opens a string token, closed by [`Formatter::end_string`].
+    #[inline]
+    fn begin_string<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.highlighter.begin_token(writer, Token::String)?;
+        self.inner.begin_string(writer)
+    }
+
+    /// Closes the string token opened by [`Formatter::begin_string`].
+    #[inline]
+    fn end_string<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.end_string(writer)?;
+        self.highlighter.end_token(writer, Token::String)
+    }
+
+    #[inline]
+    fn begin_array<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, begin_array)
+    }
+
+    #[inline]
+    fn end_array<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, end_array)
+    }
+
+    #[inline]
+    fn supports_binary(&self) -> bool {
+        self.inner.supports_binary()
+    }
+
+    #[inline]
+    fn write_byte_array<W>(&mut self, writer: &mut W, value: &[u8]) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.write_byte_array(writer, value)
+    }
+
+    #[inline]
+    fn begin_object<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, begin_object)
+    }
+
+    #[inline]
+    fn end_object<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        punctuation!(self, writer, end_object)
+    }
+
+    #[inline]
+    fn write_null<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(self, writer, Token::Keyword, self.inner.write_null(writer))
+    }
+
+    #[inline]
+    fn write_bool<W>(&mut self, writer: &mut W, value: bool) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Keyword,
+            self.inner.write_bool(writer, value)
+        )
+    }
+
+    #[inline]
+    fn write_u8<W>(&mut self, writer: &mut W, value: u8) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Number,
+            self.inner.write_u8(writer, value)
+        )
+    }
+
+    #[inline]
+    fn write_u64<W>(&mut self, writer: &mut W, value: u64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Number,
+            self.inner.write_u64(writer, value)
+        )
+    }
+
+    #[inline]
+    fn write_i64<W>(&mut self, writer: &mut W, value: i64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Number,
+            self.inner.write_i64(writer, value)
+        )
+    }
+
+    #[inline]
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        highlight!(
+            self,
+            writer,
+            Token::Number,
+            self.inner.write_f64(writer, value)
+        )
+    }
+}
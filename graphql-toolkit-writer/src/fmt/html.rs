@@ -0,0 +1,93 @@
+use std::io;
+
+use super::{
+    formatter::Formatter,
+    highlight::{HighlightFormatter, Highlighter, Token},
+};
+
+/// A formatter that highlights GraphQL documents with HTML `<span>` elements.
+///
+/// Each token is wrapped in a span whose CSS class is the class prefix followed by the token kind
+/// (see [`Token::as_str`]), for example `<span class="gql-field-name">id</span>`. Free-form text,
+/// like string values, is HTML-escaped.
+pub type HtmlFormatter<F> = HighlightFormatter<F, HtmlHighlighter>;
+
+impl<F> HtmlFormatter<F>
+where
+    F: Formatter,
+{
+    /// Construct an HTML highlighting formatter that uses the default `gql-` class prefix.
+    pub fn new(inner: F) -> Self {
+        Self::with_highlighter(inner, HtmlHighlighter::default())
+    }
+
+    /// Construct an HTML highlighting formatter that uses the given class prefix.
+    pub fn with_class_prefix(inner: F, prefix: &'static str) -> Self {
+        Self::with_highlighter(inner, HtmlHighlighter::new(prefix))
+    }
+}
+
+/// A highlighter that wraps each token in an HTML `<span>` element with a CSS class.
+#[derive(Clone, Debug)]
+pub struct HtmlHighlighter {
+    class_prefix: &'static str,
+}
+
+impl HtmlHighlighter {
+    /// Construct an HTML highlighter that uses the given CSS class prefix.
+    pub fn new(class_prefix: &'static str) -> Self {
+        Self { class_prefix }
+    }
+}
+
+impl Default for HtmlHighlighter {
+    fn default() -> Self {
+        HtmlHighlighter::new("gql-")
+    }
+}
+
+impl Highlighter for HtmlHighlighter {
+    #[inline]
+    fn begin_token<W>(&mut self, writer: &mut W, token: Token) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(b"<span class=\"")?;
+        writer.write_all(self.class_prefix.as_bytes())?;
+        writer.write_all(token.as_str().as_bytes())?;
+        writer.write_all(b"\">")
+    }
+
+    #[inline]
+    fn end_token<W>(&mut self, writer: &mut W, _token: Token) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(b"</span>")
+    }
+
+    fn write_text<W>(&mut self, writer: &mut W, text: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let bytes = text.as_bytes();
+
+        let mut start = 0;
+        for (i, byte) in bytes.iter().enumerate() {
+            let escape: &[u8] = match byte {
+                b'&' => b"&amp;",
+                b'<' => b"&lt;",
+                b'>' => b"&gt;",
+                b'"' => b"&quot;",
+                b'\'' => b"&#39;",
+                _ => continue,
+            };
+
+            writer.write_all(&bytes[start..i])?;
+            writer.write_all(escape)?;
+            start = i + 1;
+        }
+
+        writer.write_all(&bytes[start..])
+    }
+}
@@ -1,7 +1,11 @@
-use std::io;
+use std::{fmt, io};
 
 use super::formatter::Formatter;
-use crate::ser::{Serialize, Serializer};
+use crate::{
+    display::FmtWriter,
+    error::Result,
+    ser::{Serialize, Serializer},
+};
 
 /// This formatter generates a GraphQL document with a human-readable format.
 #[derive(Clone, Debug)]
@@ -139,6 +143,11 @@ impl<'a> Formatter for PrettyFormatter<'a> {
     {
         writer.write_all(b"\n")
     }
+
+    #[inline]
+    fn supports_binary(&self) -> bool {
+        true
+    }
 }
 
 /// Indent the specified writer `n` times by the `s` number of spaces.
@@ -155,7 +164,7 @@ where
 
 /// Serialize the given GraphQL AST as a pretty-printed GraphQL document into the I/O stream.
 #[inline]
-pub fn to_writer_pretty<W, T>(writer: W, value: &T) -> anyhow::Result<()>
+pub fn to_writer_pretty<W, T>(writer: W, value: &T) -> Result<()>
 where
     W: io::Write,
     T: ?Sized + Serialize,
@@ -164,9 +173,20 @@ where
     value.serialize(&mut ser)
 }
 
+/// Serialize the given GraphQL AST as a pretty-printed GraphQL document into the `fmt::Write`
+/// sink.
+#[inline]
+pub fn to_fmt_writer_pretty<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: fmt::Write,
+    T: ?Sized + Serialize,
+{
+    to_writer_pretty(FmtWriter::new(writer), value)
+}
+
 /// Serialize the given GraphQL AST as a pretty-printed GraphQL document byte vector.
 #[inline]
-pub fn to_vec_pretty<T>(value: &T) -> anyhow::Result<Vec<u8>>
+pub fn to_vec_pretty<T>(value: &T) -> Result<Vec<u8>>
 where
     T: ?Sized + Serialize,
 {
@@ -177,7 +197,7 @@ where
 
 /// Serialize the given GraphQL AST as a pretty-printed GraphQL document string.
 #[inline]
-pub fn to_string_pretty<T>(value: &T) -> anyhow::Result<String>
+pub fn to_string_pretty<T>(value: &T) -> Result<String>
 where
     T: ?Sized + Serialize,
 {
@@ -1,11 +1,25 @@
 //! A GraphQL document writer.
 
+#[cfg(feature = "tokio")]
+mod async_ser;
+mod display;
+mod error;
 mod fmt;
 mod ser;
 
+#[cfg(feature = "tokio")]
+pub use async_ser::{to_async_writer, to_async_writer_pretty, AsyncSerializer};
+pub use display::{display, Display, FmtWriter};
+pub use error::{Error, Result};
 pub use fmt::{
-    compact::{to_string, to_vec, to_writer},
+    ansi::{AnsiFormatter, AnsiHighlighter, AnsiTheme},
+    compact::{to_fmt_writer, to_string, to_vec, to_writer, CompactFormatter},
     formatter::Formatter,
-    pretty::{to_string_pretty, to_vec_pretty, to_writer_pretty},
+    highlight::{HighlightFormatter, Highlighter, Token},
+    html::{HtmlFormatter, HtmlHighlighter},
+    pretty::{
+        to_fmt_writer_pretty, to_string_pretty, to_vec_pretty, to_writer_pretty, PrettyFormatter,
+    },
 };
+pub use graphql_toolkit_ast::BinaryEncoding;
 pub use ser::{Serialize, Serializer};
@@ -1,18 +1,21 @@
-use std::io;
+use std::{borrow::Cow, io};
 
 use graphql_toolkit_ast::{
-    indexmap::IndexMap, BaseType, ConstValue, Directive, DocumentOperations, ExecutableDocument,
-    Field, FragmentDefinition, FragmentSpread, InlineFragment, Name, Number, OperationDefinition,
-    OperationType, Positioned, Selection, SelectionSet, Type, TypeCondition, Value,
-    VariableDefinition,
+    indexmap::IndexMap, BaseType, BinaryEncoding, ConstValue, Directive, DocumentOperations,
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment, Name, Number,
+    OperationDefinition, OperationType, Positioned, Selection, SelectionSet, Type, TypeCondition,
+    Value, VariableDefinition,
 };
 
-use crate::fmt::formatter::Formatter;
+use crate::{
+    error::{Error, Result},
+    fmt::formatter::Formatter,
+};
 
 /// A trait for serializing a GraphQL AST into a GraphQL document.
 pub trait Serialize {
     /// Serialize this value into the given serializer.
-    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> anyhow::Result<()>
+    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> Result<()>
     where
         W: io::Write,
         F: Formatter;
@@ -22,6 +25,7 @@ pub trait Serialize {
 pub struct Serializer<W, F> {
     writer: W,
     formatter: F,
+    binary_encoding: BinaryEncoding,
 }
 
 impl<W, F> Serializer<W, F>
@@ -32,7 +36,28 @@ where
     /// Create a new serializer with a custom formatter.
     #[inline]
     pub fn with_formatter(writer: W, formatter: F) -> Self {
-        Self { writer, formatter }
+        Self {
+            writer,
+            formatter,
+            binary_encoding: BinaryEncoding::default(),
+        }
+    }
+
+    /// Set the encoding used to write binary values.
+    ///
+    /// Binary values are written as a list of integers by default. String based encodings are
+    /// written as GraphQL string literals.
+    #[inline]
+    #[must_use]
+    pub fn with_binary_encoding(mut self, encoding: BinaryEncoding) -> Self {
+        self.binary_encoding = encoding;
+        self
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
     }
 }
 
@@ -41,26 +66,26 @@ where
     W: io::Write,
     F: Formatter,
 {
-    fn serialize_executable_document(&mut self, value: &ExecutableDocument) -> anyhow::Result<()> {
+    fn serialize_executable_document(&mut self, value: &ExecutableDocument) -> Result<()> {
         match &value.operations {
             DocumentOperations::Single(def) => {
                 self.serialize_operation_definition(&def.node, None, true)?;
             }
-            DocumentOperations::Multiple(def) => {
+            DocumentOperations::Multiple(_) => {
                 let mut first_operation = true;
-                for (name, def) in def.iter() {
+                for (name, def) in value.operations.in_order() {
                     if !first_operation {
                         self.formatter
                             .before_operation_or_fragment_definition(&mut self.writer)?;
                     }
 
-                    self.serialize_operation_definition(&def.node, Some(name), false)?;
+                    self.serialize_operation_definition(&def.node, name, false)?;
                     first_operation = false;
                 }
             }
         }
 
-        for (name, fragment) in value.fragments.iter() {
+        for (name, fragment) in value.fragments_in_order() {
             self.formatter
                 .before_operation_or_fragment_definition(&mut self.writer)?;
             self.serialize_fragment_definition(name, &fragment.node)?;
@@ -74,7 +99,7 @@ where
         value: &OperationDefinition,
         name: Option<&Name>,
         single: bool,
-    ) -> anyhow::Result<()> {
+    ) -> Result<()> {
         // Use the "query shorthand" if a document contains *only one operation* and
         // that operation is a query which defines *no variables* and contains *no
         // directives* then that operation may be represented in a shorthand form
@@ -89,6 +114,10 @@ where
             && value.variable_definitions.is_empty()
             && value.directives.is_empty();
 
+        if let Some(name) = name {
+            validate_name(name)?;
+        }
+
         // Operation signature
         if !shorthand {
             // Type
@@ -108,7 +137,7 @@ where
             // Name
             if let Some(name) = name {
                 self.formatter.write_separator(&mut self.writer)?;
-                name.serialize(self)?;
+                self.formatter.write_name(&mut self.writer, name)?;
             }
 
             // Variables definition
@@ -147,7 +176,7 @@ where
         Ok(())
     }
 
-    fn serialize_selection_set(&mut self, value: &SelectionSet) -> anyhow::Result<()> {
+    fn serialize_selection_set(&mut self, value: &SelectionSet) -> Result<()> {
         // Empty selection sets are not serialized
         if value.items.is_empty() {
             return Ok(());
@@ -171,7 +200,7 @@ where
         Ok(())
     }
 
-    fn serialize_selection(&mut self, value: &Selection) -> anyhow::Result<()> {
+    fn serialize_selection(&mut self, value: &Selection) -> Result<()> {
         match value {
             Selection::Field(field) => field.serialize(self),
             Selection::FragmentSpread(fragment) => fragment.serialize(self),
@@ -181,14 +210,21 @@ where
         Ok(())
     }
 
-    fn serialize_selection_field(&mut self, value: &Field) -> anyhow::Result<()> {
+    fn serialize_selection_field(&mut self, value: &Field) -> Result<()> {
+        if let Some(alias) = &value.alias {
+            validate_name(&alias.node)?;
+        }
+        validate_name(&value.name.node)?;
+
         if let Some(alias) = &value.alias {
-            alias.serialize(self)?;
+            self.formatter
+                .write_field_name(&mut self.writer, &alias.node)?;
             self.formatter
                 .write_name_value_separator(&mut self.writer)?;
         }
 
-        value.name.serialize(self)?;
+        self.formatter
+            .write_field_name(&mut self.writer, &value.name.node)?;
 
         if !value.arguments.is_empty() {
             self.serialize_arguments(&value.arguments)?;
@@ -206,9 +242,11 @@ where
         Ok(())
     }
 
-    fn serialize_fragment_spread(&mut self, value: &FragmentSpread) -> anyhow::Result<()> {
-        self.formatter.write_keyword(&mut self.writer, "...")?;
-        value.fragment_name.serialize(self)?;
+    fn serialize_fragment_spread(&mut self, value: &FragmentSpread) -> Result<()> {
+        validate_name(&value.fragment_name.node)?;
+        self.formatter.write_spread_operator(&mut self.writer)?;
+        self.formatter
+            .write_name(&mut self.writer, &value.fragment_name.node)?;
 
         for directive in value.directives.iter() {
             directive.serialize(self)?;
@@ -217,8 +255,8 @@ where
         Ok(())
     }
 
-    fn serialize_inline_fragment(&mut self, value: &InlineFragment) -> anyhow::Result<()> {
-        self.formatter.write_keyword(&mut self.writer, "...")?;
+    fn serialize_inline_fragment(&mut self, value: &InlineFragment) -> Result<()> {
+        self.formatter.write_spread_operator(&mut self.writer)?;
 
         if let Some(type_condition) = &value.type_condition {
             self.formatter.before_type_condition(&mut self.writer)?;
@@ -240,12 +278,17 @@ where
     fn serialize_arguments(
         &mut self,
         value: &[(Positioned<Name>, Positioned<Value>)],
-    ) -> anyhow::Result<()> {
+    ) -> Result<()> {
+        for (name, _) in value {
+            validate_name(&name.node)?;
+        }
+
         self.formatter.begin_parentheses(&mut self.writer)?;
 
         let mut iter = value.iter().peekable();
         while let Some((name, value)) = iter.next() {
-            name.serialize(self)?;
+            self.formatter
+                .write_argument_name(&mut self.writer, &name.node)?;
             self.formatter
                 .write_name_value_separator(&mut self.writer)?;
             value.serialize(self)?;
@@ -261,12 +304,15 @@ where
         Ok(())
     }
 
-    fn serialize_directive(&mut self, value: &Directive) -> anyhow::Result<()> {
+    fn serialize_directive(&mut self, value: &Directive) -> Result<()> {
+        validate_name(&value.name.node)?;
+
         self.formatter.before_directive(&mut self.writer)?;
 
         // Directive name
         self.formatter.begin_directive(&mut self.writer)?;
-        value.name.serialize(self)?;
+        self.formatter
+            .write_directive_name(&mut self.writer, &value.name.node)?;
 
         // Arguments
         if !value.arguments.is_empty() {
@@ -276,10 +322,12 @@ where
         Ok(())
     }
 
-    fn serialize_variable_definition(&mut self, value: &VariableDefinition) -> anyhow::Result<()> {
+    fn serialize_variable_definition(&mut self, value: &VariableDefinition) -> Result<()> {
         // Variable name
+        validate_name(&value.name.node)?;
         self.formatter.begin_variable(&mut self.writer)?;
-        value.name.serialize(self)?;
+        self.formatter
+            .write_variable_name(&mut self.writer, &value.name.node)?;
 
         // Variable name-type separator
         self.formatter
@@ -301,10 +349,11 @@ where
         Ok(())
     }
 
-    fn serialize_type(&mut self, value: &Type) -> anyhow::Result<()> {
+    fn serialize_type(&mut self, value: &Type) -> Result<()> {
         match &value.base {
             BaseType::Named(name) => {
-                name.serialize(self)?;
+                validate_name(name)?;
+                self.formatter.write_type_name(&mut self.writer, name)?;
             }
             BaseType::List(list) => {
                 self.formatter.begin_array(&mut self.writer)?;
@@ -325,11 +374,13 @@ where
         &mut self,
         name: &Name,
         value: &FragmentDefinition,
-    ) -> anyhow::Result<()> {
+    ) -> Result<()> {
+        validate_name(name)?;
+
         self.formatter.write_keyword(&mut self.writer, "fragment")?;
 
         self.formatter.write_separator(&mut self.writer)?;
-        name.serialize(self)?;
+        self.formatter.write_name(&mut self.writer, name)?;
         self.formatter.write_separator(&mut self.writer)?;
 
         value.type_condition.serialize(self)?;
@@ -346,20 +397,25 @@ where
         Ok(())
     }
 
-    fn serialize_type_condition(&mut self, value: &TypeCondition) -> anyhow::Result<()> {
+    fn serialize_type_condition(&mut self, value: &TypeCondition) -> Result<()> {
+        validate_name(&value.on.node)?;
+
         self.formatter.write_keyword(&mut self.writer, "on")?;
         self.formatter.write_separator(&mut self.writer)?;
-        value.on.serialize(self)
+        self.formatter
+            .write_type_name(&mut self.writer, &value.on.node)?;
+
+        Ok(())
     }
 
-    fn serialize_name(&mut self, value: &Name) -> anyhow::Result<()> {
-        self.formatter
-            .write_string_fragment(&mut self.writer, value)?;
+    fn serialize_name(&mut self, value: &Name) -> Result<()> {
+        validate_name(value)?;
+        self.formatter.write_name(&mut self.writer, value)?;
 
         Ok(())
     }
 
-    fn serialize_value(&mut self, value: &Value) -> anyhow::Result<()> {
+    fn serialize_value(&mut self, value: &Value) -> Result<()> {
         match value {
             Value::Null => {
                 self.formatter.write_null(&mut self.writer)?;
@@ -367,22 +423,20 @@ where
             Value::Number(value) => {
                 value.serialize(self)?;
             }
-            // TODO: Support string character escaping
             Value::String(value) => {
-                self.formatter.begin_string(&mut self.writer)?;
-                self.formatter
-                    .write_string_fragment(&mut self.writer, value)?;
-                self.formatter.end_string(&mut self.writer)?;
+                self.serialize_str(value)?;
             }
             Value::Boolean(value) => {
                 self.formatter.write_bool(&mut self.writer, *value)?;
             }
             Value::Variable(name) => {
+                validate_name(name)?;
                 self.formatter.begin_variable(&mut self.writer)?;
-                name.serialize(self)?;
+                self.formatter.write_variable_name(&mut self.writer, name)?;
             }
             Value::Enum(value) => {
-                value.serialize(self)?;
+                validate_enum_value(value)?;
+                self.formatter.write_enum_value(&mut self.writer, value)?;
             }
             Value::List(list) => {
                 self.serialize_value_array(list)?;
@@ -391,15 +445,14 @@ where
                 self.serialize_value_object(value)?;
             }
             Value::Binary(value) => {
-                self.formatter
-                    .write_byte_array(&mut self.writer, &value[..])?;
+                self.serialize_byte_array(value)?;
             }
         }
 
         Ok(())
     }
 
-    fn serialize_value_array(&mut self, value: &[Value]) -> anyhow::Result<()> {
+    fn serialize_value_array(&mut self, value: &[Value]) -> Result<()> {
         self.formatter.begin_array(&mut self.writer)?;
 
         let mut iter = value.iter().peekable();
@@ -417,12 +470,16 @@ where
         Ok(())
     }
 
-    fn serialize_value_object(&mut self, value: &IndexMap<Name, Value>) -> anyhow::Result<()> {
+    fn serialize_value_object(&mut self, value: &IndexMap<Name, Value>) -> Result<()> {
+        for key in value.keys() {
+            validate_name(key)?;
+        }
+
         self.formatter.begin_object(&mut self.writer)?;
 
         let mut iter = value.iter().peekable();
         while let Some((key, value)) = iter.next() {
-            key.serialize(self)?;
+            self.formatter.write_argument_name(&mut self.writer, key)?;
             self.formatter
                 .write_name_value_separator(&mut self.writer)?;
             value.serialize(self)?;
@@ -438,7 +495,7 @@ where
         Ok(())
     }
 
-    fn serialize_const_value(&mut self, value: &ConstValue) -> anyhow::Result<()> {
+    fn serialize_const_value(&mut self, value: &ConstValue) -> Result<()> {
         match value {
             ConstValue::Null => {
                 self.formatter.write_null(&mut self.writer)?;
@@ -447,17 +504,14 @@ where
                 value.serialize(self)?;
             }
             ConstValue::String(value) => {
-                // TODO: Support string character escaping
-                self.formatter.begin_string(&mut self.writer)?;
-                self.formatter
-                    .write_string_fragment(&mut self.writer, value)?;
-                self.formatter.end_string(&mut self.writer)?;
+                self.serialize_str(value)?;
             }
             ConstValue::Boolean(value) => {
                 self.formatter.write_bool(&mut self.writer, *value)?;
             }
             ConstValue::Enum(value) => {
-                value.serialize(self)?;
+                validate_enum_value(value)?;
+                self.formatter.write_enum_value(&mut self.writer, value)?;
             }
             ConstValue::List(list) => {
                 self.serialize_const_value_array(list)?;
@@ -466,15 +520,14 @@ where
                 self.serialize_const_value_object(object)?;
             }
             ConstValue::Binary(value) => {
-                self.formatter
-                    .write_byte_array(&mut self.writer, &value[..])?;
+                self.serialize_byte_array(value)?;
             }
         }
 
         Ok(())
     }
 
-    fn serialize_const_value_array(&mut self, value: &[ConstValue]) -> anyhow::Result<()> {
+    fn serialize_const_value_array(&mut self, value: &[ConstValue]) -> Result<()> {
         self.formatter.begin_array(&mut self.writer)?;
 
         let mut iter = value.iter().peekable();
@@ -492,15 +545,16 @@ where
         Ok(())
     }
 
-    fn serialize_const_value_object(
-        &mut self,
-        value: &IndexMap<Name, ConstValue>,
-    ) -> anyhow::Result<()> {
+    fn serialize_const_value_object(&mut self, value: &IndexMap<Name, ConstValue>) -> Result<()> {
+        for key in value.keys() {
+            validate_name(key)?;
+        }
+
         self.formatter.begin_object(&mut self.writer)?;
 
         let mut iter = value.iter().peekable();
         while let Some((key, value)) = iter.next() {
-            key.serialize(self)?;
+            self.formatter.write_argument_name(&mut self.writer, key)?;
             self.formatter
                 .write_name_value_separator(&mut self.writer)?;
             value.serialize(self)?;
@@ -516,12 +570,67 @@ where
         Ok(())
     }
 
-    fn serialize_number(&mut self, value: &Number) -> anyhow::Result<()> {
+    /// Serialize a string value, escaping its quotes, backslashes and control characters.
+    fn serialize_str(&mut self, value: &str) -> Result<()> {
+        self.formatter.begin_string(&mut self.writer)?;
+
+        let mut start = 0;
+        for (index, c) in value.char_indices() {
+            let escape = match c {
+                '"' => Cow::Borrowed("\\\""),
+                '\\' => Cow::Borrowed("\\\\"),
+                '\n' => Cow::Borrowed("\\n"),
+                '\r' => Cow::Borrowed("\\r"),
+                '\t' => Cow::Borrowed("\\t"),
+                c if c.is_control() => Cow::Owned(format!("\\u{:04x}", c as u32)),
+                _ => continue,
+            };
+
+            if start < index {
+                self.formatter
+                    .write_string_fragment(&mut self.writer, &value[start..index])?;
+            }
+            self.formatter
+                .write_string_escape(&mut self.writer, &escape)?;
+            start = index + c.len_utf8();
+        }
+        if start < value.len() {
+            self.formatter
+                .write_string_fragment(&mut self.writer, &value[start..])?;
+        }
+
+        self.formatter.end_string(&mut self.writer)?;
+
+        Ok(())
+    }
+
+    fn serialize_byte_array(&mut self, value: &[u8]) -> Result<()> {
+        match self.binary_encoding.encode_to_string(value) {
+            // The encoded strings need no escaping.
+            Some(encoded) => {
+                self.formatter.begin_string(&mut self.writer)?;
+                self.formatter
+                    .write_string_fragment(&mut self.writer, &encoded)?;
+                self.formatter.end_string(&mut self.writer)?;
+            }
+            None if self.formatter.supports_binary() => {
+                self.formatter.write_byte_array(&mut self.writer, value)?;
+            }
+            None => return Err(Error::UnprintableBinary),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_number(&mut self, value: &Number) -> Result<()> {
         if let Some(u) = value.as_u64() {
             self.formatter.write_u64(&mut self.writer, u)?;
         } else if let Some(i) = value.as_i64() {
             self.formatter.write_i64(&mut self.writer, i)?;
         } else if let Some(f) = value.as_f64() {
+            if !f.is_finite() {
+                return Err(Error::NonFiniteFloat(f));
+            }
             self.formatter.write_f64(&mut self.writer, f)?;
         } else {
             unreachable!("invalid number")
@@ -531,12 +640,40 @@ where
     }
 }
 
+/// Check that the name is a valid GraphQL name, as in `/[_A-Za-z][_0-9A-Za-z]*/`.
+///
+/// [Reference](https://spec.graphql.org/October2021/#Name).
+fn validate_name(name: &Name) -> Result<()> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .map_or(false, |c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric());
+
+    if !valid {
+        return Err(Error::InvalidName(name.clone()));
+    }
+
+    Ok(())
+}
+
+/// Check that the name is a valid GraphQL enum value: a name other than `true`, `false` or `null`.
+///
+/// [Reference](https://spec.graphql.org/October2021/#EnumValue).
+fn validate_enum_value(name: &Name) -> Result<()> {
+    if matches!(name.as_str(), "true" | "false" | "null") {
+        return Err(Error::InvalidName(name.clone()));
+    }
+
+    validate_name(name)
+}
+
 // Implement `AstSerialize` for a type that can be serialized.
 macro_rules! impl_serialize {
     ($ty:ty, $method:ident) => {
         impl Serialize for $ty {
             #[inline]
-            fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> anyhow::Result<()>
+            fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> Result<()>
             where
                 W: io::Write,
                 F: Formatter,
@@ -552,7 +689,7 @@ where
     T: Serialize,
 {
     #[inline]
-    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> anyhow::Result<()>
+    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> Result<()>
     where
         W: io::Write,
         F: Formatter,
@@ -563,7 +700,7 @@ where
 
 impl Serialize for OperationDefinition {
     #[inline]
-    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> anyhow::Result<()>
+    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> Result<()>
     where
         W: io::Write,
         F: Formatter,
@@ -576,7 +713,7 @@ impl Serialize for OperationDefinition {
 
 impl Serialize for (Name, OperationDefinition) {
     #[inline]
-    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> anyhow::Result<()>
+    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> Result<()>
     where
         W: io::Write,
         F: Formatter,
@@ -588,7 +725,7 @@ impl Serialize for (Name, OperationDefinition) {
 
 impl Serialize for (Name, FragmentDefinition) {
     #[inline]
-    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> anyhow::Result<()>
+    fn serialize<W, F>(&self, ser: &mut Serializer<W, F>) -> Result<()>
     where
         W: io::Write,
         F: Formatter,
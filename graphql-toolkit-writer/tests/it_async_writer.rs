@@ -0,0 +1,157 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{
+    AstPositionExt as _, DocumentOperations, ExecutableDocument, Field, Name, OperationDefinition,
+    OperationType, Selection, SelectionSet, Value,
+};
+use graphql_toolkit_writer::{
+    to_async_writer, to_async_writer_pretty, to_string, to_string_pretty, AsyncSerializer, Error,
+    Serialize,
+};
+use tokio::io::AsyncWrite;
+
+/// Test helper function to parse a GraphQL document string into a serializable AST.
+///
+/// # Panics
+/// The function asserts that the input is a valid GraphQL string by checking the result of the
+/// `parse_query` function.
+fn parse_document<I: AsRef<str>>(input: I) -> impl Serialize {
+    graphql_toolkit_parser::parse_query(input).expect("document parsing failed")
+}
+
+/// An async sink that records the number of write calls it receives.
+#[derive(Default)]
+struct CountingWriter {
+    buffer: Vec<u8>,
+    writes: usize,
+}
+
+impl AsyncWrite for CountingWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.writes += 1;
+        self.buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn async_writer_matches_sync_compact_output() {
+    //* Given
+    let ast = parse_document(
+        r#"query MyQuery($id:ID!){user(id:$id){name ...UserFields}}fragment UserFields on User{id}"#,
+    );
+
+    //* When
+    let mut writer = Vec::new();
+    let result = to_async_writer(&mut writer, &ast).await;
+
+    //* Then
+    assert_matches!(result, Ok(()));
+    assert_eq!(
+        String::from_utf8(writer).expect("invalid UTF-8"),
+        to_string(&ast).expect("failed to serialize document")
+    );
+}
+
+#[tokio::test]
+async fn async_writer_matches_sync_pretty_output() {
+    //* Given
+    let ast = parse_document(r#"{user(role:ADMIN){name friends{name}}}"#);
+
+    //* When
+    let mut writer = Vec::new();
+    let result = to_async_writer_pretty(&mut writer, &ast).await;
+
+    //* Then
+    assert_matches!(result, Ok(()));
+    assert_eq!(
+        String::from_utf8(writer).expect("invalid UTF-8"),
+        to_string_pretty(&ast).expect("failed to serialize document")
+    );
+}
+
+#[tokio::test]
+async fn async_serializer_buffers_each_document_into_a_single_write() {
+    //* Given
+    let first = parse_document(r#"{user{name friends{name}}}"#);
+    let second = parse_document(r#"{viewer{id}}"#);
+
+    let mut ser = AsyncSerializer::new(CountingWriter::default());
+
+    //* When
+    ser.serialize(&first)
+        .await
+        .expect("failed to serialize document");
+    ser.serialize(&second)
+        .await
+        .expect("failed to serialize document");
+    ser.flush().await.expect("failed to flush");
+
+    //* Then
+    let writer = ser.into_inner();
+    assert_eq!(writer.writes, 2);
+    assert_eq!(
+        String::from_utf8(writer.buffer).expect("invalid UTF-8"),
+        "{user{name,friends{name}}}{viewer{id}}"
+    );
+}
+
+#[tokio::test]
+async fn async_serializer_writes_nothing_on_error() {
+    //* Given
+    let ast = ExecutableDocument {
+        operations: DocumentOperations::Single(
+            OperationDefinition {
+                ty: OperationType::Query,
+                variable_definitions: vec![],
+                directives: vec![],
+                selection_set: SelectionSet {
+                    items: vec![Selection::Field(
+                        Field {
+                            alias: None,
+                            name: Name::new("field").default_position(),
+                            arguments: vec![(
+                                Name::new("arg").default_position(),
+                                Value::Enum(Name::new("null")).default_position(),
+                            )],
+                            directives: vec![],
+                            selection_set: Default::default(),
+                        }
+                        .default_position(),
+                    )
+                    .default_position()],
+                }
+                .default_position(),
+            }
+            .default_position(),
+        ),
+        fragments: Default::default(),
+        name_clashes: Vec::new(),
+    };
+
+    let mut ser = AsyncSerializer::new(CountingWriter::default());
+
+    //* When
+    let result = ser.serialize(&ast).await;
+
+    //* Then
+    assert_matches!(result, Err(Error::InvalidName(_)));
+    assert_eq!(ser.into_inner().writes, 0);
+}
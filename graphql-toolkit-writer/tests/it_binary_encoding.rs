@@ -0,0 +1,107 @@
+use graphql_toolkit_ast::{
+    AstPositionExt as _, ConstValue, DocumentOperations, ExecutableDocument, Field, Name,
+    OperationDefinition, OperationType, Selection, SelectionSet, Value,
+};
+use graphql_toolkit_writer::{BinaryEncoding, Serialize, Serializer};
+
+/// Test helper function to build a query document with a single field and argument.
+fn query_with_argument(argument: Value) -> ExecutableDocument {
+    ExecutableDocument {
+        operations: DocumentOperations::Single(
+            OperationDefinition {
+                ty: OperationType::Query,
+                variable_definitions: vec![],
+                directives: vec![],
+                selection_set: SelectionSet {
+                    items: vec![Selection::Field(
+                        Field {
+                            alias: None,
+                            name: Name::new("field").default_position(),
+                            arguments: vec![(
+                                Name::new("arg").default_position(),
+                                argument.default_position(),
+                            )],
+                            directives: vec![],
+                            selection_set: Default::default(),
+                        }
+                        .default_position(),
+                    )
+                    .default_position()],
+                }
+                .default_position(),
+            }
+            .default_position(),
+        ),
+        fragments: Default::default(),
+        name_clashes: Vec::new(),
+    }
+}
+
+/// Test helper function to serialize the AST into a compact string using the given encoding.
+fn to_string_with_encoding<T: Serialize>(value: &T, encoding: BinaryEncoding) -> String {
+    let mut writer = Vec::new();
+    let mut ser = Serializer::new(&mut writer).with_binary_encoding(encoding);
+    value
+        .serialize(&mut ser)
+        .expect("failed to serialize document");
+    String::from_utf8(writer).expect("invalid UTF-8")
+}
+
+#[test]
+fn binary_is_written_as_int_list_by_default() {
+    //* Given
+    let ast = query_with_argument(Value::Binary(b"hi".to_vec().into()));
+
+    //* When
+    let document = to_string_with_encoding(&ast, BinaryEncoding::default());
+
+    //* Then
+    assert_eq!(document, "{field(arg:[104,105])}");
+}
+
+#[test]
+fn binary_is_written_as_base64_string() {
+    //* Given
+    let ast = query_with_argument(Value::Binary(b"hi".to_vec().into()));
+
+    //* When
+    let document = to_string_with_encoding(&ast, BinaryEncoding::Base64);
+
+    //* Then
+    assert_eq!(document, r#"{field(arg:"aGk=")}"#);
+}
+
+#[test]
+fn binary_is_written_as_hex_string() {
+    //* Given
+    let ast = query_with_argument(Value::Binary(vec![0x00, 0xab, 0xff].into()));
+
+    //* When
+    let document = to_string_with_encoding(&ast, BinaryEncoding::Hex);
+
+    //* Then
+    assert_eq!(document, r#"{field(arg:"00abff")}"#);
+}
+
+#[test]
+fn binary_encoding_agrees_with_value_json_output() {
+    //* Given
+    let value = ConstValue::Binary(b"graphql".to_vec().into());
+
+    for encoding in [
+        BinaryEncoding::IntList,
+        BinaryEncoding::Base64,
+        BinaryEncoding::Hex,
+    ] {
+        let ast = query_with_argument(value.clone().into_value());
+
+        //* When
+        let document = to_string_with_encoding(&ast, encoding);
+        let json = value
+            .to_json_with(encoding)
+            .expect("failed to serialize value");
+
+        //* Then
+        assert_eq!(document, format!("{{field(arg:{})}}", json));
+    }
+}
@@ -0,0 +1,58 @@
+use graphql_toolkit_writer::{
+    display, to_fmt_writer, to_fmt_writer_pretty, to_string, to_string_pretty, Serialize,
+};
+
+/// Test helper function to parse a GraphQL document string into a serializable AST.
+///
+/// # Panics
+/// The function asserts that the input is a valid GraphQL string by checking the result of the
+/// `parse_query` function.
+fn parse_document<I: AsRef<str>>(input: I) -> impl Serialize {
+    graphql_toolkit_parser::parse_query(input).expect("document parsing failed")
+}
+
+#[test]
+fn display_writes_compact_document() {
+    //* Given
+    let ast = parse_document(r#"query MyQuery($id:ID!){user(id:$id){name}}"#);
+
+    //* When
+    let document = format!("{}", display(&ast));
+
+    //* Then
+    assert_eq!(
+        document,
+        to_string(&ast).expect("failed to serialize document")
+    );
+}
+
+#[test]
+fn display_alternate_writes_pretty_document() {
+    //* Given
+    let ast = parse_document(r#"query MyQuery($id:ID!){user(id:$id){name}}"#);
+
+    //* When
+    let document = format!("{:#}", display(&ast));
+
+    //* Then
+    assert_eq!(
+        document,
+        to_string_pretty(&ast).expect("failed to serialize document")
+    );
+}
+
+#[test]
+fn fmt_writer_appends_to_string() {
+    //* Given
+    let ast = parse_document(r#"{field(arg:"value")}"#);
+    let mut compact = String::from("# compact\n");
+    let mut pretty = String::from("# pretty\n");
+
+    //* When
+    to_fmt_writer(&mut compact, &ast).expect("failed to serialize document");
+    to_fmt_writer_pretty(&mut pretty, &ast).expect("failed to serialize document");
+
+    //* Then
+    assert_eq!(compact, "# compact\n{field(arg:\"value\")}");
+    assert_eq!(pretty, "# pretty\n{\n  field(arg: \"value\")\n}");
+}
@@ -0,0 +1,184 @@
+use std::io;
+
+use assert_matches::assert_matches;
+use graphql_toolkit_ast::{
+    AstPositionExt as _, DocumentOperations, ExecutableDocument, Field, Name, OperationDefinition,
+    OperationType, Selection, SelectionSet, Value,
+};
+use graphql_toolkit_writer::{
+    to_string, to_string_pretty, BinaryEncoding, CompactFormatter, Error, Formatter, HtmlFormatter,
+    Serialize, Serializer,
+};
+
+/// Test helper function to build a query document with a single field and argument.
+fn query_with_field(name: &str, argument: Value) -> ExecutableDocument {
+    ExecutableDocument {
+        operations: DocumentOperations::Single(
+            OperationDefinition {
+                ty: OperationType::Query,
+                variable_definitions: vec![],
+                directives: vec![],
+                selection_set: SelectionSet {
+                    items: vec![Selection::Field(
+                        Field {
+                            alias: None,
+                            name: Name::new(name).default_position(),
+                            arguments: vec![(
+                                Name::new("arg").default_position(),
+                                argument.default_position(),
+                            )],
+                            directives: vec![],
+                            selection_set: Default::default(),
+                        }
+                        .default_position(),
+                    )
+                    .default_position()],
+                }
+                .default_position(),
+            }
+            .default_position(),
+        ),
+        fragments: Default::default(),
+        name_clashes: Vec::new(),
+    }
+}
+
+#[test]
+fn invalid_field_name_is_rejected() {
+    //* Given
+    let ast = query_with_field("my field", Value::Null);
+
+    //* When
+    let result = to_string(&ast);
+
+    //* Then
+    assert_matches!(result, Err(Error::InvalidName(name)) => {
+        assert_eq!(name, "my field");
+    });
+}
+
+#[test]
+fn invalid_names_are_rejected_before_writing() {
+    //* Given
+    let variable = Value::Variable(Name::new("my variable"));
+    let object = Value::Object([(Name::new("my key"), Value::Null)].into_iter().collect());
+
+    //* When
+    let mut variable_output = Vec::new();
+    let variable_result = variable.serialize(&mut Serializer::new(&mut variable_output));
+    let mut object_output = Vec::new();
+    let object_result = object.serialize(&mut Serializer::new(&mut object_output));
+
+    //* Then
+    assert_matches!(variable_result, Err(Error::InvalidName(name)) => {
+        assert_eq!(name, "my variable");
+    });
+    assert!(
+        variable_output.is_empty(),
+        "the variable prefix was written"
+    );
+    assert_matches!(object_result, Err(Error::InvalidName(name)) => {
+        assert_eq!(name, "my key");
+    });
+    assert!(object_output.is_empty(), "the object start was written");
+}
+
+#[test]
+fn reserved_enum_value_is_rejected() {
+    //* Given
+    let ast = query_with_field("field", Value::Enum(Name::new("null")));
+
+    //* When
+    let result = to_string(&ast);
+
+    //* Then
+    assert_matches!(result, Err(Error::InvalidName(name)) => {
+        assert_eq!(name, "null");
+    });
+}
+
+#[test]
+fn io_error_is_reported() {
+    //* Given
+    struct FailingWriter;
+
+    impl io::Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "broken pipe"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let ast = query_with_field("field", Value::Null);
+
+    //* When
+    let result = ast.serialize(&mut Serializer::new(FailingWriter));
+
+    //* Then
+    assert_matches!(result, Err(Error::Io(err)) => {
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    });
+}
+
+#[test]
+fn binary_is_rejected_by_formatter_without_binary_support() {
+    //* Given
+    struct PlainFormatter;
+
+    impl Formatter for PlainFormatter {}
+
+    let ast = query_with_field("field", Value::Binary(vec![1u8, 2u8].into()));
+
+    //* When
+    let mut writer = Vec::new();
+    let result = ast.serialize(&mut Serializer::with_formatter(&mut writer, PlainFormatter));
+
+    //* Then
+    assert_matches!(result, Err(Error::UnprintableBinary));
+}
+
+#[test]
+fn string_encoded_binary_is_accepted_by_formatter_without_binary_support() {
+    //* Given
+    struct PlainFormatter;
+
+    impl Formatter for PlainFormatter {}
+
+    let ast = query_with_field("field", Value::Binary(vec![1u8, 2u8].into()));
+
+    //* When
+    let mut writer = Vec::new();
+    let result = ast.serialize(
+        &mut Serializer::with_formatter(&mut writer, PlainFormatter)
+            .with_binary_encoding(BinaryEncoding::Hex),
+    );
+
+    //* Then
+    assert_matches!(result, Ok(()));
+    assert_eq!(String::from_utf8(writer).unwrap(), r#"{field(arg:"0102")}"#);
+}
+
+#[test]
+fn binary_is_supported_by_compact_pretty_and_highlight_formatters() {
+    //* Given
+    let ast = query_with_field("field", Value::Binary(vec![1u8, 2u8].into()));
+
+    //* When
+    let compact = to_string(&ast);
+    let pretty = to_string_pretty(&ast);
+    let mut highlighted_output = Vec::new();
+    let highlighted = ast.serialize(&mut Serializer::with_formatter(
+        &mut highlighted_output,
+        HtmlFormatter::new(CompactFormatter),
+    ));
+
+    //* Then
+    assert_matches!(compact, Ok(document) => {
+        assert_eq!(document, "{field(arg:[1,2])}");
+    });
+    assert_matches!(pretty, Ok(_));
+    assert_matches!(highlighted, Ok(_));
+}
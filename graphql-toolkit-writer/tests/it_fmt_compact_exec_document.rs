@@ -46,7 +46,6 @@ fn query_named() {
 }
 
 #[test]
-#[ignore = "Disabled due to non-deterministic output"]
 fn query_multiple_operations() {
     //* Given
     let ast = parse_document(r#"query MyQuery{field1}query YourQuery{field2}"#);
@@ -257,6 +256,7 @@ fn query_with_variables_with_default_value_binary_array() {
             .default_position(),
         ),
         fragments: Default::default(),
+        name_clashes: Vec::new(),
     };
 
     //* When
@@ -544,10 +544,20 @@ fn query_with_field_and_string_argument() {
     assert_valid_query(&document);
 }
 
-// TODO: Add tests covering the multiline string literal argument serialization
-//  https://github.com/async-graphql/async-graphql/blob/v5.0.0/parser/tests/executables/multiline_string.graphql
 #[test]
-#[ignore = "TODO"] // https://docs.rs/serde_json/latest/src/serde_json/ser.rs.html#2051
+fn query_with_field_and_escaped_string_argument() {
+    //* Given
+    let ast = parse_document(r#"{field1(arg1:"x\"y\nz\\\u0001\u00e9")}"#);
+
+    //* When
+    let document = to_string(&ast).expect("Failed to serialize document");
+
+    //* Then
+    assert_eq!(document, r#"{field1(arg1:"x\"y\nz\\\u0001é")}"#);
+    assert_valid_query(&document);
+}
+
+#[test]
 fn query_field_multiline_string_argument() {
     //* Given
     let ast = parse_document(indoc::indoc! {
@@ -668,6 +678,7 @@ fn query_with_field_and_bytes_argument() {
             .default_position(),
         ),
         fragments: Default::default(),
+        name_clashes: Vec::new(),
     };
 
     //* When
@@ -873,7 +884,6 @@ fn query_and_fragment_with_directive() {
 }
 
 #[test]
-#[ignore = "Disabled due to non-deterministic output"]
 fn query_and_fragments_multiple() {
     //* Given
     let ast = parse_document(
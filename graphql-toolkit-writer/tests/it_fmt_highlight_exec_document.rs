@@ -0,0 +1,123 @@
+use graphql_toolkit_writer::{
+    to_string, AnsiFormatter, AnsiTheme, CompactFormatter, Formatter, HtmlFormatter,
+    PrettyFormatter, Serialize, Serializer,
+};
+
+/// Test helper function to parse a GraphQL document string into a serializable AST.
+///
+/// # Panics
+/// The function asserts that the input is a valid GraphQL string by checking the result of the
+/// `parse_query` function.
+fn parse_document<I: AsRef<str>>(input: I) -> impl Serialize {
+    graphql_toolkit_parser::parse_query(input).expect("document parsing failed")
+}
+
+/// Test helper function to serialize the AST into a string using the given formatter.
+fn to_string_with_formatter<T, F>(value: &T, formatter: F) -> String
+where
+    T: Serialize,
+    F: Formatter,
+{
+    let mut writer = Vec::new();
+    let mut ser = Serializer::with_formatter(&mut writer, formatter);
+    value
+        .serialize(&mut ser)
+        .expect("failed to serialize document");
+    String::from_utf8(writer).expect("invalid UTF-8")
+}
+
+#[test]
+fn ansi_compact_query_with_variables_and_arguments() {
+    //* Given
+    let ast = parse_document(
+        r#"query MyQuery($id:ID!=1){user(id:$id,role:ADMIN){name@include(if:true)}}"#,
+    );
+
+    //* When
+    let document = to_string_with_formatter(&ast, AnsiFormatter::new(CompactFormatter));
+
+    //* Then
+    insta::assert_snapshot!(document);
+}
+
+#[test]
+fn ansi_pretty_query_with_fragment_spread() {
+    //* Given
+    let ast = parse_document(r#"{user{...UserFields}}fragment UserFields on User{name}"#);
+
+    //* When
+    let document = to_string_with_formatter(&ast, AnsiFormatter::new(PrettyFormatter::new()));
+
+    //* Then
+    insta::assert_snapshot!(document);
+}
+
+#[test]
+fn ansi_with_empty_theme_writes_plain_document() {
+    //* Given
+    let ast = parse_document(r#"query($var:[Int!]){field(arg:$var){...on Type{id}}}"#);
+    let theme = AnsiTheme {
+        keyword: "",
+        name: "",
+        field_name: "",
+        type_name: "",
+        argument_name: "",
+        variable: "",
+        directive: "",
+        enum_value: "",
+        string: "",
+        number: "",
+        punctuation: "",
+    };
+
+    //* When
+    let document =
+        to_string_with_formatter(&ast, AnsiFormatter::with_theme(CompactFormatter, theme));
+
+    //* Then
+    assert_eq!(
+        document,
+        to_string(&ast).expect("failed to serialize document")
+    );
+}
+
+#[test]
+fn html_pretty_query_with_inline_fragment() {
+    //* Given
+    let ast = parse_document(
+        r#"query Search($term:String="graph"){search(term:$term,first:10){...on User{id}}}"#,
+    );
+
+    //* When
+    let document = to_string_with_formatter(&ast, HtmlFormatter::new(PrettyFormatter::new()));
+
+    //* Then
+    insta::assert_snapshot!(document);
+}
+
+#[test]
+fn html_escapes_string_values() {
+    //* Given
+    let ast = parse_document(r#"{field(arg:"<b>&'")}"#);
+
+    //* When
+    let document = to_string_with_formatter(
+        &ast,
+        HtmlFormatter::with_class_prefix(CompactFormatter, "hl-"),
+    );
+
+    //* Then
+    assert_eq!(
+        document,
+        concat!(
+            r#"<span class="hl-punctuation">{</span>"#,
+            r#"<span class="hl-field-name">field</span>"#,
+            r#"<span class="hl-punctuation">(</span>"#,
+            r#"<span class="hl-argument-name">arg</span>"#,
+            r#"<span class="hl-punctuation">:</span>"#,
+            r#"<span class="hl-string">"&lt;b&gt;&amp;&#39;"</span>"#,
+            r#"<span class="hl-punctuation">)</span>"#,
+            r#"<span class="hl-punctuation">}</span>"#,
+        )
+    );
+}